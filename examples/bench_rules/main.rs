@@ -0,0 +1,75 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! Compares linting a large generated module with the rules running serially
+//! and in parallel.
+//!
+//! ```shell
+//! $ cargo run --release --example bench_rules
+//! ```
+use deno_lint::linter::LinterBuilder;
+use deno_lint::rules::get_all_rules;
+use std::time::Duration;
+use std::time::Instant;
+
+const LINES: usize = 10_000;
+const RUNS: u32 = 5;
+
+const CHUNK: &str = r#"// deno-lint-ignore no-explicit-any
+export function foo(a: any, b: number): number {
+  var c = a + b;
+  if (c = 1) {
+    debugger;
+  }
+  const d = { e: 1, e: 2 };
+  switch (c) {
+    case 1:
+      bar(d);
+    case 2:
+      break;
+  }
+  return c;
+}
+"#;
+
+fn generate_source() -> String {
+  let chunk_lines = CHUNK.lines().count();
+  CHUNK.repeat((LINES + chunk_lines - 1) / chunk_lines)
+}
+
+fn bench(source_code: &str, parallel: bool) -> (Duration, usize) {
+  let mut total = Duration::default();
+  let mut count = 0;
+  for _ in 0..RUNS {
+    let mut linter = LinterBuilder::default()
+      .rules(get_all_rules())
+      .parallel(parallel)
+      .build();
+    let start = Instant::now();
+    let diagnostics = linter
+      .lint("bench.ts".to_string(), source_code.to_string())
+      .expect("Failed to lint");
+    total += start.elapsed();
+    count = diagnostics.len();
+  }
+  (total / RUNS, count)
+}
+
+fn main() {
+  let source_code = generate_source();
+  println!(
+    "Linting {} lines with {} rules, {} runs each",
+    source_code.lines().count(),
+    get_all_rules().len(),
+    RUNS
+  );
+
+  let (serial, serial_count) = bench(&source_code, false);
+  println!("serial:   {:?} ({} problems)", serial, serial_count);
+  let (parallel, parallel_count) = bench(&source_code, true);
+  println!("parallel: {:?} ({} problems)", parallel, parallel_count);
+  assert_eq!(serial_count, parallel_count);
+
+  println!(
+    "speedup:  {:.2}x",
+    serial.as_secs_f64() / parallel.as_secs_f64()
+  );
+}
@@ -1,19 +1,16 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use annotate_snippets::display_list;
-use annotate_snippets::snippet;
 use clap::App;
 use clap::AppSettings;
 use clap::Arg;
 use clap::SubCommand;
-use deno_lint::diagnostic::LintDiagnostic;
-use deno_lint::diagnostic::Range;
+use deno_lint::diagnostic::Severity;
+use deno_lint::formatter::FileDiagnostics;
+use deno_lint::formatter::OutputFormat;
 use deno_lint::linter::LinterBuilder;
 use deno_lint::rules::get_recommended_rules;
 use rayon::prelude::*;
 use serde_json::json;
 use serde_json::Value;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Arc, Mutex};
 
 fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
   App::new("dlint")
@@ -27,106 +24,65 @@ fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
         .arg(Arg::with_name("json").long("json")),
     )
     .subcommand(
-      SubCommand::with_name("run").arg(
-        Arg::with_name("FILES")
-          .help("Sets the input file to use")
-          .required(true)
-          .multiple(true),
-      ),
+      SubCommand::with_name("run")
+        .arg(
+          Arg::with_name("FILES")
+            .help("Sets the input file to use")
+            .required(true)
+            .multiple(true),
+        )
+        .arg(
+          Arg::with_name("format")
+            .long("format")
+            .help("Sets the output format")
+            .takes_value(true)
+            .possible_values(&["pretty", "json", "sarif"])
+            .default_value("pretty"),
+        ),
     )
 }
 
-// Return slice of source code covered by diagnostic
-// and adjusted range of diagnostic (ie. original range - start line
-// of sliced source code).
-fn get_slice_source_and_range<'a>(
-  line_start_indexes: &[(usize, usize)],
-  source: &'a str,
-  range: &Range,
-) -> (&'a str, (usize, usize)) {
-  let (_, first_line_start) = line_start_indexes[range.start.line - 1];
-  let (last_line_no, _) = line_start_indexes[range.end.line - 1];
-  let last_line_end = line_start_indexes[last_line_no + 1].1 - 1;
-  let adjusted_start = range.start.byte_pos - first_line_start;
-  let adjusted_end = range.end.byte_pos - first_line_start;
-  let adjusted_range = (adjusted_start, adjusted_end);
-  let slice_str = &source[first_line_start..last_line_end];
-  (slice_str, adjusted_range)
-}
-
-fn display_diagnostic(diagnostic: &LintDiagnostic, source: &str) {
-  let line_start_indexes = std::iter::once(0)
-    .chain(source.match_indices('\n').map(|l| l.0 + 1))
-    .enumerate()
-    .collect::<Vec<_>>();
-  let (slice_source, range) =
-    get_slice_source_and_range(&line_start_indexes, source, &diagnostic.range);
-
-  let footer = if let Some(hint) = &diagnostic.hint {
-    vec![snippet::Annotation {
-      label: Some(hint),
-      id: None,
-      annotation_type: snippet::AnnotationType::Help,
-    }]
-  } else {
-    vec![]
-  };
-
-  let snippet = snippet::Snippet {
-    title: Some(snippet::Annotation {
-      label: Some(&diagnostic.message),
-      id: Some(&diagnostic.code),
-      annotation_type: snippet::AnnotationType::Error,
-    }),
-    footer,
-    slices: vec![snippet::Slice {
-      source: &slice_source,
-      line_start: diagnostic.range.start.line,
-      origin: Some(&diagnostic.filename),
-      fold: false,
-      annotations: vec![snippet::SourceAnnotation {
-        range,
-        label: "",
-        annotation_type: snippet::AnnotationType::Error,
-      }],
-    }],
-    opt: display_list::FormatOptions {
-      color: true,
-      anonymized_line_numbers: false,
-      margin: None,
-    },
-  };
-  let display_list = display_list::DisplayList::from(snippet);
-  eprintln!("{}", display_list);
-}
-
-fn run_linter(paths: Vec<String>) {
-  let error_counts = Arc::new(AtomicUsize::new(0));
-  let output_lock = Arc::new(Mutex::new(())); // prevent threads outputting at the same time
-
-  paths.par_iter().for_each(|file_path| {
-    let source_code =
-      std::fs::read_to_string(&file_path).expect("Failed to read file");
-
-    let mut linter = LinterBuilder::default()
-      .rules(get_recommended_rules())
-      .build();
-
-    let file_diagnostics = linter
-      .lint(file_path.to_string(), source_code.clone())
-      .expect("Failed to lint");
-
-    error_counts.fetch_add(file_diagnostics.len(), Ordering::Relaxed);
-    let _g = output_lock.lock().unwrap();
-
-    for diagnostic in file_diagnostics {
-      display_diagnostic(&diagnostic, &source_code);
+fn run_linter(paths: Vec<String>, format: OutputFormat) {
+  // Collecting keeps the files in the order of `paths`, so that the output
+  // doesn't change between runs.
+  let files = paths
+    .par_iter()
+    .map(|file_path| {
+      let source_code =
+        std::fs::read_to_string(&file_path).expect("Failed to read file");
+
+      let mut linter = LinterBuilder::default()
+        .rules(get_recommended_rules())
+        .build();
+
+      let file_diagnostics = linter
+        .lint(file_path.to_string(), source_code.clone())
+        .expect("Failed to lint");
+
+      FileDiagnostics {
+        source_code,
+        diagnostics: file_diagnostics,
+      }
+    })
+    .collect::<Vec<FileDiagnostics>>();
+
+  let output = format.formatter(&get_recommended_rules()).format(&files);
+  if format == OutputFormat::Pretty {
+    eprint!("{}", output);
+    let problem_count: usize =
+      files.iter().map(|file| file.diagnostics.len()).sum();
+    if problem_count > 0 {
+      eprintln!("Found {} problems", problem_count);
     }
-  });
-
-  let err_count = error_counts.load(Ordering::Relaxed);
-  if err_count > 0 {
-    eprintln!("Found {} problems", err_count);
+  } else {
+    println!("{}", output);
+  }
+  // Only errors fail the run, warnings are just reported.
+  let has_errors = files
+    .iter()
+    .flat_map(|file| &file.diagnostics)
+    .any(|diagnostic| diagnostic.severity == Severity::Error);
+  if has_errors {
     std::process::exit(1);
   }
 }
@@ -214,7 +170,12 @@ fn main() {
         .unwrap()
         .map(|p| p.to_string())
         .collect();
-      run_linter(paths);
+      let format = match run_matches.value_of("format") {
+        Some("json") => OutputFormat::Json,
+        Some("sarif") => OutputFormat::Sarif,
+        _ => OutputFormat::Pretty,
+      };
+      run_linter(paths, format);
     }
     ("rules", Some(rules_matches)) => {
       let json = rules_matches.is_present("json");
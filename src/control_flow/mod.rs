@@ -171,7 +171,16 @@ impl Analyzer<'_> {
     // Preserve information about visited ast nodes.
     self.scope.may_throw |= may_throw;
     if self.scope.found_break.is_none() {
-      self.scope.found_break = found_break;
+      self.scope.found_break = match &kind {
+        // A break without label only exits the loop itself.
+        BlockKind::Loop => found_break.filter(|label| label.is_some()),
+        // A break with the label of a statement only exits that statement,
+        // even if the statement doesn't end, like a loop with a break.
+        BlockKind::Label(id) => {
+          found_break.filter(|label| label.as_ref() != Some(id))
+        }
+        _ => found_break,
+      };
     }
     self.scope.found_continue |= found_continue;
 
@@ -197,14 +206,7 @@ impl Analyzer<'_> {
             self.scope.end = prev_end;
           }
         },
-        BlockKind::Label(label) => {
-          if let Some(Some(id)) = &self.scope.found_break {
-            if *id == label {
-              // Eat break statemnt
-              self.scope.found_break = None;
-            }
-          }
-        }
+        BlockKind::Label(_) => {}
         BlockKind::Catch => {
           self.mark_as_end(lo, end);
         }
@@ -913,6 +915,25 @@ function foo() {
     assert_flow!(flow, 60, false, None); // `bar();`
   }
 
+  #[test]
+  fn labeled_break_1() {
+    let src = r#"
+function foo() {
+  for (;;) {
+    inner: for (;;) {
+      break inner;
+    }
+  }
+  bar();
+}
+    "#;
+    let flow = analyze_flow(src);
+    assert_flow!(flow, 16, false, Some(End::Forced)); // BlockStmt of `foo`
+    assert_flow!(flow, 20, false, Some(End::Forced)); // outer for stmt
+    assert_flow!(flow, 35, false, None); // labeled stmt
+    assert_flow!(flow, 84, true, None); // `bar();`
+  }
+
   #[test]
   fn try_1() {
     let src = r#"
@@ -1189,4 +1210,22 @@ throw err;
     assert_flow!(flow, 54, false, Some(End::Forced)); // return stmt
     assert_flow!(flow, 70, false, Some(End::Forced)); // throw stmt
   }
+
+  #[test]
+  fn switch_4() {
+    let src = r#"
+switch (foo) {
+  case 1:
+    do {
+      break;
+    } while (a);
+  default:
+    bar();
+}
+      "#;
+    let flow = analyze_flow(src);
+    assert_flow!(flow, 1, false, Some(End::Continue)); // switch stmt
+    assert_flow!(flow, 18, false, Some(End::Continue)); // `case 1`
+    assert_flow!(flow, 33, false, Some(End::Break)); // BlockStmt of do-while
+  }
 }
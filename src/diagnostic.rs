@@ -1,35 +1,74 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
+  /// The 1-based line number.
   pub line: usize,
+  /// The 0-based column, in UTF-16 code units as expected by editors.
   pub col: usize,
   pub byte_pos: usize,
 }
 
 impl Position {
   pub fn new(byte_pos: swc_common::BytePos, loc: swc_common::Loc) -> Self {
+    // Not using loc.col_display, because it leads to out-of-bounds columns if
+    // file contains non-narrow chars (like tabs).
+    // See: https://github.com/denoland/deno_lint/issues/139
+    // loc.col counts chars, which differs from UTF-16 code units for chars
+    // outside of the BMP (like emojis), so the column is computed from the
+    // source of the line instead.
+    let file = &loc.file;
+    let line_start = (file.lines[loc.line - 1] - file.start_pos).0 as usize;
+    let offset = (byte_pos - file.start_pos).0 as usize;
+    let col = file.src[line_start..offset].encode_utf16().count();
+
     Position {
       line: loc.line,
-      // Using loc.col instead of loc.col_display
-      // because it leads to out-of-bounds columns if file
-      // contains non-narrow chars (like tabs).
-      // See: https://github.com/denoland/deno_lint/issues/139
-      col: loc.col.0,
+      col,
       byte_pos: byte_pos.0.try_into().expect("Failed to convert byte_pos"),
     }
   }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+/// Returns the 1-based line and the 0-based column, in UTF-16 code units, of
+/// `byte_offset` in `source`. This scans `source` up to `byte_offset`, so
+/// `Position::new` uses the line index of the source map instead.
+pub fn byte_to_line_col_utf16(
+  source: &str,
+  byte_offset: usize,
+) -> (usize, usize) {
+  let before = &source[..byte_offset];
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+  let line = before.matches('\n').count() + 1;
+  let col = before[line_start..].encode_utf16().count();
+  (line, col)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Range {
   pub start: Position,
   pub end: Position,
 }
 
+/// How seriously a diagnostic of a rule should be treated.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+  /// The rule is disabled and doesn't report any diagnostics.
+  Off,
+  Warning,
+  Error,
+}
+
+impl Default for Severity {
+  fn default() -> Self {
+    Severity::Error
+  }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct LintDiagnostic {
   pub range: Range,
@@ -37,4 +76,73 @@ pub struct LintDiagnostic {
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  pub severity: Severity,
+  /// `true` if the rule provided a fix for the problem, which
+  /// `Linter::lint_and_fix` applies.
+  pub fixable: bool,
+  /// Alternative fixes for the problem, which are never applied
+  /// automatically.
+  pub suggestions: Vec<Suggestion>,
+}
+
+/// A replacement of the source text covered by `range` with `new_text`,
+/// provided by rules which can fix the problems they report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextChange {
+  pub range: Range,
+  pub new_text: String,
+}
+
+/// A fix for a problem which is one of several possible intents, so it's
+/// left to the user to pick one of the suggestions of a diagnostic.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+  pub description: String,
+  pub changes: Vec<TextChange>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_byte_to_line_col_utf16() {
+    assert_eq!(byte_to_line_col_utf16("", 0), (1, 0));
+    assert_eq!(byte_to_line_col_utf16("foo\nbar", 2), (1, 2));
+    assert_eq!(byte_to_line_col_utf16("foo\nbar", 4), (2, 0));
+    assert_eq!(byte_to_line_col_utf16("foo\nbar", 7), (2, 3));
+
+    // "é" is two bytes, but a single UTF-16 code unit.
+    let source = "é = 1;\nfoo;";
+    assert_eq!(byte_to_line_col_utf16(source, 2), (1, 1));
+    assert_eq!(byte_to_line_col_utf16(source, 8), (2, 0));
+
+    // "😀" is four bytes, and two UTF-16 code units.
+    let source = "😀 foo;\n😀😀 bar;";
+    assert_eq!(byte_to_line_col_utf16(source, 5), (1, 3));
+    assert_eq!(byte_to_line_col_utf16(source, 19), (2, 5));
+    assert_eq!(&source[19..22], "bar");
+  }
+
+  #[test]
+  fn test_position_new() {
+    let source = "é = 1;\r\n😀 foo;\n\n😀😀 bar;";
+    let source_map = swc_common::SourceMap::default();
+    let file = source_map
+      .new_source_file(swc_common::FileName::Anon, source.to_string());
+
+    for (offset, _) in source.char_indices().chain(Some((source.len(), ' '))) {
+      let byte_pos = file.start_pos + swc_common::BytePos(offset as u32);
+      let position =
+        Position::new(byte_pos, source_map.lookup_char_pos(byte_pos));
+      assert_eq!(
+        (position.line, position.col),
+        byte_to_line_col_utf16(source, offset),
+        "offset {}",
+        offset
+      );
+      assert_eq!(position.byte_pos, byte_pos.0 as usize);
+    }
+  }
 }
@@ -0,0 +1,459 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::{LintDiagnostic, Range, Severity, Suggestion};
+use crate::rules::LintRule;
+use annotate_snippets::display_list;
+use annotate_snippets::snippet;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_json::Value;
+
+/// The diagnostics of a linted file, along with its source code.
+pub struct FileDiagnostics {
+  pub source_code: String,
+  pub diagnostics: Vec<LintDiagnostic>,
+}
+
+/// Turns the diagnostics of the linted files into the output of the linter.
+pub trait Formatter {
+  fn format(&self, files: &[FileDiagnostics]) -> String;
+}
+
+/// The formats the linter can output diagnostics in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+  /// Human readable output, showing the source of every diagnostic.
+  Pretty,
+  /// A JSON array of diagnostics, see `JsonDiagnostic`.
+  Json,
+  /// A SARIF 2.1.0 log, as expected by code scanning tools.
+  Sarif,
+}
+
+impl OutputFormat {
+  /// Returns the formatter of this format. `rules` are the rules used to
+  /// lint, which are described in the SARIF output.
+  pub fn formatter(self, rules: &[Box<dyn LintRule>]) -> Box<dyn Formatter> {
+    match self {
+      OutputFormat::Pretty => Box::new(PrettyFormatter),
+      OutputFormat::Json => Box::new(JsonFormatter),
+      OutputFormat::Sarif => Box::new(SarifFormatter::new(rules)),
+    }
+  }
+}
+
+pub struct PrettyFormatter;
+
+impl Formatter for PrettyFormatter {
+  fn format(&self, files: &[FileDiagnostics]) -> String {
+    let mut output = String::new();
+    for file in files {
+      for diagnostic in &file.diagnostics {
+        output
+          .push_str(&format_pretty_diagnostic(diagnostic, &file.source_code));
+        output.push('\n');
+      }
+    }
+    output
+  }
+}
+
+// Return slice of source code covered by diagnostic
+// and adjusted range of diagnostic (ie. original range - start line
+// of sliced source code).
+fn get_slice_source_and_range<'a>(
+  line_start_indexes: &[(usize, usize)],
+  source: &'a str,
+  range: &Range,
+) -> (&'a str, (usize, usize)) {
+  let (_, first_line_start) = line_start_indexes[range.start.line - 1];
+  let (last_line_no, _) = line_start_indexes[range.end.line - 1];
+  // The last line of the source has no line break to end it.
+  let last_line_end = line_start_indexes
+    .get(last_line_no + 1)
+    .map_or(source.len(), |(_, next_line_start)| next_line_start - 1);
+  let adjusted_start = range.start.byte_pos - first_line_start;
+  let adjusted_end = range.end.byte_pos - first_line_start;
+  let adjusted_range = (adjusted_start, adjusted_end);
+  let slice_str = &source[first_line_start..last_line_end];
+  (slice_str, adjusted_range)
+}
+
+fn format_pretty_diagnostic(
+  diagnostic: &LintDiagnostic,
+  source: &str,
+) -> String {
+  let line_start_indexes = std::iter::once(0)
+    .chain(source.match_indices('\n').map(|l| l.0 + 1))
+    .enumerate()
+    .collect::<Vec<_>>();
+  let (slice_source, range) =
+    get_slice_source_and_range(&line_start_indexes, source, &diagnostic.range);
+
+  let annotation_type = match diagnostic.severity {
+    Severity::Warning => snippet::AnnotationType::Warning,
+    _ => snippet::AnnotationType::Error,
+  };
+
+  let suggestions = diagnostic
+    .suggestions
+    .iter()
+    .map(|suggestion| format!("suggestion: {}", suggestion.description))
+    .collect::<Vec<String>>();
+  let footer = diagnostic
+    .hint
+    .iter()
+    .map(|hint| snippet::Annotation {
+      label: Some(hint),
+      id: None,
+      annotation_type: snippet::AnnotationType::Help,
+    })
+    .chain(suggestions.iter().map(|suggestion| snippet::Annotation {
+      label: Some(suggestion),
+      id: None,
+      annotation_type: snippet::AnnotationType::Note,
+    }))
+    .collect();
+
+  let snippet = snippet::Snippet {
+    title: Some(snippet::Annotation {
+      label: Some(&diagnostic.message),
+      id: Some(&diagnostic.code),
+      annotation_type,
+    }),
+    footer,
+    slices: vec![snippet::Slice {
+      source: &slice_source,
+      line_start: diagnostic.range.start.line,
+      origin: Some(&diagnostic.filename),
+      fold: false,
+      annotations: vec![snippet::SourceAnnotation {
+        range,
+        label: "",
+        annotation_type,
+      }],
+    }],
+    opt: display_list::FormatOptions {
+      color: true,
+      anonymized_line_numbers: false,
+      margin: None,
+    },
+  };
+  let display_list = display_list::DisplayList::from(snippet);
+  format!("{}", display_list)
+}
+
+/// A diagnostic as output by `JsonFormatter`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonDiagnostic {
+  pub code: String,
+  pub message: String,
+  /// The 1-based line number.
+  pub line: usize,
+  /// The 0-based column, in UTF-16 code units.
+  pub col: usize,
+  pub filename: String,
+  pub hint: Option<String>,
+  #[serde(default)]
+  pub severity: Severity,
+  /// `true` if `Linter::lint_and_fix` can fix the problem.
+  #[serde(default)]
+  pub fixable: bool,
+  /// Alternative fixes for the problem, see `LintDiagnostic::suggestions`.
+  #[serde(default)]
+  pub suggestions: Vec<Suggestion>,
+}
+
+impl From<&LintDiagnostic> for JsonDiagnostic {
+  fn from(diagnostic: &LintDiagnostic) -> Self {
+    JsonDiagnostic {
+      code: diagnostic.code.clone(),
+      message: diagnostic.message.clone(),
+      line: diagnostic.range.start.line,
+      col: diagnostic.range.start.col,
+      filename: diagnostic.filename.clone(),
+      hint: diagnostic.hint.clone(),
+      severity: diagnostic.severity,
+      fixable: diagnostic.fixable,
+      suggestions: diagnostic.suggestions.clone(),
+    }
+  }
+}
+
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+  fn format(&self, files: &[FileDiagnostics]) -> String {
+    let diagnostics = files
+      .iter()
+      .flat_map(|file| &file.diagnostics)
+      .map(JsonDiagnostic::from)
+      .collect::<Vec<JsonDiagnostic>>();
+    serde_json::to_string_pretty(&diagnostics).unwrap()
+  }
+}
+
+/// Outputs a SARIF 2.1.0 log with a single run, describing the rules used
+/// to lint with their docs.
+pub struct SarifFormatter {
+  /// Codes and docs of the rules.
+  rules: Vec<(&'static str, &'static str)>,
+}
+
+impl SarifFormatter {
+  pub fn new(rules: &[Box<dyn LintRule>]) -> Self {
+    SarifFormatter {
+      rules: rules
+        .iter()
+        .map(|rule| (rule.code(), rule.docs()))
+        .collect(),
+    }
+  }
+
+  fn sarif_rule(code: &str, docs: &str) -> Value {
+    let mut rule = json!({ "id": code });
+    // The first line of the docs is a summary of the rule.
+    if let Some(summary) = docs.lines().next() {
+      rule["shortDescription"] = json!({ "text": summary });
+      rule["fullDescription"] = json!({ "text": docs });
+    }
+    rule
+  }
+
+  fn sarif_result(&self, diagnostic: &LintDiagnostic) -> Value {
+    let level = match diagnostic.severity {
+      Severity::Warning => "warning",
+      _ => "error",
+    };
+    let message = match &diagnostic.hint {
+      Some(hint) => format!("{}\n\n{}", diagnostic.message, hint),
+      None => diagnostic.message.clone(),
+    };
+    let range = &diagnostic.range;
+    // SARIF columns are 1-based and count UTF-16 code units by default,
+    // like the columns of `Position`.
+    let mut result = json!({
+      "ruleId": diagnostic.code,
+      "level": level,
+      "message": { "text": message },
+      "locations": [{
+        "physicalLocation": {
+          "artifactLocation": { "uri": diagnostic.filename },
+          "region": {
+            "startLine": range.start.line,
+            "startColumn": range.start.col + 1,
+            "endLine": range.end.line,
+            "endColumn": range.end.col + 1,
+          },
+        },
+      }],
+    });
+    // Diagnostics reported by the linter itself, as in `ban-unused-ignore`,
+    // don't belong to any of the rules.
+    if let Some(index) = self
+      .rules
+      .iter()
+      .position(|(code, _)| *code == diagnostic.code)
+    {
+      result["ruleIndex"] = json!(index);
+    }
+    result
+  }
+}
+
+impl Formatter for SarifFormatter {
+  fn format(&self, files: &[FileDiagnostics]) -> String {
+    let rules = self
+      .rules
+      .iter()
+      .map(|(code, docs)| Self::sarif_rule(code, docs))
+      .collect::<Vec<Value>>();
+    let results = files
+      .iter()
+      .flat_map(|file| &file.diagnostics)
+      .map(|diagnostic| self.sarif_result(diagnostic))
+      .collect::<Vec<Value>>();
+
+    let log = json!({
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "deno_lint",
+            "version": env!("CARGO_PKG_VERSION"),
+            "informationUri": "https://github.com/denoland/deno_lint",
+            "rules": rules,
+          },
+        },
+        "results": results,
+      }],
+    });
+    serde_json::to_string_pretty(&log).unwrap()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::LinterBuilder;
+  use crate::rules::eqeqeq::Eqeqeq;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_explicit_any::NoExplicitAny;
+  use crate::rules::no_var::NoVar;
+  use crate::rules::LintRule;
+  use std::collections::HashMap;
+
+  fn rules() -> Vec<Box<dyn LintRule>> {
+    vec![NoDebugger::new(), NoExplicitAny::new(), NoVar::new()]
+  }
+
+  fn lint(source_code: &str) -> Vec<FileDiagnostics> {
+    let mut severities = HashMap::new();
+    severities.insert("no-var", Severity::Warning);
+    let mut linter = LinterBuilder::default()
+      .rules(rules())
+      .severities(severities)
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), source_code.to_string())
+      .expect("Failed to lint");
+    vec![FileDiagnostics {
+      source_code: source_code.to_string(),
+      diagnostics,
+    }]
+  }
+
+  const SOURCE: &str = r#"
+// deno-lint-ignore no-debugger
+var a = 1;
+let b: any;
+debugger;
+"#;
+
+  #[test]
+  fn json_round_trip() {
+    let files = lint(SOURCE);
+    let output = OutputFormat::Json.formatter(&rules()).format(&files);
+    let diagnostics: Vec<JsonDiagnostic> =
+      serde_json::from_str(&output).unwrap();
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_eq!(
+      diagnostics,
+      files[0]
+        .diagnostics
+        .iter()
+        .map(JsonDiagnostic::from)
+        .collect::<Vec<JsonDiagnostic>>()
+    );
+    assert_eq!(
+      diagnostics[3],
+      JsonDiagnostic {
+        code: "no-debugger".to_string(),
+        message: "`debugger` statement is not allowed".to_string(),
+        line: 5,
+        col: 0,
+        filename: "lint_test.ts".to_string(),
+        hint: None,
+        severity: Severity::Error,
+        fixable: false,
+        suggestions: vec![],
+      }
+    );
+    assert_eq!(diagnostics[1].code, "no-var");
+    assert_eq!(diagnostics[1].severity, Severity::Warning);
+
+    let mut linter =
+      LinterBuilder::default().rules(vec![Eqeqeq::new()]).build();
+    let files = vec![FileDiagnostics {
+      source_code: r#"typeof a == "b";"#.to_string(),
+      diagnostics: linter
+        .lint(
+          "lint_test.ts".to_string(),
+          r#"typeof a == "b";"#.to_string(),
+        )
+        .expect("Failed to lint"),
+    }];
+    let output = OutputFormat::Json.formatter(&rules()).format(&files);
+    let json: Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json[0]["code"], "eqeqeq");
+    assert_eq!(json[0]["fixable"], true);
+    assert_eq!(json[0]["severity"], "error");
+
+    // Both fields are optional when reading the output.
+    let diagnostic: JsonDiagnostic = serde_json::from_str(
+      r#"{ "code": "a", "message": "b", "line": 1, "col": 0, "filename": "c", "hint": null }"#,
+    )
+    .unwrap();
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert!(!diagnostic.fixable);
+  }
+
+  #[test]
+  fn sarif_required_fields() {
+    let files = lint(SOURCE);
+    let output = OutputFormat::Sarif.formatter(&rules()).format(&files);
+    let log: Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(log["version"], "2.1.0");
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+
+    let driver = &runs[0]["tool"]["driver"];
+    assert_eq!(driver["name"], "deno_lint");
+    let sarif_rules = driver["rules"].as_array().unwrap();
+    assert_eq!(sarif_rules.len(), 3);
+    for (sarif_rule, rule) in sarif_rules.iter().zip(rules()) {
+      assert_eq!(sarif_rule["id"], rule.code());
+    }
+
+    let results = runs[0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 4);
+    for result in results {
+      assert!(result["message"]["text"].is_string());
+      assert!(result["ruleId"].is_string());
+      let region = &result["locations"][0]["physicalLocation"]["region"];
+      assert!(region["startLine"].as_u64().unwrap() >= 1);
+      assert!(region["startColumn"].as_u64().unwrap() >= 1);
+    }
+
+    assert_eq!(results[0]["ruleId"], "ban-unused-ignore");
+    assert!(results[0].get("ruleIndex").is_none());
+    assert_eq!(results[1]["ruleId"], "no-var");
+    assert_eq!(results[1]["ruleIndex"], 2);
+    assert_eq!(results[1]["level"], "warning");
+    assert_eq!(results[3]["ruleId"], "no-debugger");
+    assert_eq!(results[3]["ruleIndex"], 0);
+    assert_eq!(results[3]["level"], "error");
+    assert_eq!(
+      results[3]["locations"][0]["physicalLocation"]["region"]["startLine"],
+      5
+    );
+  }
+
+  #[test]
+  fn pretty_output() {
+    let files = lint(SOURCE);
+    let output = OutputFormat::Pretty.formatter(&rules()).format(&files);
+
+    assert!(output.contains("no-explicit-any"));
+    assert!(output.contains("lint_test.ts"));
+    assert!(output.contains("debugger;"));
+  }
+
+  #[test]
+  fn pretty_output_without_trailing_newline() {
+    let files = lint(
+      "let a: any;
+debugger;",
+    );
+    let output = OutputFormat::Pretty.formatter(&rules()).format(&files);
+
+    assert!(output.contains("let a: any;"));
+    assert!(output.contains("debugger;"));
+
+    let files = lint("debugger;");
+    let output = OutputFormat::Pretty.formatter(&rules()).format(&files);
+    assert!(output.contains("debugger;"));
+  }
+}
@@ -14,19 +14,26 @@ mod test_util;
 
 mod control_flow;
 pub mod diagnostic;
+pub mod formatter;
 mod globals;
 mod js_regex;
 pub mod linter;
 pub mod rules;
 mod scopes;
+mod source_text;
 pub mod swc_util;
 
 #[cfg(test)]
 mod lint_tests {
-  use crate::diagnostic::LintDiagnostic;
+  use crate::diagnostic::{LintDiagnostic, Severity};
   use crate::linter::*;
   use crate::rules::get_recommended_rules;
+  use crate::rules::no_cond_assign::NoCondAssign;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_var::NoVar;
+  use crate::rules::LintRule;
   use crate::test_util::assert_diagnostic;
+  use std::collections::HashMap;
 
   fn lint(
     source: &str,
@@ -160,4 +167,142 @@ mod lint_tests {
     assert_eq!(diagnostics.len(), 1);
     assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 4, 1, src);
   }
+  #[test]
+  fn ignore_all_rules_without_codes() {
+    let src = r#"
+ function foo() {
+   // deno-lint-ignore
+   var bar_foo = 1;
+   var foo_bar = 2;
+ }
+      "#;
+    let diagnostics = lint(src, false, true);
+
+    assert_diagnostic(&diagnostics[0], "ban-untagged-ignore", 3, 3, src);
+    assert!(diagnostics[1..].iter().all(|d| d.range.start.line == 5));
+    assert!(diagnostics.len() > 1);
+  }
+
+  #[test]
+  fn warn_unused_dir_without_codes() {
+    let src = r#"
+ // deno-lint-ignore
+ function foo() {}
+      "#;
+    let diagnostics = lint(src, false, true);
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "ban-untagged-ignore", 2, 1, src);
+    assert_diagnostic(&diagnostics[1], "ban-unused-ignore", 2, 1, src);
+    assert_eq!(diagnostics[1].message, "Ignore directive was not used.");
+
+    let diagnostics = lint(src, false, false);
+    assert_eq!(diagnostics.len(), 1);
+  }
+
+  #[test]
+  fn configured_severities() {
+    let src = r#"
+ const foo = Math.random();
+ if (foo) {}
+ let baz: any;
+ debugger;
+      "#;
+    let mut severities = HashMap::new();
+    severities.insert("no-empty", Severity::Warning);
+    severities.insert("no-explicit-any", Severity::Off);
+    let mut linter = LinterBuilder::default()
+      .rules(get_recommended_rules())
+      .severities(severities)
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-empty", 3, 10, src);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 5, 1, src);
+    assert_eq!(diagnostics[1].severity, Severity::Error);
+  }
+
+  #[test]
+  fn ignore_dir_for_disabled_rule() {
+    let src = r#"
+ // deno-lint-ignore no-explicit-any
+ let bar: string;
+      "#;
+    let mut severities = HashMap::new();
+    severities.insert("no-explicit-any", Severity::Off);
+    let mut linter = LinterBuilder::default()
+      .rules(get_recommended_rules())
+      .severities(severities)
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn utf16_columns() {
+    let src = r#"
+ const s = "😀"; debugger;
+ const t = "é"; debugger;
+      "#;
+    let diagnostics = lint(src, false, false);
+
+    assert_eq!(diagnostics.len(), 2);
+    // The emoji is a single char, but two UTF-16 code units.
+    assert_diagnostic(&diagnostics[0], "no-debugger", 2, 17, src);
+    assert_eq!(diagnostics[0].range.end.col, 26);
+    assert_eq!(diagnostics[0].range.start.byte_pos, 20);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 3, 16, src);
+    assert_eq!(diagnostics[1].range.end.col, 25);
+  }
+
+  #[test]
+  fn fixable_diagnostics() {
+    let src = r#"
+ if (x = 0) {}
+ debugger;
+      "#;
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoCondAssign::new(), NoDebugger::new()])
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-cond-assign", 2, 5, src);
+    assert!(diagnostics[0].fixable);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 3, 1, src);
+    assert!(!diagnostics[1].fixable);
+
+    let json = serde_json::to_value(&diagnostics).unwrap();
+    assert_eq!(json[0]["fixable"], true);
+    assert_eq!(json[1]["fixable"], false);
+  }
+
+  #[test]
+  fn diagnostics_sorted_by_position() {
+    let src = r#"
+ var a = 1; debugger; var b = 2;
+ debugger;
+      "#;
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoVar::new(), NoDebugger::new()])
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_diagnostic(&diagnostics[0], "no-var", 2, 1, src);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 2, 12, src);
+    assert_diagnostic(&diagnostics[2], "no-var", 2, 22, src);
+    assert_diagnostic(&diagnostics[3], "no-debugger", 3, 1, src);
+  }
 }
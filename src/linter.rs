@@ -1,13 +1,18 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{
+  LintDiagnostic, Position, Range, Severity, Suggestion, TextChange,
+};
 use crate::rules::LintRule;
 use crate::scopes::{analyze, Scope};
+use crate::source_text::SourceText;
 use crate::swc_util::get_default_ts_config;
 use crate::swc_util::AstParser;
 use crate::{control_flow::ControlFlow, swc_util::SwcDiagnosticBuffer};
+use rayon::prelude::*;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Instant;
 use swc_common::comments::CommentKind;
 use swc_common::comments::SingleThreadedComments;
@@ -15,38 +20,57 @@ use swc_common::BytePos;
 use swc_common::SourceMap;
 use swc_common::Span;
 use swc_common::{comments::Comment, SyntaxContext};
+use swc_ecmascript::ast::{Ident, Module};
 use swc_ecmascript::parser::Syntax;
+use swc_ecmascript::utils::ident::IdentLike;
 
 lazy_static! {
   static ref IGNORE_COMMENT_CODE_RE: regex::Regex =
     regex::Regex::new(r",\s*|\s").unwrap();
 }
 
+/// Default maximum number of times `Linter::lint_and_fix` re-lints the fixed
+/// source, so that rules producing fixes which don't settle can't loop forever.
+const DEFAULT_MAX_FIX_PASSES: usize = 10;
+
+/// The state of a single rule linting a module. Each rule gets its own
+/// context, whose read-only parts are shared with the other rules.
 pub struct Context {
   pub file_name: String,
   pub diagnostics: Vec<LintDiagnostic>,
-  pub source_map: Rc<SourceMap>,
-  pub(crate) leading_comments: HashMap<BytePos, Vec<Comment>>,
-  pub(crate) trailing_comments: HashMap<BytePos, Vec<Comment>>,
+  pub source_map: Arc<SourceMap>,
+  source_text: Arc<SourceText>,
+  pub(crate) leading_comments: Arc<HashMap<BytePos, Vec<Comment>>>,
+  pub(crate) trailing_comments: Arc<HashMap<BytePos, Vec<Comment>>>,
   pub ignore_directives: RefCell<Vec<IgnoreDirective>>,
-  pub(crate) scope: Scope,
-  pub(crate) control_flow: ControlFlow,
+  pub(crate) scope: Arc<Scope>,
+  control_flow: Arc<ControlFlow>,
   pub(crate) top_level_ctxt: SyntaxContext,
+  pub(crate) severities: Arc<HashMap<String, Severity>>,
+  /// Changes fixing the reported diagnostics, along with the code of the rule
+  /// which provided them.
+  pub(crate) fixes: Vec<(String, TextChange)>,
+  /// The code of the rule this context belongs to, which is `None` while
+  /// the linter itself reports problems.
+  rule_code: Option<&'static str>,
 }
 
 impl Context {
-  pub(crate) fn add_diagnostic(
+  /// Reports a problem found in `span` by the rule `code`.
+  pub fn add_diagnostic(
     &mut self,
     span: Span,
     code: impl Into<String>,
     message: impl Into<String>,
   ) {
     let diagnostic = self.create_diagnostic(span, code, message, None);
-    self.diagnostics.push(diagnostic);
+    self.debug_assert_rule_code(&diagnostic.code);
+    if diagnostic.severity != Severity::Off {
+      self.diagnostics.push(diagnostic);
+    }
   }
 
-  #[allow(unused)]
-  pub(crate) fn add_diagnostic_with_hint(
+  pub fn add_diagnostic_with_hint(
     &mut self,
     span: Span,
     code: impl Into<String>,
@@ -55,9 +79,116 @@ impl Context {
   ) {
     let diagnostic =
       self.create_diagnostic(span, code, message, Some(hint.into()));
+    self.debug_assert_rule_code(&diagnostic.code);
+    if diagnostic.severity != Severity::Off {
+      self.diagnostics.push(diagnostic);
+    }
+  }
+
+  /// Adds a diagnostic which can be fixed automatically by applying
+  /// `changes`, see `Context::create_text_change`.
+  pub fn add_diagnostic_with_fix(
+    &mut self,
+    span: Span,
+    code: impl Into<String>,
+    message: impl Into<String>,
+    changes: Vec<TextChange>,
+  ) {
+    let mut diagnostic = self.create_diagnostic(span, code, message, None);
+    self.debug_assert_rule_code(&diagnostic.code);
+    if diagnostic.severity == Severity::Off {
+      return;
+    }
+    diagnostic.fixable = true;
+    for change in changes {
+      self.fixes.push((diagnostic.code.clone(), change));
+    }
     self.diagnostics.push(diagnostic);
   }
 
+  /// Adds a diagnostic with alternative fixes, which unlike the fixes of
+  /// `Context::add_diagnostic_with_fix` aren't applied by
+  /// `Linter::lint_and_fix`.
+  pub fn add_diagnostic_with_suggestions(
+    &mut self,
+    span: Span,
+    code: impl Into<String>,
+    message: impl Into<String>,
+    suggestions: Vec<Suggestion>,
+  ) {
+    let diagnostic = self.create_diagnostic(span, code, message, None);
+    self.push_with_suggestions(diagnostic, suggestions);
+  }
+
+  /// Like `Context::add_diagnostic_with_suggestions`, but also with a hint.
+  pub fn add_diagnostic_with_hint_and_suggestions(
+    &mut self,
+    span: Span,
+    code: impl Into<String>,
+    message: impl Into<String>,
+    hint: impl Into<String>,
+    suggestions: Vec<Suggestion>,
+  ) {
+    let diagnostic =
+      self.create_diagnostic(span, code, message, Some(hint.into()));
+    self.push_with_suggestions(diagnostic, suggestions);
+  }
+
+  fn push_with_suggestions(
+    &mut self,
+    mut diagnostic: LintDiagnostic,
+    suggestions: Vec<Suggestion>,
+  ) {
+    self.debug_assert_rule_code(&diagnostic.code);
+    if diagnostic.severity != Severity::Off {
+      diagnostic.suggestions = suggestions;
+      self.diagnostics.push(diagnostic);
+    }
+  }
+
+  /// Returns the source code of the linted file.
+  pub fn source(&self) -> &str {
+    self.source_text.text()
+  }
+
+  /// Returns the text of the 1-based `line` of the linted file, without its
+  /// line break. The lines are indexed once and shared by all rules.
+  pub fn line_text(&self, line: usize) -> Option<&str> {
+    self.source_text.line_text(line)
+  }
+
+  /// Returns the control flow of the module, which is analyzed once and
+  /// shared by all rules.
+  pub(crate) fn control_flow(&self) -> &ControlFlow {
+    &self.control_flow
+  }
+
+  /// Checks if `ident` refers to a global rather than to a binding of the
+  /// module. Unresolved references have the top level syntax context. Top
+  /// level bindings have it as well, but those are known to the scope.
+  pub(crate) fn is_global_ident(&self, ident: &Ident) -> bool {
+    ident.span.ctxt == self.top_level_ctxt
+      && self.scope.var(&ident.to_id()).is_none()
+  }
+
+  /// Catches rules reporting diagnostics under the code of another rule, e.g.
+  /// after copying a hard-coded code from it.
+  fn debug_assert_rule_code(&self, code: &str) {
+    if let Some(rule_code) = self.rule_code {
+      debug_assert_eq!(
+        rule_code, code,
+        "The rule \"{}\" reported a diagnostic with the code \"{}\"",
+        rule_code, code
+      );
+    }
+  }
+
+  /// Returns the configured severity of the rule `code`, which is
+  /// `Severity::Error` unless configured otherwise.
+  pub(crate) fn severity(&self, code: &str) -> Severity {
+    self.severities.get(code).copied().unwrap_or_default()
+  }
+
   fn create_diagnostic(
     &self,
     span: Span,
@@ -71,12 +202,17 @@ impl Context {
     let end =
       Position::new(span.hi(), self.source_map.lookup_char_pos(span.hi()));
 
+    let code = code.into();
+    let severity = self.severity(&code);
     let diagnostic = LintDiagnostic {
       range: Range { start, end },
       filename: self.file_name.clone(),
       message: message.into(),
-      code: code.into(),
+      code,
       hint: maybe_hint,
+      severity,
+      fixable: false,
+      suggestions: vec![],
     };
 
     let time_end = Instant::now();
@@ -86,6 +222,67 @@ impl Context {
     );
     diagnostic
   }
+
+  /// Creates a change replacing the source text covered by `span` with
+  /// `new_text`, to be passed to `Context::add_diagnostic_with_fix`.
+  pub fn create_text_change(
+    &self,
+    span: Span,
+    new_text: impl Into<String>,
+  ) -> TextChange {
+    let start =
+      Position::new(span.lo(), self.source_map.lookup_char_pos(span.lo()));
+    let end =
+      Position::new(span.hi(), self.source_map.lookup_char_pos(span.hi()));
+
+    TextChange {
+      range: Range { start, end },
+      new_text: new_text.into(),
+    }
+  }
+}
+
+/// The parts of `Context` which are the same for all rules, so that the rules
+/// can lint a module in parallel.
+struct SharedContext {
+  file_name: String,
+  source_map: Arc<SourceMap>,
+  source_text: Arc<SourceText>,
+  leading_comments: Arc<HashMap<BytePos, Vec<Comment>>>,
+  trailing_comments: Arc<HashMap<BytePos, Vec<Comment>>>,
+  ignore_directives: Vec<IgnoreDirective>,
+  scope: Arc<Scope>,
+  control_flow: Arc<ControlFlow>,
+  top_level_ctxt: SyntaxContext,
+  severities: Arc<HashMap<String, Severity>>,
+}
+
+impl SharedContext {
+  /// Creates a context with empty diagnostics and fixes.
+  fn context(&self) -> Context {
+    Context {
+      file_name: self.file_name.clone(),
+      diagnostics: vec![],
+      source_map: self.source_map.clone(),
+      source_text: self.source_text.clone(),
+      leading_comments: self.leading_comments.clone(),
+      trailing_comments: self.trailing_comments.clone(),
+      ignore_directives: RefCell::new(self.ignore_directives.clone()),
+      scope: self.scope.clone(),
+      control_flow: self.control_flow.clone(),
+      top_level_ctxt: self.top_level_ctxt,
+      severities: self.severities.clone(),
+      fixes: vec![],
+      rule_code: None,
+    }
+  }
+
+  fn run_rule(&self, rule: &dyn LintRule, module: &Module) -> Context {
+    let mut context = self.context();
+    context.rule_code = Some(rule.code());
+    rule.lint_module(&mut context, module);
+    context
+  }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -94,6 +291,8 @@ pub struct IgnoreDirective {
   pub span: Span,
   pub codes: Vec<String>,
   pub used_codes: HashMap<String, bool>,
+  /// `true` if a directive without codes ignored a diagnostic.
+  pub used: bool,
   pub is_global: bool,
 }
 
@@ -111,6 +310,12 @@ impl IgnoreDirective {
       return false;
     }
 
+    // A directive without codes ignores all rules.
+    if self.codes.is_empty() {
+      self.used = true;
+      return true;
+    }
+
     let mut should_ignore = false;
     for code in self.codes.iter() {
       // `ends_with` allows to skip `@typescript-eslint` prefix - not ideal
@@ -123,6 +328,16 @@ impl IgnoreDirective {
 
     should_ignore
   }
+
+  /// Check if `IgnoreDirective` supresses diagnostics of the rule `code` on
+  /// `line`, without marking it as used.
+  fn ignores(&self, code: &str, line: usize) -> bool {
+    if !self.is_global && self.position.line != line - 1 {
+      return false;
+    }
+
+    self.codes.is_empty() || self.codes.iter().any(|c| c.ends_with(code))
+  }
 }
 
 pub struct LinterBuilder {
@@ -132,6 +347,10 @@ pub struct LinterBuilder {
   lint_unknown_rules: bool,
   syntax: swc_ecmascript::parser::Syntax,
   rules: Vec<Box<dyn LintRule>>,
+  custom_rules: Vec<Box<dyn LintRule>>,
+  severities: HashMap<String, Severity>,
+  max_fix_passes: usize,
+  parallel: bool,
 }
 
 impl LinterBuilder {
@@ -143,17 +362,30 @@ impl LinterBuilder {
       lint_unknown_rules: true,
       syntax: get_default_ts_config(),
       rules: vec![],
+      custom_rules: vec![],
+      severities: HashMap::new(),
+      max_fix_passes: DEFAULT_MAX_FIX_PASSES,
+      parallel: true,
     }
   }
 
   pub fn build(self) -> Linter {
+    let mut rules = self.rules;
+    for custom_rule in self.custom_rules {
+      rules.retain(|rule| rule.code() != custom_rule.code());
+      rules.push(custom_rule);
+    }
+
     Linter::new(
       self.ignore_file_directives,
       self.ignore_diagnostic_directives,
       self.lint_unused_ignore_directives,
       self.lint_unknown_rules,
       self.syntax,
-      self.rules,
+      rules,
+      self.severities,
+      self.max_fix_passes,
+      self.parallel,
     )
   }
 
@@ -191,6 +423,38 @@ impl LinterBuilder {
     self.rules = rules;
     self
   }
+
+  /// Adds rules implemented outside of this crate to the ones set with
+  /// `LinterBuilder::rules`. A custom rule replaces any other rule with the
+  /// same code, including a built-in one.
+  pub fn custom_rules(mut self, rules: Vec<Box<dyn LintRule>>) -> Self {
+    self.custom_rules.extend(rules);
+    self
+  }
+
+  /// Sets the severity of the diagnostics of each rule code. Rules which
+  /// aren't in the map report errors, and rules mapped to `Severity::Off`
+  /// don't report anything.
+  pub fn severities(mut self, severities: HashMap<&str, Severity>) -> Self {
+    self.severities = severities
+      .into_iter()
+      .map(|(code, severity)| (code.to_string(), severity))
+      .collect();
+    self
+  }
+
+  /// Sets how many times `Linter::lint_and_fix` applies fixes at most.
+  pub fn max_fix_passes(mut self, max_fix_passes: usize) -> Self {
+    self.max_fix_passes = max_fix_passes;
+    self
+  }
+
+  /// Sets whether the rules lint a module in parallel, which is the default.
+  /// The diagnostics are the same either way.
+  pub fn parallel(mut self, parallel: bool) -> Self {
+    self.parallel = parallel;
+    self
+  }
 }
 
 pub struct Linter {
@@ -202,6 +466,9 @@ pub struct Linter {
   lint_unknown_rules: bool,
   syntax: Syntax,
   rules: Vec<Box<dyn LintRule>>,
+  severities: Arc<HashMap<String, Severity>>,
+  max_fix_passes: usize,
+  parallel: bool,
 }
 
 impl Linter {
@@ -212,6 +479,9 @@ impl Linter {
     lint_unknown_rules: bool,
     syntax: Syntax,
     rules: Vec<Box<dyn LintRule>>,
+    severities: HashMap<String, Severity>,
+    max_fix_passes: usize,
+    parallel: bool,
   ) -> Self {
     Linter {
       has_linted: false,
@@ -222,9 +492,15 @@ impl Linter {
       lint_unknown_rules,
       syntax,
       rules,
+      severities: Arc::new(severities),
+      max_fix_passes,
+      parallel,
     }
   }
 
+  /// Lints the module and returns the diagnostics sorted by their start and
+  /// end positions, then by their code, independently of the order of the
+  /// rules.
   pub fn lint(
     &mut self,
     file_name: String,
@@ -236,27 +512,87 @@ impl Linter {
     );
     self.has_linted = true;
     let start = Instant::now();
-    let diagnostics = if source_code.is_empty() {
-      vec![]
-    } else {
-      let (parse_result, comments) =
-        self
-          .ast_parser
-          .parse_module(&file_name, self.syntax, &source_code);
-      let end_parse_module = Instant::now();
-      debug!(
-        "ast_parser.parse_module took {:#?}",
-        end_parse_module - start
-      );
-      let module = parse_result?;
-      self.lint_module(file_name, module, comments)
-    };
+    let (diagnostics, _) = self.lint_source(file_name, &source_code, false)?;
 
     let end = Instant::now();
     debug!("Linter::lint took {:#?}", end - start);
     Ok(diagnostics)
   }
 
+  /// Lints the module and applies the fixes provided by the rules.
+  ///
+  /// Overlapping fixes can't be applied at once, so only the first of them is
+  /// applied and the fixed source is linted again, until there's nothing left
+  /// to fix, the fixes don't change the source anymore, or the maximum number
+  /// of passes set with `LinterBuilder::max_fix_passes` is reached. Returns the
+  /// fixed source code along with the diagnostics which remain in it.
+  pub fn lint_and_fix(
+    &mut self,
+    file_name: String,
+    source_code: String,
+  ) -> Result<(String, Vec<LintDiagnostic>), SwcDiagnosticBuffer> {
+    assert!(
+      !self.has_linted,
+      "Linter can be used only on a single module."
+    );
+    self.has_linted = true;
+    let start = Instant::now();
+    let mut source_code = source_code;
+
+    for pass in 0..self.max_fix_passes {
+      // Every pass parses a new source file, and the byte positions of the
+      // changes are only valid if it's the first one in the source map.
+      if pass > 0 {
+        self.ast_parser = AstParser::new();
+      }
+      let (diagnostics, changes) =
+        self.lint_source(file_name.clone(), &source_code, true)?;
+      let fixed_source_code = if changes.is_empty() {
+        None
+      } else {
+        Some(apply_text_changes(&source_code, changes))
+      };
+      match fixed_source_code {
+        Some(fixed) if fixed != source_code => source_code = fixed,
+        _ => {
+          let end = Instant::now();
+          debug!("Linter::lint_and_fix took {:#?}", end - start);
+          return Ok((source_code, diagnostics));
+        }
+      }
+    }
+
+    self.ast_parser = AstParser::new();
+    let (diagnostics, _) = self.lint_source(file_name, &source_code, false)?;
+    let end = Instant::now();
+    debug!("Linter::lint_and_fix took {:#?}", end - start);
+    Ok((source_code, diagnostics))
+  }
+
+  fn lint_source(
+    &self,
+    file_name: String,
+    source_code: &str,
+    fix: bool,
+  ) -> Result<(Vec<LintDiagnostic>, Vec<TextChange>), SwcDiagnosticBuffer> {
+    if source_code.is_empty() {
+      return Ok((vec![], vec![]));
+    }
+
+    let start = Instant::now();
+    let (parse_result, comments) =
+      self
+        .ast_parser
+        .parse_module(&file_name, self.syntax, source_code);
+    let end_parse_module = Instant::now();
+    debug!(
+      "ast_parser.parse_module took {:#?}",
+      end_parse_module - start
+    );
+    let module = parse_result?;
+    Ok(self.lint_module(file_name, module, comments, fix))
+  }
+
   fn filter_diagnostics(
     &self,
     context: &mut Context,
@@ -287,10 +623,25 @@ impl Linter {
 
     if self.lint_unused_ignore_directives || self.lint_unknown_rules {
       for ignore_directive in ignore_directives.borrow().iter() {
+        if self.lint_unused_ignore_directives
+          && ignore_directive.codes.is_empty()
+          && !ignore_directive.used
+        {
+          filtered_diagnostics.push(context.create_diagnostic(
+            ignore_directive.span,
+            "ban-unused-ignore",
+            "Ignore directive was not used.",
+            None,
+          ));
+        }
+
         for (code, used) in ignore_directive.used_codes.iter() {
+          // Diagnostics of disabled rules are never reported, so there's
+          // nothing for the directive to ignore.
           if self.lint_unused_ignore_directives
             && !used
             && rule_codes.contains(code)
+            && context.severity(code) != Severity::Off
           {
             let diagnostic = context.create_diagnostic(
               ignore_directive.span,
@@ -314,7 +665,14 @@ impl Linter {
     }
 
     filtered_diagnostics
-      .sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
+      .retain(|diagnostic| diagnostic.severity != Severity::Off);
+    filtered_diagnostics.sort_by(|a, b| {
+      (a.range.start.byte_pos, a.range.end.byte_pos, &a.code).cmp(&(
+        b.range.start.byte_pos,
+        b.range.end.byte_pos,
+        &b.code,
+      ))
+    });
 
     let end = Instant::now();
     debug!("Linter::filter_diagnostics took {:#?}", end - start);
@@ -325,9 +683,10 @@ impl Linter {
   fn lint_module(
     &self,
     file_name: String,
-    module: swc_ecmascript::ast::Module,
+    module: Module,
     comments: SingleThreadedComments,
-  ) -> Vec<LintDiagnostic> {
+    fix: bool,
+  ) -> (Vec<LintDiagnostic>, Vec<TextChange>) {
     let start = Instant::now();
     let file_ignore_directive = comments.with_leading(module.span.lo(), |c| {
       let directives = c
@@ -353,7 +712,7 @@ impl Linter {
     // whole file and skip linting it.
     if let Some(ignore_directive) = &file_ignore_directive {
       if ignore_directive.codes.is_empty() {
-        return vec![];
+        return (vec![], vec![]);
       }
     }
 
@@ -380,33 +739,106 @@ impl Linter {
 
     let scope = analyze(&module);
     let control_flow = ControlFlow::analyze(&module);
+    // The module lies in the file that was just added to the source map.
+    let source_file = self
+      .ast_parser
+      .source_map
+      .lookup_char_pos(module.span.lo())
+      .file;
 
-    let mut context = Context {
+    let shared = SharedContext {
       file_name,
-      diagnostics: vec![],
       source_map: self.ast_parser.source_map.clone(),
-      leading_comments: leading,
-      trailing_comments: trailing,
-      ignore_directives: RefCell::new(ignore_directives),
-      scope,
-      control_flow,
+      source_text: Arc::new(SourceText::new(source_file)),
+      leading_comments: Arc::new(leading),
+      trailing_comments: Arc::new(trailing),
+      ignore_directives,
+      scope: Arc::new(scope),
+      control_flow: Arc::new(control_flow),
       top_level_ctxt: swc_common::GLOBALS.set(&self.ast_parser.globals, || {
         SyntaxContext::empty().apply_mark(self.ast_parser.top_level_mark)
       }),
+      severities: self.severities.clone(),
+    };
+
+    // Rules tagged "jsx" have nothing to lint unless JSX is parsed.
+    let jsx = self.syntax.jsx();
+    let rules: Vec<&dyn LintRule> = self
+      .rules
+      .iter()
+      .map(|rule| &**rule)
+      .filter(|rule| jsx || !rule.tags().contains(&"jsx"))
+      .collect();
+
+    // Every rule reports to its own context, the diagnostics are merged and
+    // sorted afterwards.
+    let rule_contexts: Vec<Context> = if self.parallel {
+      rules
+        .par_iter()
+        .map(|rule| shared.run_rule(*rule, &module))
+        .collect()
+    } else {
+      rules
+        .iter()
+        .map(|rule| shared.run_rule(*rule, &module))
+        .collect()
     };
 
-    for rule in &self.rules {
-      rule.lint_module(&mut context, &module);
+    let mut context = shared.context();
+    for rule_context in rule_contexts {
+      context.diagnostics.extend(rule_context.diagnostics);
+      context.fixes.extend(rule_context.fixes);
     }
 
+    let changes = if fix {
+      let ignore_directives = context.ignore_directives.borrow();
+      context
+        .fixes
+        .iter()
+        .filter(|(code, change)| {
+          !ignore_directives.iter().any(|ignore_directive| {
+            ignore_directive.ignores(code, change.range.start.line)
+          })
+        })
+        .map(|(_, change)| change.clone())
+        .collect()
+    } else {
+      vec![]
+    };
+
     let d = self.filter_diagnostics(&mut context, &self.rules);
     let end = Instant::now();
     debug!("Linter::lint_module took {:#?}", end - start);
 
-    d
+    (d, changes)
   }
 }
 
+/// Applies `changes` to `source_code`. Changes overlapping an earlier one are
+/// skipped, since they were computed for the original text.
+pub fn apply_text_changes(
+  source_code: &str,
+  mut changes: Vec<TextChange>,
+) -> String {
+  changes.sort_by_key(|change| {
+    (change.range.start.byte_pos, change.range.end.byte_pos)
+  });
+
+  let mut fixed = String::with_capacity(source_code.len());
+  let mut last_end = 0;
+  for change in changes {
+    let start = change.range.start.byte_pos;
+    if start < last_end {
+      continue;
+    }
+    fixed.push_str(&source_code[last_end..start]);
+    fixed.push_str(&change.new_text);
+    last_end = change.range.end.byte_pos;
+  }
+  fixed.push_str(&source_code[last_end..]);
+  fixed
+}
+
 fn parse_ignore_directives(
   ignore_diagnostic_directives: &[String],
   source_map: &SourceMap,
@@ -482,6 +914,7 @@ fn parse_ignore_comment(
           span: comment.span,
           codes,
           used_codes,
+          used: false,
           is_global,
         });
       }
@@ -494,7 +927,186 @@ fn parse_ignore_comment(
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::rules::get_all_rules;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_var::NoVar;
   use crate::swc_util;
+  use std::sync::Mutex;
+  use swc_common::Spanned;
+  use swc_ecmascript::ast::{Expr, Ident, Module, UnaryExpr, UnaryOp};
+  use swc_ecmascript::visit::{Node, VisitAll, VisitAllWith};
+
+  /// Reports `!!x`, fixing it to `x`.
+  struct NoDoubleNegation;
+
+  struct NoDoubleNegationVisitor {
+    /// Spans of the double negations along with their operand.
+    spans: Vec<(Span, Span)>,
+  }
+
+  impl VisitAll for NoDoubleNegationVisitor {
+    fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+      if unary_expr.op != UnaryOp::Bang {
+        return;
+      }
+      if let Expr::Unary(inner) = &*unary_expr.arg {
+        if inner.op == UnaryOp::Bang {
+          self.spans.push((unary_expr.span, inner.arg.span()));
+        }
+      }
+    }
+  }
+
+  impl LintRule for NoDoubleNegation {
+    fn new() -> Box<Self> {
+      Box::new(NoDoubleNegation)
+    }
+
+    fn code(&self) -> &'static str {
+      "no-double-negation"
+    }
+
+    fn lint_module(&self, context: &mut Context, module: &Module) {
+      let mut visitor = NoDoubleNegationVisitor { spans: vec![] };
+      module.visit_all_with(module, &mut visitor);
+      for (span, operand_span) in visitor.spans {
+        let operand = context.source_map.span_to_snippet(operand_span).unwrap();
+        let change = context.create_text_change(span, operand);
+        context.add_diagnostic_with_fix(
+          span,
+          "no-double-negation",
+          "Double negation",
+          vec![change],
+        );
+      }
+    }
+  }
+
+  /// Reports every identifier, under the code of a built-in rule.
+  struct NoIdent;
+
+  struct NoIdentVisitor {
+    spans: Vec<Span>,
+  }
+
+  impl VisitAll for NoIdentVisitor {
+    fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+      self.spans.push(ident.span);
+    }
+  }
+
+  impl LintRule for NoIdent {
+    fn new() -> Box<Self> {
+      Box::new(NoIdent)
+    }
+
+    fn code(&self) -> &'static str {
+      "no-var"
+    }
+
+    fn lint_module(&self, context: &mut Context, module: &Module) {
+      let mut visitor = NoIdentVisitor { spans: vec![] };
+      module.visit_all_with(module, &mut visitor);
+      for span in visitor.spans {
+        context.add_diagnostic(span, "no-var", "Identifier");
+      }
+    }
+  }
+
+  /// Records the address of the control flow it's given.
+  struct ControlFlowRecorder {
+    code: &'static str,
+    addresses: Arc<Mutex<Vec<usize>>>,
+  }
+
+  impl LintRule for ControlFlowRecorder {
+    fn new() -> Box<Self> {
+      Box::new(ControlFlowRecorder {
+        code: "control-flow-recorder",
+        addresses: Default::default(),
+      })
+    }
+
+    fn code(&self) -> &'static str {
+      self.code
+    }
+
+    fn lint_module(&self, context: &mut Context, _module: &Module) {
+      let address = context.control_flow() as *const ControlFlow as usize;
+      self.addresses.lock().unwrap().push(address);
+    }
+  }
+
+  /// Reports the second line of every module, along with the length of its
+  /// source.
+  struct SecondLine;
+
+  impl LintRule for SecondLine {
+    fn new() -> Box<Self> {
+      Box::new(SecondLine)
+    }
+
+    fn code(&self) -> &'static str {
+      "second-line"
+    }
+
+    fn lint_module(&self, context: &mut Context, module: &Module) {
+      let message =
+        format!("{:?} of {}", context.line_text(2), context.source().len());
+      context.add_diagnostic(module.span, "second-line", message);
+    }
+  }
+
+  /// Reports every module under the code of another rule.
+  struct MismatchedCode;
+
+  impl LintRule for MismatchedCode {
+    fn new() -> Box<Self> {
+      Box::new(MismatchedCode)
+    }
+
+    fn code(&self) -> &'static str {
+      "mismatched-code"
+    }
+
+    fn lint_module(&self, context: &mut Context, module: &Module) {
+      context.add_diagnostic(module.span, "getter-return", "Module");
+    }
+  }
+
+  /// Reports every module, but only lints JSX.
+  struct JsxModule;
+
+  impl LintRule for JsxModule {
+    fn new() -> Box<Self> {
+      Box::new(JsxModule)
+    }
+
+    fn tags(&self) -> &[&'static str] {
+      &["jsx"]
+    }
+
+    fn code(&self) -> &'static str {
+      "jsx-module"
+    }
+
+    fn lint_module(&self, context: &mut Context, module: &Module) {
+      context.add_diagnostic(module.span, "jsx-module", "Module");
+    }
+  }
+
+  fn lint_and_fix(
+    max_fix_passes: usize,
+    source_code: &str,
+  ) -> (String, Vec<LintDiagnostic>) {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDoubleNegation::new()])
+      .max_fix_passes(max_fix_passes)
+      .build();
+    linter
+      .lint_and_fix("lint_test.ts".to_string(), source_code.to_string())
+      .expect("Failed to lint")
+  }
 
   #[test]
   fn test_parse_ignore_comments() {
@@ -582,4 +1194,200 @@ object | undefined {}
     );
     assert_eq!(d.codes, vec!["ban-types"]);
   }
+  fn text_change(start: usize, end: usize, new_text: &str) -> TextChange {
+    let position = |byte_pos| Position {
+      line: 1,
+      col: byte_pos,
+      byte_pos,
+    };
+    TextChange {
+      range: Range {
+        start: position(start),
+        end: position(end),
+      },
+      new_text: new_text.to_string(),
+    }
+  }
+
+  #[test]
+  fn test_apply_text_changes() {
+    let source_code = "if (a = b) {}";
+    let changes = vec![
+      text_change(11, 13, "{ foo(); }"),
+      text_change(4, 9, "(a = b)"),
+    ];
+    assert_eq!(
+      apply_text_changes(source_code, changes),
+      "if ((a = b)) { foo(); }"
+    );
+
+    // Overlapping changes are skipped, they are applied on the next pass.
+    let changes = vec![
+      text_change(4, 9, "(a = b)"),
+      text_change(8, 13, "c) {}"),
+      text_change(0, 2, "while"),
+    ];
+    assert_eq!(
+      apply_text_changes(source_code, changes),
+      "while ((a = b)) {}"
+    );
+  }
+
+  #[test]
+  fn test_lint_and_fix_passes() {
+    // The fixes of the nested double negations overlap, so `!!!!x` is fixed
+    // to `!!x` by the first pass and to `x` by the second one.
+    let (fixed, diagnostics) = lint_and_fix(10, "const y = !!!!x;");
+    assert_eq!(fixed, "const y = x;");
+    assert!(diagnostics.is_empty());
+
+    let (fixed, diagnostics) = lint_and_fix(1, "const y = !!!!x;");
+    assert_eq!(fixed, "const y = !!x;");
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].fixable);
+
+    let (fixed, diagnostics) = lint_and_fix(10, "const y = !x;");
+    assert_eq!(fixed, "const y = !x;");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn test_custom_rules() {
+    let source_code = "var foo = bar;\ndebugger;";
+    let mut linter = LinterBuilder::default()
+      .custom_rules(vec![NoIdent::new()])
+      .rules(vec![NoVar::new(), NoDebugger::new()])
+      .build();
+    let diagnostics = linter
+      .lint("lint_test.ts".to_string(), source_code.to_string())
+      .expect("Failed to lint");
+
+    // The custom rule replaces the built-in `no-var` rule.
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(diagnostics[0].code, "no-var");
+    assert_eq!(diagnostics[0].message, "Identifier");
+    assert_eq!(diagnostics[0].range.start.col, 4);
+    assert_eq!(diagnostics[1].code, "no-var");
+    assert_eq!(diagnostics[1].message, "Identifier");
+    assert_eq!(diagnostics[1].range.start.col, 10);
+    assert_eq!(diagnostics[2].code, "no-debugger");
+  }
+
+  #[test]
+  fn test_parallel_rules() {
+    let source_code = r#"
+// deno-lint-ignore no-debugger
+debugger;
+var a = 1;
+if (a = 2) {}
+const b = { c: 1, c: 2 };
+switch (a) {
+  case 1:
+    foo();
+  case 2:
+    break;
+}
+"#
+    .repeat(100);
+    let lint = |parallel| {
+      let mut linter = LinterBuilder::default()
+        .rules(get_all_rules())
+        .parallel(parallel)
+        .build();
+      linter
+        .lint("lint_test.ts".to_string(), source_code.clone())
+        .expect("Failed to lint")
+        .into_iter()
+        .map(|d| (d.range.start.byte_pos, d.code, d.message))
+        .collect::<Vec<_>>()
+    };
+
+    let diagnostics = lint(true);
+    assert!(!diagnostics.is_empty());
+    assert_eq!(diagnostics, lint(false));
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(
+    expected = "The rule \"mismatched-code\" reported a diagnostic with the code \"getter-return\""
+  )]
+  fn test_mismatched_rule_code() {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![MismatchedCode::new()])
+      .build();
+    let _ = linter.lint("lint_test.ts".to_string(), "foo();".to_string());
+  }
+
+  #[test]
+  fn test_jsx_rules() {
+    let lint = |syntax| {
+      let mut linter = LinterBuilder::default()
+        .syntax(syntax)
+        .rules(vec![JsxModule::new()])
+        .build();
+      linter
+        .lint("lint_test.tsx".to_string(), "const a = 1;".to_string())
+        .expect("Failed to lint")
+    };
+
+    assert!(lint(swc_util::get_default_ts_config()).is_empty());
+    assert_eq!(lint(swc_util::get_default_tsx_config()).len(), 1);
+  }
+
+  #[test]
+  fn test_context_line_text() {
+    let lint = |source_code: &str| {
+      let mut linter = LinterBuilder::default()
+        .rules(vec![SecondLine::new()])
+        .build();
+      let diagnostics = linter
+        .lint("lint_test.ts".to_string(), source_code.to_string())
+        .expect("Failed to lint");
+      diagnostics[0].message.clone()
+    };
+
+    assert_eq!(
+      lint(
+        "a();
+const é = 1;
+b();"
+      ),
+      r#"Some("const é = 1;") of 23"#
+    );
+    assert_eq!(
+      lint(
+        "a();
+b();"
+      ),
+      r#"Some("b();") of 10"#
+    );
+    assert_eq!(lint("a();"), "None of 4");
+  }
+
+  #[test]
+  fn test_control_flow_shared() {
+    let addresses: Arc<Mutex<Vec<usize>>> = Default::default();
+    let rules: Vec<Box<dyn LintRule>> = ["first-recorder", "second-recorder"]
+      .iter()
+      .map(|code| -> Box<dyn LintRule> {
+        Box::new(ControlFlowRecorder {
+          code: *code,
+          addresses: addresses.clone(),
+        })
+      })
+      .collect();
+    let mut linter = LinterBuilder::default().rules(rules).build();
+    linter
+      .lint(
+        "lint_test.ts".to_string(),
+        "function foo() { return; bar(); }".to_string(),
+      )
+      .expect("Failed to lint");
+
+    // Both rules were given the same control flow, which was analyzed once.
+    let addresses = addresses.lock().unwrap();
+    assert_eq!(addresses.len(), 2);
+    assert_eq!(addresses[0], addresses[1]);
+  }
 }
@@ -0,0 +1,438 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::Key;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, BlockStmt, CallExpr, Class, ClassMember, Expr,
+  ExprOrSuper, Function, GetterProp, MethodKind, ObjectLit, Pat, PatOrExpr,
+  Prop, PropOrSpread, ReturnStmt, SetterProp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::{Visit, VisitWith};
+
+pub struct AccessorReturnConsistency;
+
+impl LintRule for AccessorReturnConsistency {
+  fn new() -> Box<Self> {
+    Box::new(AccessorReturnConsistency)
+  }
+
+  fn code(&self) -> &'static str {
+    "accessor-return-consistency"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = AccessorReturnConsistencyVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows setters that don't set the field their getter returns.
+
+A getter and a setter of the same name are expected to read and write the same
+value. When the getter returns `this._a` but the setter only assigns to
+`this._b`, the value that was set is never read back, which is most likely a
+typo.
+
+Only pairs where the getter returns a field of `this` and the setter assigns to
+fields of `this` directly are checked. Setters that call methods on `this` or
+assign to other accessors are skipped.
+
+### Invalid:
+```typescript
+class Temperature {
+  get celsius() {
+    return this._celsius;
+  }
+  set celsius(value) {
+    this._fahrenheit = value;
+  }
+}
+```
+
+### Valid:
+```typescript
+class Temperature {
+  get celsius() {
+    return this._celsius;
+  }
+  set celsius(value) {
+    this._celsius = value;
+  }
+}
+```"#
+  }
+}
+
+/// A getter or setter with a statically known name.
+struct Accessor<'a> {
+  name: String,
+  is_static: bool,
+  span: Span,
+  body: &'a BlockStmt,
+}
+
+#[derive(Default)]
+struct Accessors<'a> {
+  getters: Vec<Accessor<'a>>,
+  setters: Vec<Accessor<'a>>,
+}
+
+impl<'a> Accessors<'a> {
+  fn is_accessor(&self, name: &str) -> bool {
+    self
+      .getters
+      .iter()
+      .chain(self.setters.iter())
+      .any(|accessor| accessor.name == name)
+  }
+
+  /// Returns the getter paired with `setter`.
+  fn getter_of(&self, setter: &Accessor) -> Option<&Accessor<'a>> {
+    self.getters.iter().find(|getter| {
+      getter.name == setter.name && getter.is_static == setter.is_static
+    })
+  }
+}
+
+struct AccessorReturnConsistencyVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> AccessorReturnConsistencyVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn check_accessors(&mut self, accessors: &Accessors) {
+    for setter in &accessors.setters {
+      let getter = match accessors.getter_of(setter) {
+        Some(getter) => getter,
+        None => continue,
+      };
+      let returned = match returned_fields(getter.body) {
+        Some(returned) => returned,
+        None => continue,
+      };
+      let assigned = match assigned_fields(setter.body) {
+        Some(assigned) => assigned,
+        None => continue,
+      };
+      // Assigning to another accessor may set the returned field indirectly.
+      if assigned.iter().any(|field| accessors.is_accessor(field)) {
+        continue;
+      }
+      if returned.iter().any(|field| assigned.contains(field)) {
+        continue;
+      }
+      self.context.add_diagnostic_with_hint(
+        setter.span,
+        "accessor-return-consistency",
+        format!(
+          "Setter `{}` doesn't set `this.{}`, which its getter returns",
+          setter.name, returned[0]
+        ),
+        "Set the field the getter returns, or return the field the setter sets",
+      );
+    }
+  }
+}
+
+impl<'c> Visit for AccessorReturnConsistencyVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class, parent: &dyn Node) {
+    let mut accessors = Accessors::default();
+    for member in &class.body {
+      let (name, kind, is_static, span, body) = match member {
+        ClassMember::Method(method) => (
+          method.key.get_key(),
+          &method.kind,
+          method.is_static,
+          method.span,
+          &method.function.body,
+        ),
+        ClassMember::PrivateMethod(method) => (
+          method.key.get_key().map(|name| format!("#{}", name)),
+          &method.kind,
+          method.is_static,
+          method.span,
+          &method.function.body,
+        ),
+        _ => continue,
+      };
+      if let (Some(name), Some(body)) = (name, body) {
+        let accessor = Accessor {
+          name,
+          is_static,
+          span,
+          body,
+        };
+        match kind {
+          MethodKind::Getter => accessors.getters.push(accessor),
+          MethodKind::Setter => accessors.setters.push(accessor),
+          MethodKind::Method => {}
+        }
+      }
+    }
+    self.check_accessors(&accessors);
+
+    swc_ecmascript::visit::visit_class(self, class, parent);
+  }
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, parent: &dyn Node) {
+    let mut accessors = Accessors::default();
+    for prop in &object_lit.props {
+      if let PropOrSpread::Prop(prop) = prop {
+        match &**prop {
+          Prop::Getter(GetterProp {
+            key,
+            span,
+            body: Some(body),
+            ..
+          }) => {
+            if let Some(name) = key.get_key() {
+              accessors.getters.push(Accessor {
+                name,
+                is_static: false,
+                span: *span,
+                body,
+              });
+            }
+          }
+          Prop::Setter(SetterProp {
+            key,
+            span,
+            body: Some(body),
+            ..
+          }) => {
+            if let Some(name) = key.get_key() {
+              accessors.setters.push(Accessor {
+                name,
+                is_static: false,
+                span: *span,
+                body,
+              });
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    self.check_accessors(&accessors);
+
+    swc_ecmascript::visit::visit_object_lit(self, object_lit, parent);
+  }
+}
+
+/// Returns the name of the field if `expr` is a field of `this`, as in
+/// `this.a`, `this["a"]` or `this.#a`.
+fn this_field(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Member(member_expr) => {
+      match &member_expr.obj {
+        ExprOrSuper::Expr(obj) if matches!(&**obj, Expr::This(_)) => {}
+        _ => return None,
+      }
+      match &*member_expr.prop {
+        Expr::PrivateName(name) => Some(format!("#{}", name.id.sym)),
+        Expr::Ident(_) if member_expr.computed => None,
+        _ => member_expr.get_key(),
+      }
+    }
+    Expr::Paren(paren_expr) => this_field(&paren_expr.expr),
+    _ => None,
+  }
+}
+
+/// Returns the fields of `this` returned by a getter, or `None` if any of its
+/// return values is something other than a field of `this`.
+fn returned_fields(body: &BlockStmt) -> Option<Vec<String>> {
+  let mut collector = ReturnedFieldsCollector {
+    fields: Vec::new(),
+    only_fields: true,
+  };
+  body.visit_with(body, &mut collector);
+  if collector.only_fields && !collector.fields.is_empty() {
+    Some(collector.fields)
+  } else {
+    None
+  }
+}
+
+/// Returns the fields of `this` assigned to in a setter, or `None` if the
+/// setter may set fields in a way that can't be tracked.
+fn assigned_fields(body: &BlockStmt) -> Option<HashSet<String>> {
+  let mut collector = AssignedFieldsCollector {
+    fields: HashSet::new(),
+    opaque: false,
+  };
+  body.visit_with(body, &mut collector);
+  if !collector.opaque && !collector.fields.is_empty() {
+    Some(collector.fields)
+  } else {
+    None
+  }
+}
+
+struct ReturnedFieldsCollector {
+  fields: Vec<String>,
+  only_fields: bool,
+}
+
+impl Visit for ReturnedFieldsCollector {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
+    match return_stmt.arg.as_ref().and_then(|arg| this_field(arg)) {
+      Some(field) => self.fields.push(field),
+      None => self.only_fields = false,
+    }
+  }
+
+  // Return statements of nested functions don't belong to the getter.
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+  fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+  fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
+
+struct AssignedFieldsCollector {
+  fields: HashSet<String>,
+  opaque: bool,
+}
+
+impl Visit for AssignedFieldsCollector {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, parent: &dyn Node) {
+    let target = match &assign_expr.left {
+      PatOrExpr::Expr(expr) => Some(&**expr),
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Expr(expr) => Some(&**expr),
+        _ => None,
+      },
+    };
+    if let Some(field) = target.and_then(this_field) {
+      self.fields.insert(field);
+    }
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, parent);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    // Methods called on `this`, or functions it's passed to, may set any of
+    // its fields.
+    let calls_this_method = match &call_expr.callee {
+      ExprOrSuper::Super(_) => true,
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member_expr) => match &member_expr.obj {
+          ExprOrSuper::Expr(obj) => matches!(&**obj, Expr::This(_)),
+          ExprOrSuper::Super(_) => true,
+        },
+        _ => false,
+      },
+    };
+    let passes_this = call_expr
+      .args
+      .iter()
+      .any(|arg| matches!(&*arg.expr, Expr::This(_)));
+    if calls_this_method || passes_this {
+      self.opaque = true;
+    }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+  }
+
+  // `this` refers to something else in nested functions, but not in arrow
+  // functions.
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+  fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+  fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn accessor_return_consistency_valid() {
+    assert_lint_ok! {
+      AccessorReturnConsistency,
+      "class A { get a() { return this._a; } set a(v) { this._a = v; } }",
+      "class A { get a() { return this.#a; } set a(v) { this.#a = v; } }",
+      "class A { get a() { return this._a; } set a(v) { this._a = v; this._b = v; } }",
+      "class A { get a() { return this['_a']; } set a(v) { this._a = v; } }",
+      "class A { get a() { return this._a; } }",
+      "class A { set a(v) { this._b = v; } }",
+      "class A { get a() { return this._a; } set b(v) { this._b = v; } }",
+      "class A { static get a() { return this._a; } set a(v) { this._b = v; } }",
+      "class A { get a() { return this._a * 2; } set a(v) { this._b = v; } }",
+      "class A { get a() { return this._a; } set a(v) { this.update(v); } }",
+      "class A { get a() { return this._a; } set a(v) { this._b = v; this.sync(); } }",
+      "class A { get a() { return this._a; } set a(v) { this._b = v; Object.assign(this, v); } }",
+      "class A { get a() { return this._a; } set a(v) { this.b = v; } get b() { return this._a; } }",
+      "class A { get a() { return this._a; } set a(v) { [1].forEach(() => { this._a = v; }); } }",
+      "class A { get a() { if (x) { return this._a; } return this._b; } set a(v) { this._b = v; } }",
+      "class A { get [a]() { return this._a; } set [a](v) { this._b = v; } }",
+      "const o = { get a() { return this._a; }, set a(v) { this._a = v; } };",
+    };
+  }
+
+  #[test]
+  fn accessor_return_consistency_invalid() {
+    assert_lint_err! {
+      AccessorReturnConsistency,
+      "class A { get a() { return this._a; } set a(v) { this._b = v; } }": [
+        {
+          col: 38,
+          message: "Setter `a` doesn't set `this._a`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+      "class A { get #a() { return this.#x; } set #a(v) { this.#y = v; } }": [
+        {
+          col: 39,
+          message: "Setter `#a` doesn't set `this.#x`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+      "class A { static get a() { return this._a; } static set a(v) { this._b = v; } }": [
+        {
+          col: 45,
+          message: "Setter `a` doesn't set `this._a`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+      "class A { set a(v) { function f() { this._a = v; } this._b = v; } get a() { return this._a; } }": [
+        {
+          col: 10,
+          message: "Setter `a` doesn't set `this._a`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+      "const o = { get a() { return this._a; }, set a(v) { this._b = v; } };": [
+        {
+          col: 41,
+          message: "Setter `a` doesn't set `this._a`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+      "class A { m() { return { get a() { return this.x; }, set a(v) { this.y = v; } }; } }": [
+        {
+          col: 53,
+          message: "Setter `a` doesn't set `this.x`, which its getter returns",
+          hint: "Set the field the getter returns, or return the field the setter sets",
+        }
+      ],
+    };
+  }
+}
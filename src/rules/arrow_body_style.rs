@@ -0,0 +1,231 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{ArrowExpr, BlockStmtOrExpr, Expr, Module, Stmt};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArrowBodyMode {
+  /// Require braces around arrow function bodies.
+  Always,
+  /// Disallow braces around arrow function bodies.
+  Never,
+  /// Disallow braces around arrow function bodies which only return a value.
+  AsNeeded,
+}
+
+impl Default for ArrowBodyMode {
+  fn default() -> Self {
+    ArrowBodyMode::AsNeeded
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ArrowBodyStyleOptions {
+  pub mode: ArrowBodyMode,
+}
+
+pub struct ArrowBodyStyle {
+  options: ArrowBodyStyleOptions,
+}
+
+impl ArrowBodyStyle {
+  pub fn with_options(options: ArrowBodyStyleOptions) -> Box<Self> {
+    Box::new(ArrowBodyStyle { options })
+  }
+}
+
+impl LintRule for ArrowBodyStyle {
+  fn new() -> Box<Self> {
+    Self::with_options(ArrowBodyStyleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "arrow-body-style"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = ArrowBodyStyleVisitor::new(context, self.options.mode);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent style of arrow function bodies.
+
+The `mode` option is one of:
+- `"as-needed"` (default): disallows a block body which only returns a value, as in `x => { return y; }`. Returning an object literal is allowed, since it would need to be wrapped in parentheses.
+- `"always"`: requires a block body.
+- `"never"`: disallows block bodies.
+
+### Invalid (`"as-needed"`):
+```typescript
+const f = (x) => {
+  return x * 2;
+};
+```
+
+### Valid (`"as-needed"`):
+```typescript
+const f = (x) => x * 2;
+const g = (x) => {
+  console.log(x);
+  return x * 2;
+};
+const h = () => ({ a: 1 });
+```"#
+  }
+}
+
+struct ArrowBodyStyleVisitor<'c> {
+  context: &'c mut Context,
+  mode: ArrowBodyMode,
+}
+
+impl<'c> ArrowBodyStyleVisitor<'c> {
+  fn new(context: &'c mut Context, mode: ArrowBodyMode) -> Self {
+    Self { context, mode }
+  }
+}
+
+/// Checks if `expr` is an object literal, possibly wrapped in parentheses.
+fn is_object_lit(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(_) => true,
+    Expr::Paren(paren_expr) => is_object_lit(&paren_expr.expr),
+    _ => false,
+  }
+}
+
+impl<'c> VisitAll for ArrowBodyStyleVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    match (&arrow_expr.body, self.mode) {
+      (BlockStmtOrExpr::Expr(expr), ArrowBodyMode::Always) => {
+        self.context.add_diagnostic_with_hint(
+          expr.span(),
+          "arrow-body-style",
+          "Expected block statement surrounding arrow body",
+          "Wrap the body in braces and return the value",
+        );
+      }
+      (BlockStmtOrExpr::BlockStmt(block_stmt), ArrowBodyMode::Never) => {
+        self.context.add_diagnostic_with_hint(
+          block_stmt.span,
+          "arrow-body-style",
+          "Unexpected block statement surrounding arrow body",
+          "Use an expression as the arrow function body",
+        );
+      }
+      (BlockStmtOrExpr::BlockStmt(block_stmt), ArrowBodyMode::AsNeeded) => {
+        if let [Stmt::Return(return_stmt)] = &block_stmt.stmts[..] {
+          if let Some(arg) = &return_stmt.arg {
+            if !is_object_lit(arg) {
+              self.context.add_diagnostic_with_hint(
+                block_stmt.span,
+                "arrow-body-style",
+                "Unexpected block statement surrounding arrow body",
+                "Move the returned value immediately after the `=>`",
+              );
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn arrow_body_style_valid() {
+    assert_lint_ok! {
+      ArrowBodyStyle,
+      "x => y;",
+      "x => ({ a: 1 });",
+      "x => { return { a: 1 }; };",
+      "x => { foo(); return y; };",
+      "x => { return; };",
+      "x => {};",
+      "x => { foo(); };",
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_invalid() {
+    assert_lint_err! {
+      ArrowBodyStyle,
+      "x => { return y; };": [
+        {
+          col: 5,
+          message: "Unexpected block statement surrounding arrow body",
+          hint: "Move the returned value immediately after the `=>`",
+        }
+      ],
+      "const f = async (a, b) => {\n  return await a + b;\n};": [
+        {
+          col: 26,
+          message: "Unexpected block statement surrounding arrow body",
+          hint: "Move the returned value immediately after the `=>`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_always() {
+    assert_lint_ok_with_options! {
+      ArrowBodyStyle,
+      json!({ "mode": "always" }),
+      "x => { return y; };",
+      "x => { foo(); };",
+    };
+    assert_lint_err_with_options! {
+      ArrowBodyStyle,
+      json!({ "mode": "always" }),
+      "x => y;": [
+        {
+          col: 5,
+          message: "Expected block statement surrounding arrow body",
+          hint: "Wrap the body in braces and return the value",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_never() {
+    assert_lint_ok_with_options! {
+      ArrowBodyStyle,
+      json!({ "mode": "never" }),
+      "x => y;",
+      "x => ({ a: 1 });",
+    };
+    assert_lint_err_with_options! {
+      ArrowBodyStyle,
+      json!({ "mode": "never" }),
+      "x => { foo(); };": [
+        {
+          col: 5,
+          message: "Unexpected block statement surrounding arrow body",
+          hint: "Use an expression as the arrow function body",
+        }
+      ],
+    };
+
+    let options: ArrowBodyStyleOptions =
+      serde_json::from_str(r#"{ "mode": "as-needed" }"#).unwrap();
+    assert_eq!(options.mode, ArrowBodyMode::AsNeeded);
+  }
+}
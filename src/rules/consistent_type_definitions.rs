@@ -0,0 +1,199 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{Module, TsInterfaceDecl, TsType, TsTypeAliasDecl};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+/// The kind of declaration to enforce for object types.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeDefinitionStyle {
+  Interface,
+  Type,
+}
+
+impl Default for TypeDefinitionStyle {
+  fn default() -> Self {
+    TypeDefinitionStyle::Interface
+  }
+}
+
+pub struct ConsistentTypeDefinitions {
+  style: TypeDefinitionStyle,
+}
+
+impl ConsistentTypeDefinitions {
+  pub fn with_options(style: TypeDefinitionStyle) -> Box<Self> {
+    Box::new(ConsistentTypeDefinitions { style })
+  }
+}
+
+impl LintRule for ConsistentTypeDefinitions {
+  fn new() -> Box<Self> {
+    Self::with_options(TypeDefinitionStyle::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "consistent-type-definitions"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor =
+      ConsistentTypeDefinitionsVisitor::new(context, self.style);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces using either `interface` or `type` to define object types.
+
+Both can describe the shape of an object, so using one consistently makes a codebase easier to read. The option is either `"interface"` (default) or `"type"`.
+
+Type aliases of anything other than an object type literal, such as unions or function types, are not reported.
+
+### Invalid (`"interface"`):
+```typescript
+type Point = { x: number; y: number };
+```
+
+### Valid (`"interface"`):
+```typescript
+interface Point {
+  x: number;
+  y: number;
+}
+type Id = string | number;
+```
+
+### Invalid (`"type"`):
+```typescript
+interface Point {
+  x: number;
+  y: number;
+}
+```
+
+### Valid (`"type"`):
+```typescript
+type Point = { x: number; y: number };
+```"#
+  }
+}
+
+struct ConsistentTypeDefinitionsVisitor<'c> {
+  context: &'c mut Context,
+  style: TypeDefinitionStyle,
+}
+
+impl<'c> ConsistentTypeDefinitionsVisitor<'c> {
+  fn new(context: &'c mut Context, style: TypeDefinitionStyle) -> Self {
+    Self { context, style }
+  }
+}
+
+impl<'c> VisitAll for ConsistentTypeDefinitionsVisitor<'c> {
+  fn visit_ts_type_alias_decl(
+    &mut self,
+    type_alias_decl: &TsTypeAliasDecl,
+    _parent: &dyn Node,
+  ) {
+    if self.style == TypeDefinitionStyle::Interface
+      && matches!(&*type_alias_decl.type_ann, TsType::TsTypeLit(_))
+    {
+      self.context.add_diagnostic_with_hint(
+        type_alias_decl.span,
+        "consistent-type-definitions",
+        "Use an `interface` instead of a `type`",
+        format!("Declare `{}` as an interface", type_alias_decl.id.sym),
+      );
+    }
+  }
+
+  fn visit_ts_interface_decl(
+    &mut self,
+    interface_decl: &TsInterfaceDecl,
+    _parent: &dyn Node,
+  ) {
+    if self.style == TypeDefinitionStyle::Type {
+      self.context.add_diagnostic_with_hint(
+        interface_decl.span,
+        "consistent-type-definitions",
+        "Use a `type` instead of an `interface`",
+        format!(
+          "Declare `{}` as a type alias of an object type",
+          interface_decl.id.sym
+        ),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn consistent_type_definitions_valid() {
+    assert_lint_ok! {
+      ConsistentTypeDefinitions,
+      "interface Point { x: number; y: number; }",
+      "type Id = string | number;",
+      "type Fn = () => void;",
+      "type Point = { x: number } & { y: number };",
+      "type Readonly<T> = { readonly [K in keyof T]: T[K] };",
+    };
+  }
+
+  #[test]
+  fn consistent_type_definitions_invalid() {
+    assert_lint_err! {
+      ConsistentTypeDefinitions,
+      "type Point = { x: number; y: number };": [
+        {
+          col: 0,
+          message: "Use an `interface` instead of a `type`",
+          hint: "Declare `Point` as an interface",
+        }
+      ],
+      "export type Box<T> = { value: T };": [
+        {
+          col: 7,
+          message: "Use an `interface` instead of a `type`",
+          hint: "Declare `Box` as an interface",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_definitions_type_style() {
+    assert_lint_ok_with_options! {
+      ConsistentTypeDefinitions,
+      json!("type"),
+      "type Point = { x: number; y: number };",
+    };
+    assert_lint_err_with_options! {
+      ConsistentTypeDefinitions,
+      json!("type"),
+      "interface Point { x: number; y: number; }": [
+        {
+          col: 0,
+          message: "Use a `type` instead of an `interface`",
+          hint: "Declare `Point` as a type alias of an object type",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_definitions_options_deserialize() {
+    let style: TypeDefinitionStyle = serde_json::from_str(r#""type""#).unwrap();
+    assert_eq!(style, TypeDefinitionStyle::Type);
+    let style: TypeDefinitionStyle =
+      serde_json::from_str(r#""interface""#).unwrap();
+    assert_eq!(style, TypeDefinitionStyle::Interface);
+  }
+}
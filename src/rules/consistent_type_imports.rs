@@ -0,0 +1,358 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ExportNamedSpecifier, Expr, Ident, ImportDecl, ImportSpecifier,
+  JSXElementName, JSXMemberExpr, JSXObject, MemberExpr, Module, ModuleDecl,
+  ModuleItem, Prop, TsEntityName,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TypeImportPreference {
+  TypeImports,
+  NoTypeImports,
+}
+
+impl Default for TypeImportPreference {
+  fn default() -> Self {
+    TypeImportPreference::TypeImports
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TypeImportFixStyle {
+  SeparateTypeImports,
+  InlineTypeImports,
+}
+
+impl Default for TypeImportFixStyle {
+  fn default() -> Self {
+    TypeImportFixStyle::SeparateTypeImports
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConsistentTypeImportsOptions {
+  /// Whether bindings only used as types should be imported with
+  /// `import type`, or `import type` should not be used at all.
+  pub prefer: TypeImportPreference,
+  /// The style of type import suggested in hints.
+  pub fix_style: TypeImportFixStyle,
+}
+
+pub struct ConsistentTypeImports {
+  options: ConsistentTypeImportsOptions,
+}
+
+impl ConsistentTypeImports {
+  pub fn with_options(options: ConsistentTypeImportsOptions) -> Box<Self> {
+    Box::new(ConsistentTypeImports { options })
+  }
+
+  fn check_import(
+    &self,
+    context: &mut Context,
+    import_decl: &ImportDecl,
+    collector: &UsageCollector,
+  ) {
+    for specifier in &import_decl.specifiers {
+      let (span, local) = specifier_span_and_local(specifier);
+      match self.options.prefer {
+        TypeImportPreference::TypeImports => {
+          if import_decl.type_only {
+            continue;
+          }
+          let id = local.to_id();
+          if collector.types.contains(&id) && !collector.values.contains(&id) {
+            let hint = match self.options.fix_style {
+              TypeImportFixStyle::SeparateTypeImports => format!(
+                "Import `{}` in an `import type` declaration",
+                local.sym
+              ),
+              TypeImportFixStyle::InlineTypeImports => {
+                format!("Mark the specifier as `type {}`", local.sym)
+              }
+            };
+            context.add_diagnostic_with_hint(
+              span,
+              "consistent-type-imports",
+              format!(
+                "`{}` is only used as a type, so it should be imported as a type",
+                local.sym
+              ),
+              hint,
+            );
+          }
+        }
+        TypeImportPreference::NoTypeImports => {
+          if import_decl.type_only {
+            context.add_diagnostic_with_hint(
+              span,
+              "consistent-type-imports",
+              "Use a regular import instead of `import type`",
+              "Remove the `type` keyword from the import declaration",
+            );
+          }
+        }
+      }
+    }
+  }
+}
+
+impl LintRule for ConsistentTypeImports {
+  fn new() -> Box<Self> {
+    Self::with_options(ConsistentTypeImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "consistent-type-imports"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut collector = UsageCollector::default();
+    module.visit_with(module, &mut collector);
+
+    for item in &module.body {
+      if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
+        self.check_import(context, import_decl, &collector);
+      }
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces consistent usage of type imports.
+
+With the default `prefer: "type-imports"`, imported bindings which are only used as types must be imported with `import type`. This makes it explicit that the import is erased at runtime. With `prefer: "no-type-imports"`, `import type` is disallowed instead.
+
+The `fixStyle` option (`"separate-type-imports"` or `"inline-type-imports"`) controls which form of type import is suggested.
+
+### Invalid:
+```typescript
+import { Foo } from "./foo.ts";
+let foo: Foo;
+```
+
+### Valid:
+```typescript
+import type { Foo } from "./foo.ts";
+let foo: Foo;
+```
+```typescript
+import { Foo } from "./foo.ts";
+const foo = new Foo();
+```"#
+  }
+}
+
+fn specifier_span_and_local(specifier: &ImportSpecifier) -> (Span, &Ident) {
+  match specifier {
+    ImportSpecifier::Named(named) => (named.span, &named.local),
+    ImportSpecifier::Default(default) => (default.span, &default.local),
+    ImportSpecifier::Namespace(namespace) => (namespace.span, &namespace.local),
+  }
+}
+
+/// Collects the bindings referenced in value positions and type positions.
+#[derive(Default)]
+struct UsageCollector {
+  values: HashSet<Id>,
+  types: HashSet<Id>,
+}
+
+fn root_ident(entity_name: &TsEntityName) -> &Ident {
+  match entity_name {
+    TsEntityName::Ident(ident) => ident,
+    TsEntityName::TsQualifiedName(qualified_name) => {
+      root_ident(&qualified_name.left)
+    }
+  }
+}
+
+fn jsx_root_ident(member_expr: &JSXMemberExpr) -> &Ident {
+  match &member_expr.obj {
+    JSXObject::Ident(ident) => ident,
+    JSXObject::JSXMemberExpr(member_expr) => jsx_root_ident(member_expr),
+  }
+}
+
+impl Visit for UsageCollector {
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    match expr {
+      Expr::Ident(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      _ => expr.visit_children_with(self),
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    match prop {
+      Prop::Shorthand(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_export_named_specifier(
+    &mut self,
+    export: &ExportNamedSpecifier,
+    _parent: &dyn Node,
+  ) {
+    self.values.insert(export.orig.to_id());
+  }
+
+  fn visit_jsx_element_name(
+    &mut self,
+    name: &JSXElementName,
+    _parent: &dyn Node,
+  ) {
+    match name {
+      JSXElementName::Ident(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      JSXElementName::JSXMemberExpr(member_expr) => {
+        self.values.insert(jsx_root_ident(member_expr).to_id());
+      }
+      JSXElementName::JSXNamespacedName(_) => {}
+    }
+  }
+
+  fn visit_ts_entity_name(
+    &mut self,
+    entity_name: &TsEntityName,
+    _parent: &dyn Node,
+  ) {
+    self.types.insert(root_ident(entity_name).to_id());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn consistent_type_imports_valid() {
+    assert_lint_ok! {
+      ConsistentTypeImports,
+      "import type { Foo } from './foo.ts'; let foo: Foo;",
+      "import { Foo } from './foo.ts'; const foo = new Foo();",
+      "import { Foo } from './foo.ts'; const foo: Foo = new Foo();",
+      "import { Foo } from './foo.ts';",
+      "import { Foo } from './foo.ts'; export { Foo };",
+      "import Foo from './foo.ts'; class Bar extends Foo {}",
+      "import * as foo from './foo.ts'; foo.bar();",
+      "import { foo } from './foo.ts'; const x = { foo };",
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_invalid() {
+    assert_lint_err! {
+      ConsistentTypeImports,
+      "import { Foo } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 9,
+          message: "`Foo` is only used as a type, so it should be imported as a type",
+          hint: "Import `Foo` in an `import type` declaration",
+        }
+      ],
+      "import Foo, { bar } from './foo.ts'; function f(x: Foo) { bar(); }": [
+        {
+          col: 7,
+          message: "`Foo` is only used as a type, so it should be imported as a type",
+          hint: "Import `Foo` in an `import type` declaration",
+        }
+      ],
+      "import * as foo from './foo.ts'; let x: foo.Bar;": [
+        {
+          col: 7,
+          message: "`foo` is only used as a type, so it should be imported as a type",
+          hint: "Import `foo` in an `import type` declaration",
+        }
+      ],
+      "import { Foo } from './foo.ts'; class Bar implements Foo {}": [
+        {
+          col: 9,
+          message: "`Foo` is only used as a type, so it should be imported as a type",
+          hint: "Import `Foo` in an `import type` declaration",
+        }
+      ],
+      "import { foo } from './foo.ts'; let x: typeof foo;": [
+        {
+          col: 9,
+          message: "`foo` is only used as a type, so it should be imported as a type",
+          hint: "Import `foo` in an `import type` declaration",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_inline_fix_style() {
+    assert_lint_err_with_options! {
+      ConsistentTypeImports,
+      json!({ "fixStyle": "inline-type-imports" }),
+      "import { Foo } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 9,
+          message: "`Foo` is only used as a type, so it should be imported as a type",
+          hint: "Mark the specifier as `type Foo`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_no_type_imports() {
+    assert_lint_ok_with_options! {
+      ConsistentTypeImports,
+      json!({ "prefer": "no-type-imports" }),
+      "import { Foo } from './foo.ts'; let foo: Foo;",
+    };
+    assert_lint_err_with_options! {
+      ConsistentTypeImports,
+      json!({ "prefer": "no-type-imports" }),
+      "import type { Foo } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 14,
+          message: "Use a regular import instead of `import type`",
+          hint: "Remove the `type` keyword from the import declaration",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_options_deserialize() {
+    let options: ConsistentTypeImportsOptions = serde_json::from_str(
+      r#"{ "prefer": "no-type-imports", "fixStyle": "inline-type-imports" }"#,
+    )
+    .unwrap();
+    assert_eq!(options.prefer, TypeImportPreference::NoTypeImports);
+    assert_eq!(options.fix_style, TypeImportFixStyle::InlineTypeImports);
+  }
+}
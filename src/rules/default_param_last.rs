@@ -147,11 +147,11 @@ class Foo {
     assert_lint_err::<DefaultParamLast>("const f = function (a = 2, b) {}", 20);
     assert_lint_err_n::<DefaultParamLast>(
       "function f(a = 5, b = 6, c) {}",
-      vec![18, 11],
+      vec![11, 18],
     );
     assert_lint_err_n::<DefaultParamLast>(
       "function f(a = 5, b, c = 6, d) {}",
-      vec![21, 11],
+      vec![11, 21],
     );
     assert_lint_err::<DefaultParamLast>("function f(a = 5, b, c = 5) {}", 11);
     assert_lint_err::<DefaultParamLast>("const f = (a = 5, b, ...c) => {}", 11);
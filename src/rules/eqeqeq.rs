@@ -1,16 +1,49 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_ecmascript::ast::{BinExpr, BinaryOp};
+use serde::Deserialize;
+use std::mem::discriminant;
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecmascript::ast::{BinExpr, BinaryOp, Expr, Lit, UnaryOp};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct Eqeqeq;
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EqeqeqMode {
+  /// Require `===` and `!==` in all comparisons.
+  Always,
+  /// Allow `==` and `!=` in comparisons with `null`, of two literals of the
+  /// same type and of the result of `typeof`.
+  Smart,
+}
+
+impl Default for EqeqeqMode {
+  fn default() -> Self {
+    EqeqeqMode::Always
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EqeqeqOptions {
+  pub mode: EqeqeqMode,
+}
+
+pub struct Eqeqeq {
+  options: EqeqeqOptions,
+}
+
+impl Eqeqeq {
+  pub fn with_options(options: EqeqeqOptions) -> Box<Self> {
+    Box::new(Eqeqeq { options })
+  }
+}
 
 impl LintRule for Eqeqeq {
   fn new() -> Box<Self> {
-    Box::new(Eqeqeq)
+    Self::with_options(EqeqeqOptions::default())
   }
 
   fn code(&self) -> &'static str {
@@ -22,7 +55,7 @@ impl LintRule for Eqeqeq {
     context: &mut Context,
     module: &swc_ecmascript::ast::Module,
   ) {
-    let mut visitor = EqeqeqVisitor::new(context);
+    let mut visitor = EqeqeqVisitor::new(context, self.options.mode);
     visitor.visit_module(module, module);
   }
 
@@ -35,12 +68,27 @@ value.  On the other hand `==` and `!=` do type coercion before value checking
 which can lead to unexpected results.  For example `5 == "5"` is true, while
 `5 === "5"` is false.
 
+The `mode` option is one of:
+- `"always"` (default): requires `===` and `!==` in all comparisons.
+- `"smart"`: allows `==` and `!=` when comparing with `null`, comparing two
+literals of the same type, or comparing the result of `typeof`.
+
+Comparisons of two literals of the same type or of the result of `typeof`
+behave the same with either operator, so they are fixed automatically. Other
+comparisons aren't, since the fix could change the behavior of the code.
+
 ### Valid:
 ```typescript
 if (a === 5) {}
 if ("hello world" !== input) {}
 ```
 
+### Valid (`"smart"`):
+```typescript
+if (a == null) {}
+if (typeof a == "number") {}
+```
+
 ### Invalid:
 ```typescript
 if (a == 5) {}
@@ -51,11 +99,58 @@ if ("hello world" != input) {}
 
 struct EqeqeqVisitor<'c> {
   context: &'c mut Context,
+  mode: EqeqeqMode,
 }
 
 impl<'c> EqeqeqVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context, mode: EqeqeqMode) -> Self {
+    Self { context, mode }
+  }
+
+  /// Returns the span of the operator of `bin_expr`, if it's the only
+  /// occurrence of it between the operands.
+  fn operator_span(&self, bin_expr: &BinExpr, operator: &str) -> Option<Span> {
+    let between = Span::new(
+      bin_expr.left.span().hi(),
+      bin_expr.right.span().lo(),
+      bin_expr.span.ctxt(),
+    );
+    let snippet = self.context.source_map.span_to_snippet(between).ok()?;
+    let mut matches = snippet.match_indices(operator);
+    let (offset, _) = matches.next()?;
+    if matches.next().is_some() {
+      return None;
+    }
+    let lo = between.lo() + BytePos(offset as u32);
+    Some(Span::new(
+      lo,
+      lo + BytePos(operator.len() as u32),
+      between.ctxt(),
+    ))
+  }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+fn is_typeof(expr: &Expr) -> bool {
+  matches!(unwrap_paren(expr), Expr::Unary(unary) if unary.op == UnaryOp::TypeOf)
+}
+
+fn is_null(expr: &Expr) -> bool {
+  matches!(unwrap_paren(expr), Expr::Lit(Lit::Null(_)))
+}
+
+fn are_literals_of_same_type(left: &Expr, right: &Expr) -> bool {
+  match (unwrap_paren(left), unwrap_paren(right)) {
+    (Expr::Lit(left), Expr::Lit(right)) => {
+      discriminant(left) == discriminant(right)
+    }
+    _ => false,
   }
 }
 
@@ -64,22 +159,44 @@ impl<'c> Visit for EqeqeqVisitor<'c> {
 
   fn visit_bin_expr(&mut self, bin_expr: &BinExpr, parent: &dyn Node) {
     if matches!(bin_expr.op, BinaryOp::EqEq | BinaryOp::NotEq) {
-      let message = if bin_expr.op == BinaryOp::EqEq {
-        "expected '===' and instead saw '=='."
-      } else {
-        "expected '!==' and instead saw '!='."
-      };
-      let hint = if bin_expr.op == BinaryOp::EqEq {
-        "Use '==='"
+      // These comparisons behave the same with a strict operator.
+      let is_safe = is_typeof(&bin_expr.left)
+        || is_typeof(&bin_expr.right)
+        || are_literals_of_same_type(&bin_expr.left, &bin_expr.right);
+      let is_null_check = is_null(&bin_expr.left) || is_null(&bin_expr.right);
+      if self.mode == EqeqeqMode::Smart && (is_safe || is_null_check) {
+        swc_ecmascript::visit::visit_bin_expr(self, bin_expr, parent);
+        return;
+      }
+
+      let (operator, strict_operator) = if bin_expr.op == BinaryOp::EqEq {
+        ("==", "===")
       } else {
-        "Use '!=='"
+        ("!=", "!==")
       };
-      self.context.add_diagnostic_with_hint(
-        bin_expr.span,
-        "eqeqeq",
-        message,
-        hint,
-      )
+      let message = format!(
+        "expected '{}' and instead saw '{}'.",
+        strict_operator, operator
+      );
+      match self.operator_span(bin_expr, operator) {
+        Some(operator_span) if is_safe => {
+          let change = self
+            .context
+            .create_text_change(operator_span, strict_operator);
+          self.context.add_diagnostic_with_fix(
+            bin_expr.span,
+            "eqeqeq",
+            message,
+            vec![change],
+          );
+        }
+        _ => self.context.add_diagnostic_with_hint(
+          bin_expr.span,
+          "eqeqeq",
+          message,
+          format!("Use '{}'", strict_operator),
+        ),
+      }
     }
     swc_ecmascript::visit::visit_bin_expr(self, bin_expr, parent);
   }
@@ -88,7 +205,9 @@ impl<'c> Visit for EqeqeqVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::linter::LinterBuilder;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn eqeqeq_valid() {
@@ -143,5 +262,88 @@ b"#,
     assert_lint_err::<Eqeqeq>("(a + b) == c;", 0);
     assert_lint_err::<Eqeqeq>("(a + b)  !=  c;", 0);
     assert_lint_err::<Eqeqeq>("((1) )  ==  (2);", 0);
+    assert_lint_err! {
+      Eqeqeq,
+      "a == b": [
+        {
+          col: 0,
+          message: "expected '===' and instead saw '=='.",
+          hint: "Use '==='",
+        }
+      ],
+      "if (a != null) {}": [
+        {
+          col: 4,
+          message: "expected '!==' and instead saw '!='.",
+          hint: "Use '!=='",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn eqeqeq_smart() {
+    assert_lint_ok_with_options! {
+      Eqeqeq,
+      json!({ "mode": "smart" }),
+      "a == null",
+      "null != a",
+      "typeof a == 'number'",
+      "'string' != typeof a",
+      "'hello' != 'world'",
+      "2 == 3",
+      "true == (false)",
+      "a === b",
+    };
+    assert_lint_err_with_options! {
+      Eqeqeq,
+      json!({ "mode": "smart" }),
+      "a == b": [
+        {
+          col: 0,
+          message: "expected '===' and instead saw '=='.",
+          hint: "Use '==='",
+        }
+      ],
+      "true == 1": [
+        {
+          col: 0,
+          message: "expected '===' and instead saw '=='.",
+          hint: "Use '==='",
+        }
+      ],
+      "a != undefined": [
+        {
+          col: 0,
+          message: "expected '!==' and instead saw '!='.",
+          hint: "Use '!=='",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn eqeqeq_fix() {
+    let lint_and_fix = |src: &str| {
+      let mut linter =
+        LinterBuilder::default().rules(vec![Eqeqeq::new()]).build();
+      linter
+        .lint_and_fix("deno_lint_test.ts".to_string(), src.to_string())
+        .expect("Failed to lint")
+    };
+
+    let (fixed, diagnostics) =
+      lint_and_fix("typeof a == 'number'; 'a' != (typeof b); ((1) )  ==  (2);");
+    assert_eq!(
+      fixed,
+      "typeof a === 'number'; 'a' !== (typeof b); ((1) )  ===  (2);"
+    );
+    assert!(diagnostics.is_empty());
+
+    // Fixing other comparisons could change their result.
+    let (fixed, diagnostics) = lint_and_fix("a == b; 1 == '1';");
+    assert_eq!(fixed, "a == b; 1 == '1';");
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics.iter().all(|d| !d.fixable));
   }
 }
@@ -1,21 +1,56 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use serde::Deserialize;
 use swc_common::Span;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
 use swc_ecmascript::ast::{
-  ArrowExpr, Class, ClassMember, Decl, DefaultDecl, Expr, Function, Module,
-  ModuleDecl, Pat, TsKeywordTypeKind, TsType, TsTypeAnn, VarDecl,
+  ArrowExpr, BlockStmtOrExpr, Class, ClassMember, Decl, DefaultDecl, Expr,
+  Function, Module, ModuleDecl, Pat, Stmt, TsKeywordTypeKind, TsType,
+  TsTypeAnn, VarDecl,
 };
 
-pub struct ExplicitModuleBoundaryTypes;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ExplicitModuleBoundaryTypesOptions {
+  /// Allow arguments explicitly typed as `any`.
+  pub allow_arguments_explicitly_typed_as_any: bool,
+  /// Don't require a return type on functions which immediately return
+  /// another function. The returned function is checked instead.
+  pub allow_higher_order_functions: bool,
+  /// Don't require a return type on arrow functions whose body is an
+  /// `as const` assertion.
+  pub allow_direct_const_assertion_in_arrow_functions: bool,
+}
+
+impl Default for ExplicitModuleBoundaryTypesOptions {
+  fn default() -> Self {
+    Self {
+      allow_arguments_explicitly_typed_as_any: false,
+      allow_higher_order_functions: true,
+      allow_direct_const_assertion_in_arrow_functions: true,
+    }
+  }
+}
+
+pub struct ExplicitModuleBoundaryTypes {
+  options: ExplicitModuleBoundaryTypesOptions,
+}
+
+impl ExplicitModuleBoundaryTypes {
+  pub fn with_options(
+    options: ExplicitModuleBoundaryTypesOptions,
+  ) -> Box<Self> {
+    Box::new(ExplicitModuleBoundaryTypes { options })
+  }
+}
 
 impl LintRule for ExplicitModuleBoundaryTypes {
   fn new() -> Box<Self> {
-    Box::new(ExplicitModuleBoundaryTypes)
+    Self::with_options(ExplicitModuleBoundaryTypesOptions::default())
   }
 
   fn code(&self) -> &'static str {
@@ -23,7 +58,8 @@ impl LintRule for ExplicitModuleBoundaryTypes {
   }
 
   fn lint_module(&self, context: &mut Context, module: &Module) {
-    let mut visitor = ExplicitModuleBoundaryTypesVisitor::new(context);
+    let mut visitor =
+      ExplicitModuleBoundaryTypesVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
   }
 
@@ -35,6 +71,15 @@ inputs and outputs of a module (known as the module boundary).  This will make
 it very clear to any users of the module how to supply inputs and handle
 outputs in a type safe manner.
 
+Options:
+- `allowArgumentsExplicitlyTypedAsAny` (default `false`) allows arguments
+  typed as `any`.
+- `allowHigherOrderFunctions` (default `true`) allows omitting the return type
+  of a function which immediately returns another function, as long as the
+  returned function is fully typed.
+- `allowDirectConstAssertionInArrowFunctions` (default `true`) allows omitting
+  the return type of an arrow function whose body is an `as const` assertion.
+
 ### Valid:
 ```typescript
 // Typed input parameters and return value
@@ -47,6 +92,13 @@ export var arrowFn = (arg: string): string => `hello ${arg}`;
 function isValid() {
   return true;
 }
+
+// The returned function is typed (with `allowHigherOrderFunctions`)
+export const adder = (a: number) => (b: number): number => a + b;
+
+// The return type is inferred from the const assertion
+// (with `allowDirectConstAssertionInArrowFunctions`)
+export const getConfig = () => ({ mode: "strict" } as const);
 ```
 
 ### Invalid:
@@ -65,13 +117,17 @@ export function isValid() {
   }
 }
 
-struct ExplicitModuleBoundaryTypesVisitor<'c> {
+struct ExplicitModuleBoundaryTypesVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o ExplicitModuleBoundaryTypesOptions,
 }
 
-impl<'c> ExplicitModuleBoundaryTypesVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> ExplicitModuleBoundaryTypesVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o ExplicitModuleBoundaryTypesOptions,
+  ) -> Self {
+    Self { context, options }
   }
 
   fn check_class(&mut self, class: &Class) {
@@ -84,12 +140,16 @@ impl<'c> ExplicitModuleBoundaryTypesVisitor<'c> {
 
   fn check_fn(&mut self, function: &Function) {
     if function.return_type.is_none() {
-      self.context.add_diagnostic_with_hint(
-        function.span,
-        "explicit-module-boundary-types",
-        "Missing return type on function",
-        "Add a return type to the function signature",
-      );
+      let returned_fn = function
+        .body
+        .as_ref()
+        .and_then(|body| returned_function(&body.stmts));
+      match returned_fn {
+        Some(expr) if self.options.allow_higher_order_functions => {
+          self.check_fn_expr(expr)
+        }
+        _ => self.report_missing_return_type(function.span),
+      }
     }
     for param in &function.params {
       self.check_pat(&param.pat);
@@ -98,20 +158,52 @@ impl<'c> ExplicitModuleBoundaryTypesVisitor<'c> {
 
   fn check_arrow(&mut self, arrow: &ArrowExpr) {
     if arrow.return_type.is_none() {
-      self.context.add_diagnostic_with_hint(
-        arrow.span,
-        "explicit-module-boundary-types",
-        "Missing return type on function",
-        "Add a return type to the function signature",
-      );
+      let (returned_fn, is_const_assertion) = match &arrow.body {
+        BlockStmtOrExpr::BlockStmt(block) => {
+          (returned_function(&block.stmts), false)
+        }
+        BlockStmtOrExpr::Expr(expr) => {
+          let expr = unwrap_paren(expr);
+          let returned_fn = if is_function(expr) { Some(expr) } else { None };
+          (returned_fn, matches!(expr, Expr::TsConstAssertion(_)))
+        }
+      };
+      match returned_fn {
+        Some(expr) if self.options.allow_higher_order_functions => {
+          self.check_fn_expr(expr)
+        }
+        _ if is_const_assertion
+          && self.options.allow_direct_const_assertion_in_arrow_functions => {}
+        _ => self.report_missing_return_type(arrow.span),
+      }
     }
     for pat in &arrow.params {
       self.check_pat(&pat);
     }
   }
 
+  fn check_fn_expr(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Fn(fn_expr) => self.check_fn(&fn_expr.function),
+      Expr::Arrow(arrow) => self.check_arrow(arrow),
+      _ => {}
+    }
+  }
+
+  fn report_missing_return_type(&mut self, span: Span) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      "explicit-module-boundary-types",
+      "Missing return type on function",
+      "Add a return type to the function signature",
+    );
+  }
+
   fn check_ann(&mut self, ann: &Option<TsTypeAnn>, span: Span) {
     if let Some(ann) = ann {
+      if self.options.allow_arguments_explicitly_typed_as_any {
+        return;
+      }
       let ts_type = ann.type_ann.as_ref();
       if let TsType::TsKeywordType(keyword_type) = ts_type {
         if TsKeywordTypeKind::TsAnyKeyword == keyword_type.kind {
@@ -155,7 +247,30 @@ impl<'c> ExplicitModuleBoundaryTypesVisitor<'c> {
   }
 }
 
-impl<'c> Visit for ExplicitModuleBoundaryTypesVisitor<'c> {
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+fn is_function(expr: &Expr) -> bool {
+  matches!(expr, Expr::Fn(_) | Expr::Arrow(_))
+}
+
+/// If `stmts` consists of a single `return` of a function, returns that
+/// function.
+fn returned_function(stmts: &[Stmt]) -> Option<&Expr> {
+  if let [Stmt::Return(return_stmt)] = stmts {
+    let arg = unwrap_paren(return_stmt.arg.as_ref()?);
+    if is_function(arg) {
+      return Some(arg);
+    }
+  }
+  None
+}
+
+impl<'c, 'o> Visit for ExplicitModuleBoundaryTypesVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_module_decl(
@@ -184,6 +299,7 @@ impl<'c> Visit for ExplicitModuleBoundaryTypesVisitor<'c> {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn explicit_module_boundary_types_valid() {
@@ -194,6 +310,9 @@ mod tests {
       "export var arrowFn = (arg: string): string => `test ${arg}`",
       "export var arrowFn = (arg: unknown): string => `test ${arg}`",
       "class Test { method() { return; } }",
+      "export const adder = (a: number) => (b: number): number => a + b;",
+      "export function adder(a: number) { return (b: number): number => a + b; }",
+      "export const getConfig = () => ({ mode: 'strict' } as const);",
     };
   }
 
@@ -223,5 +342,55 @@ mod tests {
       "export class Test { method() { return; } }",
       20,
     );
+    assert_lint_err::<ExplicitModuleBoundaryTypes>(
+      "export const adder = (a: number) => (b: number) => a + b;",
+      36,
+    );
+    assert_lint_err::<ExplicitModuleBoundaryTypes>(
+      "export function adder(a: number) { return (b: number) => a + b; }",
+      42,
+    );
+  }
+
+  #[test]
+  fn explicit_module_boundary_types_options() {
+    assert_lint_ok_with_options! {
+      ExplicitModuleBoundaryTypes,
+      json!({ "allowArgumentsExplicitlyTypedAsAny": true }),
+      "export var arrowFn = (arg: any): string => `test ${arg}`;",
+    };
+    assert_lint_err_with_options! {
+      ExplicitModuleBoundaryTypes,
+      json!({ "allowHigherOrderFunctions": false }),
+      "export const adder = (a: number) => (b: number): number => a + b;": [
+        {
+          col: 21,
+          message: "Missing return type on function",
+          hint: "Add a return type to the function signature",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      ExplicitModuleBoundaryTypes,
+      json!({ "allowDirectConstAssertionInArrowFunctions": false }),
+      "export const getConfig = () => ({ mode: 'strict' } as const);": [
+        {
+          col: 25,
+          message: "Missing return type on function",
+          hint: "Add a return type to the function signature",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn explicit_module_boundary_types_options_deserialize() {
+    let options: ExplicitModuleBoundaryTypesOptions = serde_json::from_str(
+      r#"{ "allowArgumentsExplicitlyTypedAsAny": true, "allowHigherOrderFunctions": false }"#,
+    )
+    .unwrap();
+    assert!(options.allow_arguments_explicitly_typed_as_any);
+    assert!(!options.allow_higher_order_functions);
+    assert!(options.allow_direct_const_assertion_in_arrow_functions);
   }
 }
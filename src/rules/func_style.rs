@@ -0,0 +1,234 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{Expr, FnDecl, Module, Pat, VarDeclarator};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FunctionStyle {
+  /// Require function declarations.
+  Declaration,
+  /// Require function expressions assigned to variables.
+  Expression,
+}
+
+impl Default for FunctionStyle {
+  fn default() -> Self {
+    FunctionStyle::Expression
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FuncStyleOptions {
+  pub style: FunctionStyle,
+  /// Allow arrow functions assigned to variables under `"declaration"`.
+  pub allow_arrow_functions: bool,
+}
+
+pub struct FuncStyle {
+  options: FuncStyleOptions,
+}
+
+impl FuncStyle {
+  pub fn with_options(options: FuncStyleOptions) -> Box<Self> {
+    Box::new(FuncStyle { options })
+  }
+}
+
+impl LintRule for FuncStyle {
+  fn new() -> Box<Self> {
+    Self::with_options(FuncStyleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "func-style"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = FuncStyleVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces the consistent use of either function declarations or function expressions assigned to variables.
+
+With `style: "expression"` (default), function declarations are reported. With `style: "declaration"`, function expressions and arrow functions assigned to variables are reported, unless `allowArrowFunctions` is enabled for arrow functions.
+
+### Invalid (`"expression"`):
+```typescript
+function foo() {}
+```
+
+### Valid (`"expression"`):
+```typescript
+const foo = function () {};
+const bar = () => {};
+```
+
+### Invalid (`"declaration"`):
+```typescript
+const foo = function () {};
+const bar = () => {};
+```
+
+### Valid (`"declaration"`):
+```typescript
+function foo() {}
+```"#
+  }
+}
+
+struct FuncStyleVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o FuncStyleOptions,
+}
+
+impl<'c, 'o> FuncStyleVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o FuncStyleOptions) -> Self {
+    Self { context, options }
+  }
+}
+
+impl<'c, 'o> VisitAll for FuncStyleVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    // Overload signatures and ambient declarations have no body.
+    if self.options.style != FunctionStyle::Expression
+      || fn_decl.function.body.is_none()
+    {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      fn_decl.function.span,
+      "func-style",
+      "Expected a function expression",
+      format!(
+        "Replace the declaration with `const {} = function () {{ ... }}`",
+        fn_decl.ident.sym
+      ),
+    );
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if self.options.style != FunctionStyle::Declaration {
+      return;
+    }
+
+    let name = match &var_declarator.name {
+      Pat::Ident(ident) => &ident.sym,
+      _ => return,
+    };
+    let is_reported = match var_declarator.init.as_deref() {
+      Some(Expr::Fn(_)) => true,
+      Some(Expr::Arrow(_)) => !self.options.allow_arrow_functions,
+      _ => false,
+    };
+    if is_reported {
+      self.context.add_diagnostic_with_hint(
+        var_declarator.span,
+        "func-style",
+        "Expected a function declaration",
+        format!("Replace the variable with `function {}() {{ ... }}`", name),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn func_style_valid() {
+    assert_lint_ok! {
+      FuncStyle,
+      "const foo = function () {};",
+      "const foo = () => {};",
+      "export default function () {}",
+      "declare function foo(): void;",
+      "const x = 1;",
+    };
+  }
+
+  #[test]
+  fn func_style_invalid() {
+    assert_lint_err! {
+      FuncStyle,
+      "function foo() {}": [
+        {
+          col: 0,
+          message: "Expected a function expression",
+          hint: "Replace the declaration with `const foo = function () { ... }`",
+        }
+      ],
+      "export function foo() {}": [
+        {
+          col: 7,
+          message: "Expected a function expression",
+          hint: "Replace the declaration with `const foo = function () { ... }`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn func_style_declaration() {
+    assert_lint_ok_with_options! {
+      FuncStyle,
+      json!({ "style": "declaration" }),
+      "function foo() {}",
+      "const x = foo();",
+    };
+    assert_lint_err_with_options! {
+      FuncStyle,
+      json!({ "style": "declaration" }),
+      "const foo = () => {};": [
+        {
+          col: 6,
+          message: "Expected a function declaration",
+          hint: "Replace the variable with `function foo() { ... }`",
+        }
+      ],
+      "let foo = function () {};": [
+        {
+          col: 4,
+          message: "Expected a function declaration",
+          hint: "Replace the variable with `function foo() { ... }`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn func_style_allow_arrow_functions() {
+    assert_lint_ok_with_options! {
+      FuncStyle,
+      json!({ "style": "declaration", "allowArrowFunctions": true }),
+      "const foo = () => {};",
+    };
+    assert_lint_err_with_options! {
+      FuncStyle,
+      json!({ "style": "declaration", "allowArrowFunctions": true }),
+      "const foo = function () {};": [
+        {
+          col: 6,
+          message: "Expected a function declaration",
+          hint: "Replace the variable with `function foo() { ... }`",
+        }
+      ],
+    };
+  }
+}
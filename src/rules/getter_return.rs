@@ -1,10 +1,12 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use crate::diagnostic::Suggestion;
 use crate::swc_util::Key;
+use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::mem;
-use swc_common::{Span, Spanned};
+use swc_common::{BytePos, Span, Spanned};
 use swc_ecmascript::ast::{
   BlockStmtOrExpr, CallExpr, ClassMethod, Expr, ExprOrSuper, GetterProp,
   MethodKind, PrivateMethod, Prop, PropName, PropOrSpread, ReturnStmt,
@@ -14,11 +16,28 @@ use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 use swc_ecmascript::visit::VisitWith;
 
-pub struct GetterReturn;
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GetterReturnOptions {
+  /// Overrides the hint of the reported diagnostics, e.g. to localize it.
+  pub hint: Option<String>,
+  /// Allows returning `undefined` implicitly with `return;`.
+  pub allow_implicit: bool,
+}
+
+pub struct GetterReturn {
+  options: GetterReturnOptions,
+}
+
+impl GetterReturn {
+  pub fn with_options(options: GetterReturnOptions) -> Box<Self> {
+    Box::new(GetterReturn { options })
+  }
+}
 
 impl LintRule for GetterReturn {
   fn new() -> Box<Self> {
-    Box::new(GetterReturn)
+    Self::with_options(GetterReturnOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -34,7 +53,7 @@ impl LintRule for GetterReturn {
     context: &mut Context,
     module: &swc_ecmascript::ast::Module,
   ) {
-    let mut visitor = GetterReturnVisitor::new(context);
+    let mut visitor = GetterReturnVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
     visitor.report();
   }
@@ -44,6 +63,10 @@ impl LintRule for GetterReturn {
 
 Getter functions return the value of a property.  If the function returns no
 value then this contract is broken.
+
+The `allowImplicit` option (default `false`) allows returning `undefined`
+implicitly with `return;`. The `hint` option replaces the hint of the reported
+diagnostics, for example to localize it.
     
 ### Valid:
 ```typescript
@@ -73,19 +96,22 @@ class Person {
   }
 }
 
-struct GetterReturnVisitor<'c> {
+struct GetterReturnVisitor<'c, 'o> {
   context: &'c mut Context,
-  errors: BTreeMap<Span, String>,
+  options: &'o GetterReturnOptions,
+  /// The message and the "Return undefined" suggestion of each error.
+  errors: BTreeMap<Span, (String, Suggestion)>,
   /// If this visitor is currently in a getter, its name is stored.
   getter_name: Option<String>,
   // `true` if a getter contains as least one return statement.
   has_return: bool,
 }
 
-impl<'c> GetterReturnVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
+impl<'c, 'o> GetterReturnVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o GetterReturnOptions) -> Self {
     Self {
       context,
+      options,
       errors: BTreeMap::new(),
       getter_name: None,
       has_return: false,
@@ -93,38 +119,71 @@ impl<'c> GetterReturnVisitor<'c> {
   }
 
   fn report(&mut self) {
-    for (span, msg) in &self.errors {
-      self.context.add_diagnostic_with_hint(
+    let hint = self
+      .options
+      .hint
+      .as_deref()
+      .unwrap_or("Return a value from the getter function");
+    for (span, (msg, suggestion)) in &self.errors {
+      self.context.add_diagnostic_with_hint_and_suggestions(
         *span,
         "getter-return",
         msg,
-        "Return a value from the getter function",
+        hint,
+        vec![suggestion.clone()],
       );
     }
   }
 
-  fn report_expected(&mut self, span: Span) {
+  /// Suggests to insert `return undefined` at `pos`.
+  fn return_undefined(&self, pos: BytePos, text: &str) -> Suggestion {
+    Suggestion {
+      description: "Return undefined".to_string(),
+      changes: vec![self
+        .context
+        .create_text_change(Span::new(pos, pos, Default::default()), text)],
+    }
+  }
+
+  /// Suggests to add `return undefined;` to the end of the getter's body.
+  fn return_undefined_at_end(&self, getter_body_span: Span) -> Suggestion {
+    let pos = getter_body_span.hi - BytePos(1);
+    let prev = Span::new(pos - BytePos(1), pos, getter_body_span.ctxt);
+    let text = match self.context.source_map.span_to_snippet(prev) {
+      Ok(snippet) if snippet.trim().is_empty() => "return undefined; ",
+      _ => " return undefined; ",
+    };
+    self.return_undefined(pos, text)
+  }
+
+  fn report_expected(&mut self, span: Span, suggestion: Suggestion) {
     self.errors.insert(
       span,
-      format!(
-        "Expected to return a value in {}.",
-        self
-          .getter_name
-          .clone()
-          .expect("the name of getter is not set")
+      (
+        format!(
+          "Expected to return a value in {}.",
+          self
+            .getter_name
+            .clone()
+            .expect("the name of getter is not set")
+        ),
+        suggestion,
       ),
     );
   }
 
-  fn report_always_expected(&mut self, span: Span) {
+  fn report_always_expected(&mut self, span: Span, suggestion: Suggestion) {
     self.errors.insert(
       span,
-      format!(
-        "Expected {} to always return a value.",
-        self
-          .getter_name
-          .clone()
-          .expect("the name of getter is not set")
+      (
+        format!(
+          "Expected {} to always return a value.",
+          self
+            .getter_name
+            .clone()
+            .expect("the name of getter is not set")
+        ),
+        suggestion,
       ),
     );
   }
@@ -136,15 +195,16 @@ impl<'c> GetterReturnVisitor<'c> {
 
     if self
       .context
-      .control_flow
+      .control_flow()
       .meta(getter_body_span.lo)
       .unwrap()
       .continues_execution()
     {
+      let suggestion = self.return_undefined_at_end(getter_body_span);
       if self.has_return {
-        self.report_always_expected(getter_span);
+        self.report_always_expected(getter_span, suggestion);
       } else {
-        self.report_expected(getter_span);
+        self.report_expected(getter_span, suggestion);
       }
     }
   }
@@ -166,7 +226,7 @@ impl<'c> GetterReturnVisitor<'c> {
   }
 }
 
-impl<'c> Visit for GetterReturnVisitor<'c> {
+impl<'c, 'o> Visit for GetterReturnVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_class_method(&mut self, class_method: &ClassMethod, _: &dyn Node) {
@@ -283,8 +343,10 @@ impl<'c> Visit for GetterReturnVisitor<'c> {
   fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
     if self.getter_name.is_some() {
       self.has_return = true;
-      if return_stmt.arg.is_none() {
-        self.report_expected(return_stmt.span);
+      if return_stmt.arg.is_none() && !self.options.allow_implicit {
+        let after_return = return_stmt.span.lo + BytePos("return".len() as u32);
+        let suggestion = self.return_undefined(after_return, " undefined");
+        self.report_expected(return_stmt.span, suggestion);
       }
     }
   }
@@ -293,7 +355,20 @@ impl<'c> Visit for GetterReturnVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::diagnostic::LintDiagnostic;
+  use crate::formatter::{FileDiagnostics, JsonDiagnostic, OutputFormat};
+  use crate::linter::{apply_text_changes, LinterBuilder};
   use crate::test_util::*;
+  use serde_json::json;
+
+  fn lint(src: &str) -> Vec<LintDiagnostic> {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![GetterReturn::new()])
+      .build();
+    linter
+      .lint("deno_lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint")
+  }
 
   // Some tests are derived from
   // https://github.com/eslint/eslint/blob/v7.9.0/tests/lib/rules/getter-return.js
@@ -491,4 +566,117 @@ Object.defineProperty(foo, 'bar', {
       vec![10, 27],
     );
   }
+
+  #[test]
+  fn getter_return_allow_implicit() {
+    assert_lint_ok_with_options! {
+      GetterReturn,
+      json!({ "allowImplicit": true }),
+      "var foo = { get bar() { return; } };",
+      "var foo = { get bar() { return;; } };",
+      "var foo = { get bar(){ if (bar) { return; } return true; } };",
+      "class foo { get bar() { return; } }",
+      "class foo { get bar() { if (baz) { return true; } return; } }",
+      "Object.defineProperty(foo, 'bar', { get: function () { return; } });",
+    };
+    assert_lint_err_with_options! {
+      GetterReturn,
+      json!({ "allowImplicit": true }),
+      "var foo = { get bar() {} };": [
+        {
+          col: 12,
+          message: "Expected to return a value in bar.",
+          hint: "Return a value from the getter function",
+        }
+      ],
+      "var foo = { get bar() { if (baz) { return; } } };": [
+        {
+          col: 12,
+          message: "Expected bar to always return a value.",
+          hint: "Return a value from the getter function",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn getter_return_custom_hint() {
+    assert_lint_err_with_options! {
+      GetterReturn,
+      json!({ "hint": "Gib einen Wert zurück" }),
+      "class Foo { get bar() {} }": [
+        {
+          col: 12,
+          message: "Expected to return a value in bar.",
+          hint: "Gib einen Wert zurück",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn getter_return_suggestions() {
+    let cases = [
+      (
+        "class Foo { get bar() {} }",
+        "class Foo { get bar() { return undefined; } }",
+      ),
+      (
+        "const foo = { get bar() { baz(); } };",
+        "const foo = { get bar() { baz(); return undefined; } };",
+      ),
+      (
+        "class Foo { get bar() { if (a) { return 1; } else { baz(); }} }",
+        "class Foo { get bar() { if (a) { return 1; } else { baz(); } return undefined; } }",
+      ),
+      (
+        "class Foo { get bar() { return; } }",
+        "class Foo { get bar() { return undefined; } }",
+      ),
+    ];
+
+    for (src, fixed) in cases.iter() {
+      let diagnostics = lint(src);
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(
+        diagnostics[0].hint.as_deref(),
+        Some("Return a value from the getter function")
+      );
+      let suggestions = &diagnostics[0].suggestions;
+      assert_eq!(suggestions.len(), 1);
+      assert_eq!(suggestions[0].description, "Return undefined");
+      assert_eq!(
+        apply_text_changes(src, suggestions[0].changes.clone()),
+        *fixed
+      );
+      assert_lint_ok::<GetterReturn>(fixed);
+    }
+  }
+
+  #[test]
+  fn getter_return_suggestions_json() {
+    let src = "class Foo { get bar() {} }";
+    let files = vec![FileDiagnostics {
+      source_code: src.to_string(),
+      diagnostics: lint(src),
+    }];
+    let output = OutputFormat::Json.formatter(&[]).format(&files);
+    let diagnostics: Vec<JsonDiagnostic> =
+      serde_json::from_str(&output).unwrap();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].suggestions,
+      files[0].diagnostics[0].suggestions
+    );
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+      json[0]["suggestions"][0]["description"],
+      json!("Return undefined")
+    );
+    assert_eq!(
+      json[0]["suggestions"][0]["changes"][0]["newText"],
+      json!(" return undefined; ")
+    );
+  }
 }
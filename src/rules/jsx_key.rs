@@ -0,0 +1,280 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::ArrayLit;
+use swc_ecmascript::ast::ArrowExpr;
+use swc_ecmascript::ast::BlockStmtOrExpr;
+use swc_ecmascript::ast::CallExpr;
+use swc_ecmascript::ast::Expr;
+use swc_ecmascript::ast::ExprOrSuper;
+use swc_ecmascript::ast::Function;
+use swc_ecmascript::ast::JSXAttrName;
+use swc_ecmascript::ast::JSXAttrOrSpread;
+use swc_ecmascript::ast::ReturnStmt;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct JsxKey;
+
+impl LintRule for JsxKey {
+  fn new() -> Box<Self> {
+    Box::new(JsxKey)
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "jsx-key"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = JsxKeyVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires a `key` prop on JSX elements rendered from an array.
+
+The `key` prop lets the framework tell which elements of a list were added, moved or removed between renders. This rule checks elements which are items of an array literal or returned from a `.map()` callback. Short fragments (`<>`) can't have a key, so `<Fragment key={...}>` must be used instead.
+
+### Invalid:
+```typescript
+[<li />, <li />];
+items.map((item) => <li>{item.name}</li>);
+items.map((item) => <>{item.name}</>);
+```
+
+### Valid:
+```typescript
+[<li key="a" />, <li key="b" />];
+items.map((item) => <li key={item.id}>{item.name}</li>);
+items.map((item) => <Fragment key={item.id}>{item.name}</Fragment>);
+```"#
+  }
+}
+
+struct JsxKeyVisitor<'c> {
+  context: &'c mut Context,
+  /// Spans of the functions passed to `.map()`.
+  map_callbacks: HashSet<Span>,
+  /// Whether each of the enclosing functions is a `.map()` callback.
+  function_stack: Vec<bool>,
+}
+
+impl<'c> JsxKeyVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      map_callbacks: HashSet::new(),
+      function_stack: vec![],
+    }
+  }
+
+  fn check_element(&mut self, expr: &Expr, message: &str) {
+    match expr {
+      Expr::Paren(paren) => self.check_element(&paren.expr, message),
+      Expr::Cond(cond) => {
+        self.check_element(&cond.cons, message);
+        self.check_element(&cond.alt, message);
+      }
+      Expr::JSXElement(element) => {
+        if !has_key_prop(&element.opening.attrs) {
+          self.context.add_diagnostic_with_hint(
+            element.span,
+            "jsx-key",
+            message,
+            "Add a `key` prop which identifies the element among its siblings",
+          );
+        }
+      }
+      Expr::JSXFragment(fragment) => {
+        self.context.add_diagnostic_with_hint(
+          fragment.span,
+          "jsx-key",
+          "Short fragments can't have a `key` prop",
+          "Use `<Fragment key={...}>` instead of `<>`",
+        );
+      }
+      _ => {}
+    }
+  }
+}
+
+pub(crate) fn has_key_prop(attrs: &[JSXAttrOrSpread]) -> bool {
+  attrs.iter().any(|attr| match attr {
+    JSXAttrOrSpread::JSXAttr(attr) => {
+      matches!(&attr.name, JSXAttrName::Ident(ident) if ident.sym == *"key")
+    }
+    JSXAttrOrSpread::SpreadElement(_) => false,
+  })
+}
+
+fn is_map_call(call_expr: &CallExpr) -> bool {
+  if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+    if let Expr::Member(member) = &**callee {
+      if let (false, Expr::Ident(prop)) = (member.computed, &*member.prop) {
+        return prop.sym == *"map";
+      }
+    }
+  }
+  false
+}
+
+impl<'c> Visit for JsxKeyVisitor<'c> {
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, parent: &dyn Node) {
+    for elem in array_lit.elems.iter().flatten() {
+      if elem.spread.is_none() {
+        self
+          .check_element(&elem.expr, "Missing `key` prop for element in array");
+      }
+    }
+    swc_ecmascript::visit::visit_array_lit(self, array_lit, parent);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    if is_map_call(call_expr) {
+      if let Some(callback) = call_expr.args.first() {
+        match &*callback.expr {
+          Expr::Arrow(arrow) => {
+            self.map_callbacks.insert(arrow.span);
+          }
+          Expr::Fn(fn_expr) => {
+            self.map_callbacks.insert(fn_expr.function.span);
+          }
+          _ => {}
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
+    let is_map_callback = self.map_callbacks.contains(&arrow_expr.span);
+    if is_map_callback {
+      if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+        self.check_element(expr, "Missing `key` prop for element in iterator");
+      }
+    }
+    self.function_stack.push(is_map_callback);
+    swc_ecmascript::visit::visit_arrow_expr(self, arrow_expr, parent);
+    self.function_stack.pop();
+  }
+
+  fn visit_function(&mut self, function: &Function, parent: &dyn Node) {
+    let is_map_callback = self.map_callbacks.contains(&function.span);
+    self.function_stack.push(is_map_callback);
+    swc_ecmascript::visit::visit_function(self, function, parent);
+    self.function_stack.pop();
+  }
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, parent: &dyn Node) {
+    if self.function_stack.last() == Some(&true) {
+      if let Some(arg) = &return_stmt.arg {
+        self.check_element(arg, "Missing `key` prop for element in iterator");
+      }
+    }
+    swc_ecmascript::visit::visit_return_stmt(self, return_stmt, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn jsx_key_valid() {
+    assert_lint_ok! {
+      JsxKey,
+      r#"[<div key="a" />, <div key="b" />]"#,
+      "arr.map(x => <li key={x.id} />)",
+      "arr.map(x => (<li key={x.id}>{x.name}</li>))",
+      "arr.map(function(x) { return <li key={x.id} />; })",
+      "arr.map(x => { const y = <span />; return <li key={x}>{y}</li>; })",
+      "arr.map(x => <Fragment key={x}><li /></Fragment>)",
+      "arr.map(x => x ? <li key='a' /> : <li key='b' />)",
+      "arr.forEach(x => <li />)",
+      "[...items]",
+      "<div>{[1, 2].map(x => x * 2)}</div>",
+      "arr.map(x => { function render() { return <li />; } return render(x); })",
+    };
+  }
+
+  #[test]
+  fn jsx_key_invalid() {
+    assert_lint_err! {
+      JsxKey,
+      "[<div/>]": [
+        {
+          col: 1,
+          message: "Missing `key` prop for element in array",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      r#"[<div key="a" />, <div />]"#: [
+        {
+          col: 18,
+          message: "Missing `key` prop for element in array",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(x => <li/>)": [
+        {
+          col: 13,
+          message: "Missing `key` prop for element in iterator",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(x => (<li>{x}</li>))": [
+        {
+          col: 14,
+          message: "Missing `key` prop for element in iterator",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(function(x) { return <li />; })": [
+        {
+          col: 29,
+          message: "Missing `key` prop for element in iterator",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(x => { if (x) { return <li />; } return null; })": [
+        {
+          col: 31,
+          message: "Missing `key` prop for element in iterator",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(x => x ? <li key='a' /> : <li />)": [
+        {
+          col: 34,
+          message: "Missing `key` prop for element in iterator",
+          hint: "Add a `key` prop which identifies the element among its siblings",
+        }
+      ],
+      "arr.map(x => <>{x}</>)": [
+        {
+          col: 13,
+          message: "Short fragments can't have a `key` prop",
+          hint: "Use `<Fragment key={...}>` instead of `<>`",
+        }
+      ],
+      "[<>a</>]": [
+        {
+          col: 1,
+          message: "Short fragments can't have a `key` prop",
+          hint: "Use `<Fragment key={...}>` instead of `<>`",
+        }
+      ],
+    };
+  }
+}
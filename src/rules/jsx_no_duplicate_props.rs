@@ -0,0 +1,216 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashSet;
+use swc_ecmascript::ast::JSXAttrName;
+use swc_ecmascript::ast::JSXAttrOrSpread;
+use swc_ecmascript::ast::JSXOpeningElement;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxNoDuplicatePropsOptions {
+  /// Treats props whose names only differ by case as duplicates, as in
+  /// `<div id="a" ID="b" />`.
+  pub ignore_case: bool,
+}
+
+pub struct JsxNoDuplicateProps {
+  options: JsxNoDuplicatePropsOptions,
+}
+
+impl JsxNoDuplicateProps {
+  pub fn with_options(options: JsxNoDuplicatePropsOptions) -> Box<Self> {
+    Box::new(JsxNoDuplicateProps { options })
+  }
+}
+
+impl LintRule for JsxNoDuplicateProps {
+  fn new() -> Box<Self> {
+    Self::with_options(JsxNoDuplicatePropsOptions::default())
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "jsx-no-duplicate-props"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = JsxNoDuplicatePropsVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows passing the same prop more than once to a JSX element.
+
+Only the last of the duplicate props is used, so the others are likely a mistake.
+
+### Options:
+- `ignoreCase`: whether props whose names only differ by case are duplicates, `false` by default.
+
+### Invalid:
+```typescript
+<div id="a" id="b" />
+```
+
+### Valid:
+```typescript
+<div id="a" className="b" />
+<div id="a" {...props} />
+```"#
+  }
+}
+
+struct JsxNoDuplicatePropsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o JsxNoDuplicatePropsOptions,
+}
+
+impl<'c, 'o> JsxNoDuplicatePropsVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o JsxNoDuplicatePropsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn prop_name(&self, name: &JSXAttrName) -> String {
+    let name = match name {
+      JSXAttrName::Ident(ident) => ident.sym.to_string(),
+      JSXAttrName::JSXNamespacedName(namespaced) => {
+        format!("{}:{}", namespaced.ns.sym, namespaced.name.sym)
+      }
+    };
+    if self.options.ignore_case {
+      name.to_lowercase()
+    } else {
+      name
+    }
+  }
+}
+
+impl<'c, 'o> Visit for JsxNoDuplicatePropsVisitor<'c, 'o> {
+  fn visit_jsx_opening_element(
+    &mut self,
+    opening_element: &JSXOpeningElement,
+    parent: &dyn Node,
+  ) {
+    let mut names = HashSet::new();
+    for attr in &opening_element.attrs {
+      // Spread props aren't checked, since their names aren't known.
+      if let JSXAttrOrSpread::JSXAttr(attr) = attr {
+        let name = self.prop_name(&attr.name);
+        if !names.insert(name.clone()) {
+          self.context.add_diagnostic_with_hint(
+            attr.span,
+            "jsx-no-duplicate-props",
+            format!("Duplicate prop `{}`", name),
+            "Remove the duplicate prop, only the last one is used",
+          );
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_jsx_opening_element(
+      self,
+      opening_element,
+      parent,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  #[test]
+  fn jsx_no_duplicate_props_valid() {
+    assert_lint_ok! {
+      JsxNoDuplicateProps,
+      r#"<div id="a" className="b" />"#,
+      r#"<div id="a" ID="b" />"#,
+      r#"<div id="a" {...props} {...props} />"#,
+      r#"<div {...props} id="a" />"#,
+      r#"<svg xlink:href="a" href="b" />"#,
+      r#"<div id="a"><span id="a" /></div>"#,
+      r#"<Foo bar={<div id="a" />} id="a" />"#,
+    };
+  }
+
+  #[test]
+  fn jsx_no_duplicate_props_invalid() {
+    assert_lint_err! {
+      JsxNoDuplicateProps,
+      r#"<div id="a" id="b" />"#: [
+        {
+          col: 12,
+          message: "Duplicate prop `id`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+      r#"<div id="a" {...props} id="b" />"#: [
+        {
+          col: 23,
+          message: "Duplicate prop `id`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+      r#"<Foo a b a={1} a="2"></Foo>"#: [
+        {
+          col: 9,
+          message: "Duplicate prop `a`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        },
+        {
+          col: 15,
+          message: "Duplicate prop `a`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+      r#"<svg xlink:href="a" xlink:href="b" />"#: [
+        {
+          col: 20,
+          message: "Duplicate prop `xlink:href`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+      r#"<div><span id="a" id="b" /></div>"#: [
+        {
+          col: 18,
+          message: "Duplicate prop `id`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn jsx_no_duplicate_props_ignore_case() {
+    assert_lint_ok_with_options! {
+      JsxNoDuplicateProps,
+      json!({ "ignoreCase": true }),
+      r#"<div id="a" className="b" />"#,
+    };
+    assert_lint_err_with_options! {
+      JsxNoDuplicateProps,
+      json!({ "ignoreCase": true }),
+      r#"<div id="a" ID="b" />"#: [
+        {
+          col: 12,
+          message: "Duplicate prop `id`",
+          hint: "Remove the duplicate prop, only the last one is used",
+        }
+      ],
+    };
+  }
+}
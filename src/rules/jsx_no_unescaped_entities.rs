@@ -0,0 +1,249 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::{hygiene::SyntaxContext, BytePos, Span};
+use swc_ecmascript::ast::JSXText;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxNoUnescapedEntitiesOptions {
+  /// The characters that aren't allowed in JSX text, along with the entities
+  /// they can be escaped with.
+  pub forbid: Vec<ForbiddenEntity>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForbiddenEntity {
+  #[serde(rename = "char")]
+  pub character: char,
+  #[serde(default)]
+  pub alternatives: Vec<String>,
+}
+
+impl ForbiddenEntity {
+  fn new(character: char, alternatives: &[&str]) -> Self {
+    Self {
+      character,
+      alternatives: alternatives.iter().map(|a| a.to_string()).collect(),
+    }
+  }
+}
+
+impl Default for JsxNoUnescapedEntitiesOptions {
+  fn default() -> Self {
+    Self {
+      forbid: vec![
+        ForbiddenEntity::new('>', &["&gt;"]),
+        ForbiddenEntity::new('"', &["&quot;", "&ldquo;", "&#34;", "&rdquo;"]),
+        ForbiddenEntity::new('\'', &["&apos;", "&lsquo;", "&#39;", "&rsquo;"]),
+        ForbiddenEntity::new('}', &["&#125;"]),
+      ],
+    }
+  }
+}
+
+pub struct JsxNoUnescapedEntities {
+  options: JsxNoUnescapedEntitiesOptions,
+}
+
+impl JsxNoUnescapedEntities {
+  pub fn with_options(options: JsxNoUnescapedEntitiesOptions) -> Box<Self> {
+    Box::new(JsxNoUnescapedEntities { options })
+  }
+}
+
+impl LintRule for JsxNoUnescapedEntities {
+  fn new() -> Box<Self> {
+    Self::with_options(JsxNoUnescapedEntitiesOptions::default())
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "jsx-no-unescaped-entities"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor =
+      JsxNoUnescapedEntitiesVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows characters in JSX text that should be escaped.
+
+Characters like `>` or `}` in JSX text are easily mistaken for a tag or an
+expression that was meant to be closed, so they should be written as an HTML
+entity or a JavaScript string instead.
+
+### Options:
+- `forbid`: a list of `{ "char": ">", "alternatives": ["&gt;"] }` objects with
+  the forbidden characters and the entities they can be escaped with. Defaults
+  to `>`, `"`, `'` and `}`.
+
+### Invalid:
+```typescript
+<div>1 > 2</div>
+<div>Don't do this</div>
+```
+
+### Valid:
+```typescript
+<div>1 &gt; 2</div>
+<div>{'>'}</div>
+<div>Don&apos;t do this</div>
+```"#
+  }
+}
+
+struct JsxNoUnescapedEntitiesVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o JsxNoUnescapedEntitiesOptions,
+}
+
+impl<'c, 'o> JsxNoUnescapedEntitiesVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o JsxNoUnescapedEntitiesOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+}
+
+fn hint(entity: &ForbiddenEntity) -> String {
+  if entity.alternatives.is_empty() {
+    return format!("Use `{{'{}'}}` instead", entity.character);
+  }
+  let alternatives = entity
+    .alternatives
+    .iter()
+    .map(|a| format!("`{}`", a))
+    .collect::<Vec<_>>()
+    .join(", ");
+  format!("Escape it with {}", alternatives)
+}
+
+impl<'c, 'o> Visit for JsxNoUnescapedEntitiesVisitor<'c, 'o> {
+  fn visit_jsx_text(&mut self, jsx_text: &JSXText, _parent: &dyn Node) {
+    // `raw` is the text as written in the source, so the byte offsets of its
+    // characters line up with the span of the node.
+    for (index, c) in jsx_text.raw.char_indices() {
+      if let Some(entity) =
+        self.options.forbid.iter().find(|e| e.character == c)
+      {
+        let lo = jsx_text.span.lo + BytePos(index as u32);
+        let span = Span::new(
+          lo,
+          lo + BytePos(c.len_utf8() as u32),
+          SyntaxContext::empty(),
+        );
+        self.context.add_diagnostic_with_hint(
+          span,
+          "jsx-no-unescaped-entities",
+          format!("Unescaped `{}` found in JSX text", c),
+          hint(entity),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  #[test]
+  fn jsx_no_unescaped_entities_valid() {
+    assert_lint_ok! {
+      JsxNoUnescapedEntities,
+      "<div>{'>'}</div>",
+      "<div>1 &gt; 2</div>",
+      "<div>Don&apos;t</div>",
+      r#"<div title="'>'" />"#,
+      "<div>{`it's`}</div>",
+      "<div>\n  <span>text</span>\n</div>",
+      "<>no entities here</>",
+    };
+  }
+
+  #[test]
+  fn jsx_no_unescaped_entities_invalid() {
+    assert_lint_err! {
+      JsxNoUnescapedEntities,
+      "<div>1 > 2</div>": [
+        {
+          col: 7,
+          message: "Unescaped `>` found in JSX text",
+          hint: "Escape it with `&gt;`",
+        }
+      ],
+      "<div>Don't</div>": [
+        {
+          col: 8,
+          message: "Unescaped `'` found in JSX text",
+          hint: "Escape it with `&apos;`, `&lsquo;`, `&#39;`, `&rsquo;`",
+        }
+      ],
+      r#"<p>"quoted" <b>a > b</b></p>"#: [
+        {
+          col: 3,
+          message: "Unescaped `\"` found in JSX text",
+          hint: "Escape it with `&quot;`, `&ldquo;`, `&#34;`, `&rdquo;`",
+        },
+        {
+          col: 10,
+          message: "Unescaped `\"` found in JSX text",
+          hint: "Escape it with `&quot;`, `&ldquo;`, `&#34;`, `&rdquo;`",
+        },
+        {
+          col: 17,
+          message: "Unescaped `>` found in JSX text",
+          hint: "Escape it with `&gt;`",
+        }
+      ],
+      "<>é > 1</>": [
+        {
+          col: 4,
+          message: "Unescaped `>` found in JSX text",
+          hint: "Escape it with `&gt;`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn jsx_no_unescaped_entities_forbid() {
+    assert_lint_ok_with_options! {
+      JsxNoUnescapedEntities,
+      json!({ "forbid": [{ "char": "&" }] }),
+      "<div>1 > 2</div>",
+    };
+    assert_lint_err_with_options! {
+      JsxNoUnescapedEntities,
+      json!({ "forbid": [{ "char": ">", "alternatives": ["&gt;"] }, { "char": "~" }] }),
+      "<div>a ~ b > c</div>": [
+        {
+          col: 7,
+          message: "Unescaped `~` found in JSX text",
+          hint: "Use `{'~'}` instead",
+        },
+        {
+          col: 11,
+          message: "Unescaped `>` found in JSX text",
+          hint: "Escape it with `&gt;`",
+        }
+      ],
+    };
+  }
+}
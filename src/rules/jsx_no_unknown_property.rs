@@ -0,0 +1,348 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use swc_ecmascript::ast::JSXAttrName;
+use swc_ecmascript::ast::JSXAttrOrSpread;
+use swc_ecmascript::ast::JSXElementName;
+use swc_ecmascript::ast::JSXOpeningElement;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxNoUnknownPropertyOptions {
+  /// Custom attributes that are allowed on DOM elements. `data-*` and
+  /// `aria-*` attributes are always allowed.
+  pub ignore: Vec<String>,
+}
+
+pub struct JsxNoUnknownProperty {
+  options: JsxNoUnknownPropertyOptions,
+}
+
+impl JsxNoUnknownProperty {
+  pub fn with_options(options: JsxNoUnknownPropertyOptions) -> Box<Self> {
+    Box::new(JsxNoUnknownProperty { options })
+  }
+}
+
+lazy_static! {
+  /// Properties that React accepts on DOM elements, including the
+  /// camelCased SVG attributes.
+  static ref DOM_PROPERTIES: HashSet<&'static str> = [
+    // React specific
+    "children", "dangerouslySetInnerHTML", "key", "ref",
+    "suppressContentEditableWarning", "suppressHydrationWarning",
+    "defaultChecked", "defaultValue",
+    // HTML
+    "accept", "acceptCharset", "accessKey", "action", "allow",
+    "allowFullScreen", "alt", "as", "async", "autoCapitalize",
+    "autoComplete", "autoCorrect", "autoFocus", "autoPlay", "capture",
+    "cellPadding", "cellSpacing", "charSet", "checked", "cite", "className",
+    "colSpan", "cols", "content", "contentEditable", "contextMenu",
+    "controls", "coords", "crossOrigin", "dateTime", "decoding", "default",
+    "defer", "dir", "disabled", "download", "draggable", "encType",
+    "enterKeyHint", "form", "formAction", "formEncType", "formMethod",
+    "formNoValidate", "formTarget", "frameBorder", "headers", "height",
+    "hidden", "high", "href", "hrefLang", "htmlFor", "httpEquiv", "id",
+    "inputMode", "integrity", "is", "itemID", "itemProp", "itemRef",
+    "itemScope", "itemType", "kind", "label", "lang", "list", "loading",
+    "loop", "low", "manifest", "max", "maxLength", "media", "method", "min",
+    "minLength", "multiple", "muted", "name", "noModule", "noValidate",
+    "nonce", "open", "optimum", "pattern", "placeholder", "playsInline",
+    "poster", "preload", "readOnly", "referrerPolicy", "rel", "required",
+    "reversed", "role", "rowSpan", "rows", "sandbox", "scope", "scoped",
+    "scrolling", "selected", "shape", "size", "sizes", "slot", "span",
+    "spellCheck", "src", "srcDoc", "srcLang", "srcSet", "start", "step",
+    "style", "summary", "tabIndex", "target", "title", "translate", "type",
+    "useMap", "value", "width", "wrap",
+    // SVG
+    "clipPath", "clipRule", "cx", "cy", "d", "dx", "dy", "fill",
+    "fillOpacity", "fillRule", "filter", "fontFamily", "fontSize",
+    "fontWeight", "fx", "fy", "gradientTransform", "gradientUnits",
+    "markerEnd", "markerMid", "markerStart", "mask", "offset", "opacity",
+    "patternUnits", "points", "preserveAspectRatio", "r", "rx", "ry",
+    "stopColor", "stopOpacity", "stroke", "strokeDasharray",
+    "strokeDashoffset", "strokeLinecap", "strokeLinejoin",
+    "strokeMiterlimit", "strokeOpacity", "strokeWidth", "textAnchor",
+    "transform", "version", "viewBox", "x", "x1", "x2", "xmlns", "y", "y1",
+    "y2",
+    // Events
+    "onAbort", "onAnimationEnd", "onAnimationIteration", "onAnimationStart",
+    "onBlur", "onCanPlay", "onCanPlayThrough", "onChange", "onClick",
+    "onContextMenu", "onCopy", "onCut", "onDoubleClick", "onDrag",
+    "onDragEnd", "onDragEnter", "onDragLeave", "onDragOver", "onDragStart",
+    "onDrop", "onDurationChange", "onEnded", "onError", "onFocus", "onInput",
+    "onInvalid", "onKeyDown", "onKeyPress", "onKeyUp", "onLoad",
+    "onLoadedData", "onLoadedMetadata", "onLoadStart", "onMouseDown",
+    "onMouseEnter", "onMouseLeave", "onMouseMove", "onMouseOut",
+    "onMouseOver", "onMouseUp", "onPaste", "onPause", "onPlay", "onPlaying",
+    "onPointerCancel", "onPointerDown", "onPointerEnter", "onPointerLeave",
+    "onPointerMove", "onPointerOut", "onPointerOver", "onPointerUp",
+    "onProgress", "onRateChange", "onReset", "onScroll", "onSeeked",
+    "onSeeking", "onSelect", "onStalled", "onSubmit", "onSuspend",
+    "onTimeUpdate", "onToggle", "onTouchCancel", "onTouchEnd",
+    "onTouchMove", "onTouchStart", "onTransitionEnd", "onVolumeChange",
+    "onWaiting", "onWheel",
+  ]
+  .iter()
+  .copied()
+  .collect();
+
+  /// Common mistakes whose correction can't be found by ignoring case.
+  static ref CORRECTIONS: HashMap<&'static str, &'static str> = [
+    ("class", "className"),
+    ("for", "htmlFor"),
+    ("http-equiv", "httpEquiv"),
+    ("accept-charset", "acceptCharset"),
+    ("ondblclick", "onDoubleClick"),
+    ("clip-path", "clipPath"),
+    ("fill-opacity", "fillOpacity"),
+    ("fill-rule", "fillRule"),
+    ("font-family", "fontFamily"),
+    ("font-size", "fontSize"),
+    ("stroke-linecap", "strokeLinecap"),
+    ("stroke-linejoin", "strokeLinejoin"),
+    ("stroke-width", "strokeWidth"),
+    ("text-anchor", "textAnchor"),
+  ]
+  .iter()
+  .copied()
+  .collect();
+
+  /// Maps the lowercased DOM properties to their correct spelling, so that
+  /// `tabindex` or `onclick` can be corrected.
+  static ref LOWERCASE_DOM_PROPERTIES: HashMap<String, &'static str> =
+    DOM_PROPERTIES
+      .iter()
+      .map(|prop| (prop.to_lowercase(), *prop))
+      .collect();
+}
+
+impl LintRule for JsxNoUnknownProperty {
+  fn new() -> Box<Self> {
+    Self::with_options(JsxNoUnknownPropertyOptions::default())
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "jsx-no-unknown-property"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = JsxNoUnknownPropertyVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unknown properties on DOM elements.
+
+JSX uses the DOM property names rather than the HTML attribute names, so
+mistakes like `class` instead of `className` are easy to make. Only lowercase
+(DOM) elements are checked. `data-*` and `aria-*` attributes are always allowed.
+
+### Options:
+- `ignore`: a list of custom attributes that are allowed, empty by default.
+
+### Invalid:
+```typescript
+<div class="box" />
+<label for="name" />
+<div tabindex="0" />
+```
+
+### Valid:
+```typescript
+<div className="box" />
+<label htmlFor="name" />
+<div tabIndex={0} data-id="1" aria-label="box" />
+<Foo class="box" />
+```"#
+  }
+}
+
+struct JsxNoUnknownPropertyVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o JsxNoUnknownPropertyOptions,
+}
+
+impl<'c, 'o> JsxNoUnknownPropertyVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o JsxNoUnknownPropertyOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn is_allowed(&self, name: &str) -> bool {
+    name.starts_with("data-")
+      || name.starts_with("aria-")
+      || DOM_PROPERTIES.contains(name)
+      || self.options.ignore.iter().any(|ignored| ignored == name)
+  }
+}
+
+/// Whether the element is a DOM element, as opposed to a component
+/// (`<Foo />`, `<foo.Bar />`) or a custom element (`<my-element />`).
+fn is_dom_element(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => {
+      let name: &str = &ident.sym;
+      name.starts_with(|c: char| c.is_ascii_lowercase()) && !name.contains('-')
+    }
+    _ => false,
+  }
+}
+
+fn correction(name: &str) -> Option<&'static str> {
+  CORRECTIONS
+    .get(name)
+    .or_else(|| LOWERCASE_DOM_PROPERTIES.get(&name.to_lowercase()))
+    .copied()
+}
+
+impl<'c, 'o> Visit for JsxNoUnknownPropertyVisitor<'c, 'o> {
+  fn visit_jsx_opening_element(
+    &mut self,
+    opening_element: &JSXOpeningElement,
+    parent: &dyn Node,
+  ) {
+    if is_dom_element(&opening_element.name) {
+      for attr in &opening_element.attrs {
+        // Namespaced attributes like `xlink:href` aren't checked.
+        if let JSXAttrOrSpread::JSXAttr(attr) = attr {
+          if let JSXAttrName::Ident(ident) = &attr.name {
+            let name: &str = &ident.sym;
+            if self.is_allowed(name) {
+              continue;
+            }
+            let message = format!("Unknown property `{}` found", name);
+            match correction(name) {
+              Some(correct) => self.context.add_diagnostic_with_hint(
+                attr.span,
+                "jsx-no-unknown-property",
+                message,
+                format!("Use `{}` instead", correct),
+              ),
+              None => self
+                .context
+                .add_diagnostic_with_hint(attr.span, "jsx-no-unknown-property", message, "Use a valid DOM property, or add it to the `ignore` option if it's intended"),
+            }
+          }
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_jsx_opening_element(
+      self,
+      opening_element,
+      parent,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  #[test]
+  fn jsx_no_unknown_property_valid() {
+    assert_lint_ok! {
+      JsxNoUnknownProperty,
+      r#"<div className="x" />"#,
+      r#"<label htmlFor="name" />"#,
+      r#"<div tabIndex={0} onClick={f} />"#,
+      r#"<div data-foo="a" aria-label="b" />"#,
+      r#"<div key="a" ref={ref} {...props} />"#,
+      r#"<svg viewBox="0 0 10 10" xlink:href="a" />"#,
+      r#"<Foo class="x" />"#,
+      r#"<foo.bar class="x" />"#,
+      r#"<my-element class="x" />"#,
+    };
+  }
+
+  #[test]
+  fn jsx_no_unknown_property_invalid() {
+    assert_lint_err! {
+      JsxNoUnknownProperty,
+      r#"<div class="x"/>"#: [
+        {
+          col: 5,
+          message: "Unknown property `class` found",
+          hint: "Use `className` instead",
+        }
+      ],
+      r#"<label for="name" />"#: [
+        {
+          col: 7,
+          message: "Unknown property `for` found",
+          hint: "Use `htmlFor` instead",
+        }
+      ],
+      r#"<div tabindex="0" onclick={f} />"#: [
+        {
+          col: 5,
+          message: "Unknown property `tabindex` found",
+          hint: "Use `tabIndex` instead",
+        },
+        {
+          col: 18,
+          message: "Unknown property `onclick` found",
+          hint: "Use `onClick` instead",
+        }
+      ],
+      r#"<path stroke-width="2" />"#: [
+        {
+          col: 6,
+          message: "Unknown property `stroke-width` found",
+          hint: "Use `strokeWidth` instead",
+        }
+      ],
+      r#"<div foo="bar" />"#: [
+        {
+          col: 5,
+          message: "Unknown property `foo` found",
+          hint: "Use a valid DOM property, or add it to the `ignore` option if it's intended",
+        }
+      ],
+      r#"<Foo><span class="x" /></Foo>"#: [
+        {
+          col: 11,
+          message: "Unknown property `class` found",
+          hint: "Use `className` instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn jsx_no_unknown_property_ignore() {
+    assert_lint_ok_with_options! {
+      JsxNoUnknownProperty,
+      json!({ "ignore": ["foo", "class"] }),
+      r#"<div foo="bar" class="x" />"#,
+    };
+    assert_lint_err_with_options! {
+      JsxNoUnknownProperty,
+      json!({ "ignore": ["foo"] }),
+      r#"<div foo="bar" class="x" />"#: [
+        {
+          col: 15,
+          message: "Unknown property `class` found",
+          hint: "Use `className` instead",
+        }
+      ],
+    };
+  }
+}
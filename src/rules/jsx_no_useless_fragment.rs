@@ -0,0 +1,325 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::jsx_key::has_key_prop;
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::ArrowExpr;
+use swc_ecmascript::ast::BlockStmtOrExpr;
+use swc_ecmascript::ast::Expr;
+use swc_ecmascript::ast::JSXElement;
+use swc_ecmascript::ast::JSXElementChild;
+use swc_ecmascript::ast::JSXElementName;
+use swc_ecmascript::ast::JSXExpr;
+use swc_ecmascript::ast::JSXFragment;
+use swc_ecmascript::ast::JSXObject;
+use swc_ecmascript::ast::ReturnStmt;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxNoUselessFragmentOptions {
+  /// Allows a fragment whose only child is an expression, as in
+  /// `<>{foo}</>`.
+  pub allow_expressions: bool,
+}
+
+pub struct JsxNoUselessFragment {
+  options: JsxNoUselessFragmentOptions,
+}
+
+impl JsxNoUselessFragment {
+  pub fn with_options(options: JsxNoUselessFragmentOptions) -> Box<Self> {
+    Box::new(JsxNoUselessFragment { options })
+  }
+}
+
+impl LintRule for JsxNoUselessFragment {
+  fn new() -> Box<Self> {
+    Self::with_options(JsxNoUselessFragmentOptions::default())
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "jsx-no-useless-fragment"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = JsxNoUselessFragmentVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows fragments with less than two children.
+
+A fragment groups several children without adding an element to the output, so a fragment with a single child or without children can be removed. Keyed fragments are allowed, and so is a returned fragment whose only child is text, which turns the text into JSX.
+
+### Options:
+- `allowExpressions`: whether a fragment whose only child is an expression, as in `<>{foo}</>`, is allowed, `false` by default.
+
+### Invalid:
+```typescript
+<><Foo /></>;
+<Fragment><Foo /></Fragment>;
+<></>;
+```
+
+### Valid:
+```typescript
+<><Foo /><Bar /></>;
+<Fragment key={item.id}><Foo /></Fragment>;
+const Greeting = () => <>Hello</>;
+```"#
+  }
+}
+
+struct JsxNoUselessFragmentVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o JsxNoUselessFragmentOptions,
+  /// Spans of the fragments which are returned from a function, where a
+  /// single text child is allowed.
+  returned_fragments: HashSet<Span>,
+}
+
+impl<'c, 'o> JsxNoUselessFragmentVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o JsxNoUselessFragmentOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      returned_fragments: HashSet::new(),
+    }
+  }
+
+  fn mark_returned(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Paren(paren) => self.mark_returned(&paren.expr),
+      Expr::JSXFragment(fragment) => {
+        self.returned_fragments.insert(fragment.span);
+      }
+      Expr::JSXElement(element) => {
+        self.returned_fragments.insert(element.span);
+      }
+      _ => {}
+    }
+  }
+
+  fn check_children(&mut self, span: Span, children: &[JSXElementChild]) {
+    let children = children
+      .iter()
+      .filter(|child| !is_padding(child))
+      .collect::<Vec<_>>();
+    let is_useless = match children.as_slice() {
+      [] => true,
+      [JSXElementChild::JSXText(_)] => !self.returned_fragments.contains(&span),
+      [JSXElementChild::JSXExprContainer(_)] => !self.options.allow_expressions,
+      [_] => true,
+      _ => false,
+    };
+    if is_useless {
+      self.context.add_diagnostic_with_hint(
+        span,
+        "jsx-no-useless-fragment",
+        "Fragments should contain more than one child",
+        "Remove the fragment, it doesn't group anything",
+      );
+    }
+  }
+}
+
+/// Whitespace around children on their own lines and comments aren't
+/// rendered.
+fn is_padding(child: &JSXElementChild) -> bool {
+  match child {
+    JSXElementChild::JSXText(text) => {
+      text.value.trim().is_empty() && text.value.contains('\n')
+    }
+    JSXElementChild::JSXExprContainer(container) => {
+      matches!(container.expr, JSXExpr::JSXEmptyExpr(_))
+    }
+    _ => false,
+  }
+}
+
+/// Returns `true` for `<Fragment>` and `<React.Fragment>`.
+fn is_fragment_element(element: &JSXElement) -> bool {
+  match &element.opening.name {
+    JSXElementName::Ident(ident) => ident.sym == *"Fragment",
+    JSXElementName::JSXMemberExpr(member) => {
+      member.prop.sym == *"Fragment"
+        && matches!(&member.obj, JSXObject::Ident(obj) if obj.sym == *"React")
+    }
+    JSXElementName::JSXNamespacedName(_) => false,
+  }
+}
+
+impl<'c, 'o> Visit for JsxNoUselessFragmentVisitor<'c, 'o> {
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, parent: &dyn Node) {
+    if let Some(arg) = &return_stmt.arg {
+      self.mark_returned(arg);
+    }
+    swc_ecmascript::visit::visit_return_stmt(self, return_stmt, parent);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
+    if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+      self.mark_returned(expr);
+    }
+    swc_ecmascript::visit::visit_arrow_expr(self, arrow_expr, parent);
+  }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment, parent: &dyn Node) {
+    self.check_children(fragment.span, &fragment.children);
+    swc_ecmascript::visit::visit_jsx_fragment(self, fragment, parent);
+  }
+
+  fn visit_jsx_element(&mut self, element: &JSXElement, parent: &dyn Node) {
+    if is_fragment_element(element) && !has_key_prop(&element.opening.attrs) {
+      self.check_children(element.span, &element.children);
+    }
+    swc_ecmascript::visit::visit_jsx_element(self, element, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  #[test]
+  fn jsx_no_useless_fragment_valid() {
+    assert_lint_ok! {
+      JsxNoUselessFragment,
+      "<><Foo/><Bar/></>",
+      "<>foo<Bar/></>",
+      "<><Foo/>{bar}</>",
+      "<Fragment><Foo/><Bar/></Fragment>",
+      "<React.Fragment><Foo/><Bar/></React.Fragment>",
+      "<Fragment key={item.id}><Foo/></Fragment>",
+      "<Fragment key={item.id}></Fragment>",
+      "const Greeting = () => <>Hello</>;",
+      "function Greeting() { return (<>Hello</>); }",
+      r#"
+const el = (
+  <>
+    <Foo />
+    <Bar />
+  </>
+);
+"#,
+      "<div><Foo/></div>",
+      "<Other.Fragment><Foo/></Other.Fragment>",
+    };
+  }
+
+  #[test]
+  fn jsx_no_useless_fragment_invalid() {
+    assert_lint_err! {
+      JsxNoUselessFragment,
+      "<><Foo/></>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<></>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<>foo</>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<>{foo}</>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<Fragment><Foo/></Fragment>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<React.Fragment><Foo/></React.Fragment>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "<div><><Foo/></></div>": [
+        {
+          col: 5,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      "const Foo = () => <><Bar/></>;": [
+        {
+          col: 18,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+      r#"
+const el = (
+  <>
+    {/* a comment */}
+    <Foo />
+  </>
+);
+"#: [
+        {
+          line: 3,
+          col: 2,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn jsx_no_useless_fragment_allow_expressions() {
+    assert_lint_ok_with_options! {
+      JsxNoUselessFragment,
+      json!({ "allowExpressions": true }),
+      "<>{foo}</>",
+      "<Fragment>{foo}</Fragment>",
+    };
+    assert_lint_err_with_options! {
+      JsxNoUselessFragment,
+      json!({ "allowExpressions": true }),
+      "<><Foo/></>": [
+        {
+          col: 0,
+          message: "Fragments should contain more than one child",
+          hint: "Remove the fragment, it doesn't group anything",
+        }
+      ],
+    };
+  }
+}
@@ -1,36 +1,56 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use crate::linter::Context;
+use serde::Deserialize;
+use std::collections::HashMap;
 
+pub mod accessor_return_consistency;
 pub mod adjacent_overload_signatures;
+pub mod arrow_body_style;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod camelcase;
+pub mod consistent_type_definitions;
+pub mod consistent_type_imports;
 pub mod constructor_super;
 pub mod default_param_last;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
+pub mod func_style;
 pub mod getter_return;
+pub mod jsx_key;
+pub mod jsx_no_duplicate_props;
+pub mod jsx_no_unescaped_entities;
+pub mod jsx_no_unknown_property;
+pub mod jsx_no_useless_fragment;
 pub mod no_array_constructor;
+pub mod no_async_in_array_predicate;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
 pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
+pub mod no_confusing_arrow;
 pub mod no_const_assign;
 pub mod no_constant_condition;
+pub mod no_constant_typeof;
 pub mod no_control_regex;
+pub mod no_danger;
+pub mod no_dead_store_in_loop;
 pub mod no_debugger;
 pub mod no_delete_var;
+pub mod no_deno_exit;
+pub mod no_deprecated_deno_api;
 pub mod no_dupe_args;
 pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_dynamic_import_nonliteral;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_interface;
@@ -38,20 +58,26 @@ pub mod no_empty_pattern;
 pub mod no_eval;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
+pub mod no_external_import;
 pub mod no_extra_boolean_cast;
 pub mod no_extra_non_null_assertion;
 pub mod no_extra_semi;
 pub mod no_fallthrough;
 pub mod no_func_assign;
 pub mod no_global_assign;
+pub mod no_implicit_coercion;
 pub mod no_import_assign;
+pub mod no_import_type_side_effects;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
 pub mod no_irregular_whitespace;
+pub mod no_magic_numbers;
+pub mod no_misleading_character_class;
 pub mod no_misused_new;
 pub mod no_mixed_spaces_and_tabs;
 pub mod no_namespace;
+pub mod no_neg_zero_key;
 pub mod no_new_symbol;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
@@ -59,35 +85,68 @@ pub mod no_obj_calls;
 pub mod no_octal;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
+pub mod no_redundant_type_constituents_lite;
 pub mod no_regex_spaces;
+pub mod no_restricted_exports;
 pub mod no_self_assign;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_storage_in_server;
 pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_undef;
+pub mod no_unexpected_multiline;
+pub mod no_unnecessary_boolean_literal_compare;
 pub mod no_unreachable;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
+pub mod no_untyped_public_export;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_useless_assignment;
+pub mod no_useless_length_check;
+pub mod no_useless_spread;
 pub mod no_var;
+pub mod no_window;
+pub mod no_window_prefix;
 pub mod no_with;
+pub mod object_shorthand;
+pub mod prefer_array_flat_map;
 pub mod prefer_as_const;
+pub mod prefer_at;
 pub mod prefer_const;
+pub mod prefer_destructuring;
+pub mod prefer_function_type;
+pub mod prefer_includes;
 pub mod prefer_namespace_keyword;
+pub mod prefer_nullish_coalescing;
+pub mod prefer_number_properties;
+pub mod prefer_optional_chain;
+pub mod prefer_rest_params;
+pub mod prefer_string_starts_ends_with;
+pub mod radix;
+pub mod require_array_sort_compare;
+pub mod require_await;
+pub mod require_json_import_assertion;
 pub mod require_yield;
 pub mod single_var_declarator;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
 
-pub trait LintRule {
+/// A rule checking a module for problems. Besides the rules of this crate,
+/// embedders can implement their own and pass them to
+/// `LinterBuilder::custom_rules`.
+///
+/// Rules lint a module in parallel, so they must be `Send` and `Sync`.
+pub trait LintRule: Send + Sync {
   fn new() -> Box<Self>
   where
     Self: Sized;
+  /// Checks `module`, reporting problems to the `context` of this rule, as
+  /// with `Context::add_diagnostic`.
   fn lint_module(
     &self,
     context: &mut Context,
@@ -104,36 +163,56 @@ pub trait LintRule {
 
 pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
   vec![
+    accessor_return_consistency::AccessorReturnConsistency::new(),
     adjacent_overload_signatures::AdjacentOverloadSignatures::new(),
+    arrow_body_style::ArrowBodyStyle::new(),
     ban_ts_comment::BanTsComment::new(),
     ban_types::BanTypes::new(),
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
     camelcase::Camelcase::new(),
+    consistent_type_definitions::ConsistentTypeDefinitions::new(),
+    consistent_type_imports::ConsistentTypeImports::new(),
     constructor_super::ConstructorSuper::new(),
     default_param_last::DefaultParamLast::new(),
     eqeqeq::Eqeqeq::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
+    func_style::FuncStyle::new(),
     getter_return::GetterReturn::new(),
+    jsx_key::JsxKey::new(),
+    jsx_no_duplicate_props::JsxNoDuplicateProps::new(),
+    jsx_no_unescaped_entities::JsxNoUnescapedEntities::new(),
+    jsx_no_unknown_property::JsxNoUnknownProperty::new(),
+    jsx_no_useless_fragment::JsxNoUselessFragment::new(),
     no_array_constructor::NoArrayConstructor::new(),
+    no_async_in_array_predicate::NoAsyncInArrayPredicate::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
+    no_storage_in_server::NoStorageInServer::new(),
     no_case_declarations::NoCaseDeclarations::new(),
     no_class_assign::NoClassAssign::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
+    no_confusing_arrow::NoConfusingArrow::new(),
     no_const_assign::NoConstAssign::new(),
     no_constant_condition::NoConstantCondition::new(),
+    no_constant_typeof::NoConstantTypeof::new(),
     no_control_regex::NoControlRegex::new(),
+    no_danger::NoDanger::new(),
+    no_dead_store_in_loop::NoDeadStoreInLoop::new(),
     no_debugger::NoDebugger::new(),
     no_delete_var::NoDeleteVar::new(),
+    no_deno_exit::NoDenoExit::new(),
+    no_deprecated_deno_api::NoDeprecatedDenoApi::new(),
     no_dupe_args::NoDupeArgs::new(),
     no_dupe_class_members::NoDupeClassMembers::new(),
     no_dupe_else_if::NoDupeElseIf::new(),
     no_dupe_keys::NoDupeKeys::new(),
     no_duplicate_case::NoDuplicateCase::new(),
+    no_redundant_type_constituents_lite::NoDuplicateTypeConstituents::new(),
+    no_dynamic_import_nonliteral::NoDynamicImportNonliteral::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
     no_empty_interface::NoEmptyInterface::new(),
@@ -141,20 +220,26 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_eval::NoEval::new(),
     no_ex_assign::NoExAssign::new(),
     no_explicit_any::NoExplicitAny::new(),
+    no_external_import::NoExternalImport::new(),
     no_extra_boolean_cast::NoExtraBooleanCast::new(),
     no_extra_non_null_assertion::NoExtraNonNullAssertion::new(),
     no_extra_semi::NoExtraSemi::new(),
     no_fallthrough::NoFallthrough::new(),
     no_func_assign::NoFuncAssign::new(),
     no_global_assign::NoGlobalAssign::new(),
+    no_implicit_coercion::NoImplicitCoercion::new(),
     no_import_assign::NoImportAssign::new(),
+    no_import_type_side_effects::NoImportTypeSideEffects::new(),
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
+    no_magic_numbers::NoMagicNumbers::new(),
+    no_misleading_character_class::NoMisleadingCharacterClass::new(),
     no_misused_new::NoMisusedNew::new(),
     no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::new(),
     no_namespace::NoNamespace::new(),
+    no_neg_zero_key::NoNegZeroKey::new(),
     no_new_symbol::NoNewSymbol::new(),
     no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain::new(),
     no_non_null_assertion::NoNonNullAssertion::new(),
@@ -163,6 +248,7 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_restricted_exports::NoRestrictedExports::new(),
     no_self_assign::NoSelfAssign::new(),
     no_setter_return::NoSetterReturn::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
@@ -171,16 +257,39 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_this_before_super::NoThisBeforeSuper::new(),
     no_throw_literal::NoThrowLiteral::new(),
     no_undef::NoUndef::new(),
+    no_unexpected_multiline::NoUnexpectedMultiline::new(),
+    no_unnecessary_boolean_literal_compare::NoUnnecessaryBooleanLiteralCompare::new(),
     no_unreachable::NoUnreachable::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
+    no_untyped_public_export::NoUntypedPublicExport::new(),
     no_unused_labels::NoUnusedLabels::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_useless_assignment::NoUselessAssignment::new(),
+    no_useless_length_check::NoUselessLengthCheck::new(),
+    no_useless_spread::NoUselessSpread::new(),
     no_var::NoVar::new(),
+    no_window::NoWindow::new(),
+    no_window_prefix::NoWindowPrefix::new(),
     no_with::NoWith::new(),
+    object_shorthand::ObjectShorthand::new(),
+    prefer_array_flat_map::PreferArrayFlatMap::new(),
     prefer_as_const::PreferAsConst::new(),
+    prefer_at::PreferAt::new(),
     prefer_const::PreferConst::new(),
+    prefer_destructuring::PreferDestructuring::new(),
+    prefer_function_type::PreferFunctionType::new(),
+    prefer_includes::PreferIncludes::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_nullish_coalescing::PreferNullishCoalescing::new(),
+    prefer_number_properties::PreferNumberProperties::new(),
+    prefer_optional_chain::PreferOptionalChain::new(),
+    prefer_rest_params::PreferRestParams::new(),
+    prefer_string_starts_ends_with::PreferStringStartsEndsWith::new(),
+    radix::Radix::new(),
+    require_array_sort_compare::RequireArraySortCompare::new(),
+    require_await::RequireAwait::new(),
+    require_json_import_assertion::RequireJsonImportAssertion::new(),
     require_yield::RequireYield::new(),
     single_var_declarator::SingleVarDeclarator::new(),
     triple_slash_reference::TripleSlashReference::new(),
@@ -196,6 +305,236 @@ pub fn get_recommended_rules() -> Vec<Box<dyn LintRule>> {
     .collect()
 }
 
+/// Returns a markdown page documenting all the rules, sorted by code.
+pub fn generate_rule_docs() -> String {
+  let mut rules = get_all_rules();
+  rules.sort_by_key(|rule| rule.code());
+
+  let mut docs = String::from("# Rules\n");
+  for rule in rules {
+    docs.push_str(&format!("\n## {}\n\n", rule.code()));
+    if !rule.tags().is_empty() {
+      let tags = rule
+        .tags()
+        .iter()
+        .map(|tag| format!("`{}`", tag))
+        .collect::<Vec<_>>();
+      docs.push_str(&format!("Tags: {}\n\n", tags.join(", ")));
+    }
+    let rule_docs = rule.docs().trim();
+    if rule_docs.is_empty() {
+      docs.push_str("(no documentation)\n");
+    } else {
+      docs.push_str(rule_docs);
+      docs.push('\n');
+    }
+  }
+  docs
+}
+
+/// Selects the rules to lint with, and their options.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LintConfig {
+  /// Tags of the rules to start from, as in `"recommended"`.
+  pub tags: Vec<String>,
+  /// Codes of rules to lint with in addition to the tagged ones.
+  pub enabled: Vec<String>,
+  /// Codes of rules not to lint with, even if tagged or enabled.
+  pub disabled: Vec<String>,
+  /// Options of rules, keyed by the rule code.
+  pub rule_options: HashMap<String, serde_json::Value>,
+}
+
+impl Default for LintConfig {
+  fn default() -> Self {
+    LintConfig {
+      tags: vec!["recommended".to_string()],
+      enabled: vec![],
+      disabled: vec![],
+      rule_options: HashMap::new(),
+    }
+  }
+}
+
+/// Returns the rules selected by `config`, in the order of `get_all_rules`.
+///
+/// Fails if `config` refers to an unknown rule, or if the options of a rule
+/// are invalid.
+pub fn build_rules(
+  config: &LintConfig,
+) -> Result<Vec<Box<dyn LintRule>>, serde_json::Error> {
+  let all_rules = get_all_rules();
+  let codes = config
+    .enabled
+    .iter()
+    .chain(&config.disabled)
+    .chain(config.rule_options.keys());
+  for code in codes {
+    if !all_rules.iter().any(|rule| rule.code() == code) {
+      return Err(serde::de::Error::custom(format!("Unknown rule `{}`", code)));
+    }
+  }
+
+  let mut rules = vec![];
+  for rule in all_rules {
+    let code = rule.code();
+    let tagged = rule
+      .tags()
+      .iter()
+      .any(|tag| config.tags.iter().any(|t| t == tag));
+    let enabled = config.enabled.iter().any(|c| c == code);
+    let disabled = config.disabled.iter().any(|c| c == code);
+    if !(tagged || enabled) || disabled {
+      continue;
+    }
+
+    match config.rule_options.get(code) {
+      Some(options) => {
+        rules.push(get_rule_with_options(code, options.clone())?)
+      }
+      None => rules.push(rule),
+    }
+  }
+  Ok(rules)
+}
+
+/// Returns the rule `code`, built with the given `options`.
+pub(crate) fn get_rule_with_options(
+  code: &str,
+  options: serde_json::Value,
+) -> Result<Box<dyn LintRule>, serde_json::Error> {
+  use serde_json::from_value;
+
+  let rule: Box<dyn LintRule> = match code {
+    "arrow-body-style" => {
+      arrow_body_style::ArrowBodyStyle::with_options(from_value(options)?)
+    }
+    "consistent-type-definitions" => {
+      consistent_type_definitions::ConsistentTypeDefinitions::with_options(
+        from_value(options)?,
+      )
+    }
+    "consistent-type-imports" => {
+      consistent_type_imports::ConsistentTypeImports::with_options(from_value(
+        options,
+      )?)
+    }
+    "eqeqeq" => eqeqeq::Eqeqeq::with_options(from_value(options)?),
+    "explicit-module-boundary-types" => {
+      explicit_module_boundary_types::ExplicitModuleBoundaryTypes::with_options(
+        from_value(options)?,
+      )
+    }
+    "func-style" => func_style::FuncStyle::with_options(from_value(options)?),
+    "getter-return" => {
+      getter_return::GetterReturn::with_options(from_value(options)?)
+    }
+    "jsx-no-duplicate-props" => {
+      jsx_no_duplicate_props::JsxNoDuplicateProps::with_options(from_value(
+        options,
+      )?)
+    }
+    "jsx-no-unescaped-entities" => {
+      jsx_no_unescaped_entities::JsxNoUnescapedEntities::with_options(
+        from_value(options)?,
+      )
+    }
+    "jsx-no-unknown-property" => {
+      jsx_no_unknown_property::JsxNoUnknownProperty::with_options(from_value(
+        options,
+      )?)
+    }
+    "jsx-no-useless-fragment" => {
+      jsx_no_useless_fragment::JsxNoUselessFragment::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-browser-storage" => {
+      no_storage_in_server::NoStorageInServer::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-confusing-arrow" => {
+      no_confusing_arrow::NoConfusingArrow::with_options(from_value(options)?)
+    }
+    "no-constant-condition" => {
+      no_constant_condition::NoConstantCondition::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-dynamic-import-nonliteral" => {
+      no_dynamic_import_nonliteral::NoDynamicImportNonliteral::with_options(
+        from_value(options)?,
+      )
+    }
+    "no-empty" => no_empty::NoEmpty::with_options(from_value(options)?),
+    "no-external-import" => {
+      no_external_import::NoExternalImport::with_options(from_value(options)?)
+    }
+    "no-fallthrough" => {
+      no_fallthrough::NoFallthrough::with_options(from_value(options)?)
+    }
+    "no-global-assign" => {
+      no_global_assign::NoGlobalAssign::with_options(from_value(options)?)
+    }
+    "no-implicit-coercion" => {
+      no_implicit_coercion::NoImplicitCoercion::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-magic-numbers" => {
+      no_magic_numbers::NoMagicNumbers::with_options(from_value(options)?)
+    }
+    "no-mixed-spaces-and-tabs" => {
+      no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-restricted-exports" => {
+      no_restricted_exports::NoRestrictedExports::with_options(from_value(
+        options,
+      )?)
+    }
+    "no-self-assign" => {
+      no_self_assign::NoSelfAssign::with_options(from_value(options)?)
+    }
+    "object-shorthand" => {
+      object_shorthand::ObjectShorthand::with_options(from_value(options)?)
+    }
+    "prefer-const" => {
+      prefer_const::PreferConst::with_options(from_value(options)?)
+    }
+    "prefer-destructuring" => {
+      prefer_destructuring::PreferDestructuring::with_options(from_value(
+        options,
+      )?)
+    }
+    "prefer-nullish-coalescing" => {
+      prefer_nullish_coalescing::PreferNullishCoalescing::with_options(
+        from_value(options)?,
+      )
+    }
+    "radix" => radix::Radix::with_options(from_value(options)?),
+    "require-array-sort-compare" => {
+      require_array_sort_compare::RequireArraySortCompare::with_options(
+        from_value(options)?,
+      )
+    }
+    "use-isnan" => use_isnan::UseIsNaN::with_options(from_value(options)?),
+    "valid-typeof" => {
+      valid_typeof::ValidTypeof::with_options(from_value(options)?)
+    }
+    _ => {
+      return Err(serde::de::Error::custom(format!(
+        "Rule `{}` doesn't take options",
+        code
+      )))
+    }
+  };
+  Ok(rule)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -219,4 +558,101 @@ mod tests {
       assert_eq!(sorted.code(), unsorted.code());
     }
   }
+
+  #[test]
+  fn rule_docs() {
+    let docs = generate_rule_docs();
+    assert!(docs.starts_with("# Rules\n"));
+    assert!(docs.contains(
+      "\n## no-debugger\n\nTags: `recommended`\n\nDisallows the use of the `debugger` statement.\n"
+    ));
+    assert!(docs.contains("\n## no-var\n\nDisallows the `var` keyword.\n"));
+    assert!(docs.contains("\n## no-eval\n\n(no documentation)\n"));
+
+    // Sections are sorted by code.
+    let getter_return = docs.find("\n## getter-return\n").unwrap();
+    let no_debugger = docs.find("\n## no-debugger\n").unwrap();
+    assert!(getter_return < no_debugger);
+  }
+
+  fn build_codes(config: &str) -> Vec<&'static str> {
+    let config: LintConfig = serde_json::from_str(config).unwrap();
+    build_rules(&config)
+      .unwrap()
+      .iter()
+      .map(|rule| rule.code())
+      .collect()
+  }
+
+  #[test]
+  fn build_rules_default() {
+    let codes = build_rules(&LintConfig::default())
+      .unwrap()
+      .iter()
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>();
+    let recommended = get_recommended_rules()
+      .iter()
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>();
+    assert_eq!(codes, recommended);
+  }
+
+  #[test]
+  fn build_rules_enabled_and_disabled() {
+    let codes = build_codes(
+      r#"{ "enabled": ["no-var", "eqeqeq"], "disabled": ["no-debugger"] }"#,
+    );
+    assert!(codes.contains(&"no-var"));
+    assert!(codes.contains(&"eqeqeq"));
+    assert!(codes.contains(&"no-empty"));
+    assert!(!codes.contains(&"no-debugger"));
+
+    let codes = build_codes(
+      r#"{ "tags": [], "enabled": ["no-var", "no-debugger"], "disabled": ["no-var"] }"#,
+    );
+    assert_eq!(codes, vec!["no-debugger"]);
+  }
+
+  #[test]
+  fn build_rules_options() {
+    let config: LintConfig = serde_json::from_str(
+      r#"{
+        "tags": [],
+        "enabled": ["no-empty"],
+        "ruleOptions": { "no-empty": { "allowEmptyCatch": true } }
+      }"#,
+    )
+    .unwrap();
+    let mut linter = crate::linter::LinterBuilder::default()
+      .rules(build_rules(&config).unwrap())
+      .build();
+    let diagnostics = linter
+      .lint(
+        "deno_lint_test.ts".to_string(),
+        "try { foo(); } catch (e) {} if (foo) {}".to_string(),
+      )
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range.start.col, 37);
+  }
+
+  #[test]
+  fn build_rules_errors() {
+    let config: LintConfig =
+      serde_json::from_str(r#"{ "enabled": ["no-such-rule"] }"#).unwrap();
+    assert!(build_rules(&config).is_err());
+
+    let config: LintConfig = serde_json::from_str(
+      r#"{ "ruleOptions": { "no-debugger": { "foo": true } } }"#,
+    )
+    .unwrap();
+    assert!(build_rules(&config).is_err());
+
+    let config: LintConfig = serde_json::from_str(
+      r#"{ "ruleOptions": { "no-empty": { "allowEmptyCatch": 1 } } }"#,
+    )
+    .unwrap();
+    assert!(build_rules(&config).is_err());
+  }
 }
@@ -0,0 +1,169 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::{is_async_function, static_property_name};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoAsyncInArrayPredicate;
+
+/// Array methods whose callback's return value is used as a condition.
+const PREDICATE_METHODS: &[&str] =
+  &["every", "filter", "find", "findIndex", "some"];
+
+impl LintRule for NoAsyncInArrayPredicate {
+  fn new() -> Box<Self> {
+    Box::new(NoAsyncInArrayPredicate)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-async-in-array-predicate"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoAsyncInArrayPredicateVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows async callbacks in array methods that expect a predicate.
+
+`filter`, `find`, `findIndex`, `some` and `every` use the return value of
+their callback as a condition. An async callback returns a promise, which is
+always truthy, so the condition is never what was intended.
+
+### Invalid:
+```typescript
+const active = users.filter(async (user) => await isActive(user));
+const found = users.some(async function (user) {
+  return await isActive(user);
+});
+```
+
+### Valid:
+```typescript
+const results = await Promise.all(users.map((user) => isActive(user)));
+const active = users.filter((_, i) => results[i]);
+
+const found = users.some((user) => user.active);
+```"#
+  }
+}
+
+struct NoAsyncInArrayPredicateVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoAsyncInArrayPredicateVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> VisitAll for NoAsyncInArrayPredicateVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let method = match &call_expr.callee {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Member(member_expr) => static_property_name(member_expr),
+        _ => None,
+      },
+      ExprOrSuper::Super(_) => None,
+    };
+
+    if let Some(method) = method {
+      if !PREDICATE_METHODS.contains(&method) {
+        return;
+      }
+      if let Some(callback) = call_expr.args.get(0) {
+        if callback.spread.is_none() && is_async_function(&callback.expr) {
+          self.context.add_diagnostic_with_hint(
+            callback.expr.span(),
+            "no-async-in-array-predicate",
+            format!("Async callback passed to `{}`", method),
+            "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_async_in_array_predicate_valid() {
+    assert_lint_ok! {
+      NoAsyncInArrayPredicate,
+      "arr.filter(x => x > 1);",
+      "arr.filter(function (x) { return x > 1; });",
+      "arr.map(async x => await f(x));",
+      "arr.forEach(async x => { await f(x); });",
+      "arr.reduce(async (acc, x) => (await acc) + x, Promise.resolve(0));",
+      "arr.filter(f);",
+      "arr.filter(...callbacks);",
+      "filter(async x => x);",
+      "arr[method](async x => x);",
+      "arr.some(x => x, async () => {});",
+    };
+  }
+
+  #[test]
+  fn no_async_in_array_predicate_invalid() {
+    assert_lint_err! {
+      NoAsyncInArrayPredicate,
+      "arr.filter(async x => await f(x));": [
+        {
+          col: 11,
+          message: "Async callback passed to `filter`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        }
+      ],
+      "arr.find(async function (x) { return await f(x); });": [
+        {
+          col: 9,
+          message: "Async callback passed to `find`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        }
+      ],
+      "arr.findIndex((async (x) => x));": [
+        {
+          col: 14,
+          message: "Async callback passed to `findIndex`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        }
+      ],
+      "arr['some'](async x => x);": [
+        {
+          col: 12,
+          message: "Async callback passed to `some`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        }
+      ],
+      "a.b.every(async x => x.filter(async y => y));": [
+        {
+          col: 10,
+          message: "Async callback passed to `every`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        },
+        {
+          col: 30,
+          message: "Async callback passed to `filter`",
+          hint: "The returned promise is always truthy, await the results with `Promise.all` first or use a `for` loop instead",
+        }
+      ],
+    };
+  }
+}
@@ -1,7 +1,8 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_ecmascript::ast::{Expr, NewExpr, ParenExpr};
+use crate::swc_util::is_async_function;
+use swc_ecmascript::ast::{Expr, NewExpr};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::VisitAll;
@@ -42,15 +43,6 @@ impl<'c> NoAsyncPromiseExecutorVisitor<'c> {
   }
 }
 
-fn is_async_function(expr: &Expr) -> bool {
-  match expr {
-    Expr::Fn(fn_expr) => fn_expr.function.is_async,
-    Expr::Arrow(arrow_expr) => arrow_expr.is_async,
-    Expr::Paren(ParenExpr { ref expr, .. }) => is_async_function(&**expr),
-    _ => false,
-  }
-}
-
 impl<'c> VisitAll for NoAsyncPromiseExecutorVisitor<'c> {
   noop_visit_type!();
 
@@ -1,5 +1,6 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use swc_common::Spanned;
 use swc_ecmascript::ast::BinaryOp::*;
 use swc_ecmascript::ast::Expr::{Lit, Unary};
 use swc_ecmascript::ast::Lit::Num;
@@ -58,6 +59,21 @@ impl<'c> NoCompareNegZeroVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  /// Suggests `Object.is` for equality comparisons with `operand`, while
+  /// relational comparisons treat `-0` the same as `0`.
+  fn hint(&self, op: BinaryOp, operand: &Expr) -> String {
+    let operand = self
+      .context
+      .source_map
+      .span_to_snippet(operand.span())
+      .unwrap_or_else(|_| "x".to_string());
+    match op {
+      EqEq | EqEqEq => format!("Use `Object.is({}, -0)` instead", operand),
+      NotEq | NotEqEq => format!("Use `!Object.is({}, -0)` instead", operand),
+      _ => "Compare against `0` instead, which is the same".to_string(),
+    }
+  }
 }
 
 impl<'c> VisitAll for NoCompareNegZeroVisitor<'c> {
@@ -68,13 +84,20 @@ impl<'c> VisitAll for NoCompareNegZeroVisitor<'c> {
       return;
     }
 
-    if bin_expr.left.is_neg_zero() || bin_expr.right.is_neg_zero() {
-      self.context.add_diagnostic(
-        bin_expr.span,
-        "no-compare-neg-zero",
-        "Do not compare against -0",
-      );
-    }
+    let operand = if bin_expr.right.is_neg_zero() {
+      &bin_expr.left
+    } else if bin_expr.left.is_neg_zero() {
+      &bin_expr.right
+    } else {
+      return;
+    };
+    let hint = self.hint(bin_expr.op, operand);
+    self.context.add_diagnostic_with_hint(
+      bin_expr.span,
+      "no-compare-neg-zero",
+      "Do not compare against -0",
+      hint,
+    );
   }
 }
 
@@ -183,4 +206,39 @@ mod tests {
     // nested
     assert_lint_err::<NoCompareNegZero>("{} == { foo: x === -0 }", 13);
   }
+
+  #[test]
+  fn no_compare_neg_zero_hint() {
+    assert_lint_err! {
+      NoCompareNegZero,
+      "x === 0; x === -0;": [
+        {
+          col: 9,
+          message: "Do not compare against -0",
+          hint: "Use `Object.is(x, -0)` instead",
+        }
+      ],
+      "-0 === x": [
+        {
+          col: 0,
+          message: "Do not compare against -0",
+          hint: "Use `Object.is(x, -0)` instead",
+        }
+      ],
+      "foo.bar != -0": [
+        {
+          col: 0,
+          message: "Do not compare against -0",
+          hint: "Use `!Object.is(foo.bar, -0)` instead",
+        }
+      ],
+      "x < -0": [
+        {
+          col: 0,
+          message: "Do not compare against -0",
+          hint: "Compare against `0` instead, which is the same",
+        }
+      ],
+    };
+  }
 }
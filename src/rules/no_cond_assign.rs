@@ -2,7 +2,7 @@
 use super::{Context, LintRule};
 use swc_common::Span;
 use swc_ecmascript::ast::Expr;
-use swc_ecmascript::ast::Expr::{Assign, Bin, Paren};
+use swc_ecmascript::ast::Expr::{Assign, Bin, Paren, Seq};
 use swc_ecmascript::ast::Module;
 use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 
@@ -22,8 +22,25 @@ impl LintRule for NoCondAssign {
   }
 
   fn lint_module(&self, context: &mut Context, module: &Module) {
-    let mut visitor = NoCondAssignVisitor::new(context);
+    let mut visitor = NoCondAssignVisitor::new();
     module.visit_all_with(module, &mut visitor);
+    for span in visitor.spans {
+      let message =
+        "Expected a conditional expression and instead saw an assignment";
+      match context.source_map.span_to_snippet(span) {
+        Ok(snippet) => {
+          let change =
+            context.create_text_change(span, format!("({})", snippet));
+          context.add_diagnostic_with_fix(
+            span,
+            "no-cond-assign",
+            message,
+            vec![change],
+          );
+        }
+        Err(_) => context.add_diagnostic(span, "no-cond-assign", message),
+      }
+    }
   }
 
   fn docs(&self) -> &'static str {
@@ -31,6 +48,8 @@ impl LintRule for NoCondAssign {
 
 Use of the assignment operator within a conditional statement is often the result of mistyping the equality operator, `==`. If an assignment within a conditional statement is required then this rule allows it by wrapping the assignment in parentheses.
 
+The problems reported by this rule can be fixed automatically, by wrapping the assignment in parentheses.
+
 ### Valid:
 ```typescript
 var x;
@@ -59,31 +78,28 @@ function setHeight(someNode) {
     someNode.height = "100px";
   } while (someNode = someNode.parentNode);
 }
+```
+```typescript
+// The parentheses belong to the sequence, not to the assignment.
+if ((x, y = 0)) {}
 ```"#
   }
 }
 
-struct NoCondAssignVisitor<'c> {
-  context: &'c mut Context,
+struct NoCondAssignVisitor {
+  /// Spans of the assignments used as conditions.
+  spans: Vec<Span>,
 }
 
-impl<'c> NoCondAssignVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
-  }
-
-  fn add_diagnostic(&mut self, span: Span) {
-    self.context.add_diagnostic(
-      span,
-      "no-cond-assign",
-      "Expected a conditional expression and instead saw an assignment",
-    );
+impl NoCondAssignVisitor {
+  fn new() -> Self {
+    Self { spans: vec![] }
   }
 
   fn check_condition(&mut self, condition: &Expr) {
     match condition {
       Assign(assign) => {
-        self.add_diagnostic(assign.span);
+        self.spans.push(assign.span);
       }
       Bin(bin) => {
         if bin.op == swc_ecmascript::ast::BinaryOp::LogicalOr {
@@ -91,12 +107,23 @@ impl<'c> NoCondAssignVisitor<'c> {
           self.check_condition(&bin.right);
         }
       }
+      // The value of a sequence is its last expression, as in `if (a, b = c)`.
+      Seq(seq) => {
+        if let Some(last) = seq.exprs.last() {
+          self.check_condition(last);
+        }
+      }
+      // Parentheses around a sequence don't mark its last assignment as
+      // intentional, as in `if ((a, b = c))`.
+      Paren(paren) if matches!(&*paren.expr, Seq(_)) => {
+        self.check_condition(&paren.expr);
+      }
       _ => {}
     }
   }
 }
 
-impl<'c> VisitAll for NoCondAssignVisitor<'c> {
+impl VisitAll for NoCondAssignVisitor {
   noop_visit_type!();
 
   fn visit_if_stmt(
@@ -147,8 +174,19 @@ impl<'c> VisitAll for NoCondAssignVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::diagnostic::LintDiagnostic;
+  use crate::linter::LinterBuilder;
   use crate::test_util::*;
 
+  fn lint_and_fix(src: &str) -> (String, Vec<LintDiagnostic>) {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoCondAssign::new()])
+      .build();
+    linter
+      .lint_and_fix("deno_lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint")
+  }
+
   #[test]
   fn no_cond_assign_valid() {
     assert_lint_ok! {
@@ -170,6 +208,12 @@ mod tests {
       "if (function(node) { return node = parentNode; }) { }",
       "const x; const b = (x === 0) ? 1 : 0;",
       "switch (foo) { case a = b: bar(); }",
+      "if ((a, (b = c))) { }",
+      "if ((a = b, c)) { }",
+      "while (a, (b = c)) { }",
+      "for (const x of a = b) { }",
+      "for (const x in a = b) { }",
+      "for (x of (a, b = c)) { }",
     };
   }
 
@@ -212,4 +256,40 @@ mod tests {
       19,
     );
   }
+
+  #[test]
+  fn no_cond_assign_sequence() {
+    assert_lint_err::<NoCondAssign>("if ((a, b = c)) { }", 8);
+    assert_lint_err::<NoCondAssign>("if (a, b = c) { }", 7);
+    assert_lint_err::<NoCondAssign>("while ((a, b = c)) { }", 11);
+    assert_lint_err::<NoCondAssign>("do { } while ((a, b = c));", 18);
+    assert_lint_err::<NoCondAssign>("for (; a, b = c;) { }", 10);
+    assert_lint_err::<NoCondAssign>("if (x || (a, b = c)) { }", 13);
+    assert_lint_err::<NoCondAssign>("if ((a, (b, c = d))) { }", 12);
+
+    let (fixed, diagnostics) = lint_and_fix("if ((a, b = c)) {}");
+    assert_eq!(fixed, "if ((a, (b = c))) {}");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn no_cond_assign_fix() {
+    let (fixed, diagnostics) =
+      lint_and_fix("if (x = 0) {}\nwhile (a = b || (c = d)) {}");
+    assert_eq!(fixed, "if ((x = 0)) {}\nwhile ((a = b || (c = d))) {}");
+    assert!(diagnostics.is_empty());
+
+    let (fixed, diagnostics) = lint_and_fix("let b = (x = 0) ? 1 : 0;");
+    assert_eq!(fixed, "let b = ((x = 0)) ? 1 : 0;");
+    assert!(diagnostics.is_empty());
+
+    let src =
+      "// deno-lint-ignore no-cond-assign\nif (x = 0) {}\nif (y = 0) {}";
+    let (fixed, diagnostics) = lint_and_fix(src);
+    assert_eq!(
+      fixed,
+      "// deno-lint-ignore no-cond-assign\nif (x = 0) {}\nif ((y = 0)) {}"
+    );
+    assert!(diagnostics.is_empty());
+  }
 }
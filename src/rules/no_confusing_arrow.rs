@@ -0,0 +1,179 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{ArrowExpr, BlockStmtOrExpr, Expr, Module};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoConfusingArrowOptions {
+  /// Allow a conditional body which is wrapped in parentheses.
+  pub allow_parens: bool,
+}
+
+impl Default for NoConfusingArrowOptions {
+  fn default() -> Self {
+    Self { allow_parens: true }
+  }
+}
+
+pub struct NoConfusingArrow {
+  options: NoConfusingArrowOptions,
+}
+
+impl NoConfusingArrow {
+  pub fn with_options(options: NoConfusingArrowOptions) -> Box<Self> {
+    Box::new(NoConfusingArrow { options })
+  }
+}
+
+impl LintRule for NoConfusingArrow {
+  fn new() -> Box<Self> {
+    Self::with_options(NoConfusingArrowOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-confusing-arrow"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoConfusingArrowVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows arrow functions whose body is a conditional expression.
+
+`a => a ? b : c` can easily be misread as the comparison `a >= b`. Wrapping the body in parentheses or a block makes the intent clear.
+
+The `allowParens` option (default `true`) allows a conditional body wrapped in parentheses.
+
+### Invalid:
+```typescript
+const f = (a) => a ? 1 : 2;
+```
+
+### Valid:
+```typescript
+const f = (a) => (a ? 1 : 2);
+const g = (a) => {
+  return a ? 1 : 2;
+};
+```"#
+  }
+}
+
+struct NoConfusingArrowVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoConfusingArrowOptions,
+}
+
+impl<'c, 'o> NoConfusingArrowVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoConfusingArrowOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+}
+
+impl<'c, 'o> VisitAll for NoConfusingArrowVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    let is_confusing = match &arrow_expr.body {
+      BlockStmtOrExpr::Expr(expr) => match &**expr {
+        Expr::Cond(_) => true,
+        Expr::Paren(paren_expr) => {
+          !self.options.allow_parens
+            && matches!(&*paren_expr.expr, Expr::Cond(_))
+        }
+        _ => false,
+      },
+      BlockStmtOrExpr::BlockStmt(_) => false,
+    };
+
+    if is_confusing {
+      self.context.add_diagnostic_with_hint(
+        arrow_expr.span,
+        "no-confusing-arrow",
+        "Arrow function used ambiguously with a conditional expression",
+        if self.options.allow_parens {
+          "Wrap the conditional expression in parentheses or a block"
+        } else {
+          "Wrap the conditional expression in a block"
+        },
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_confusing_arrow_valid() {
+    assert_lint_ok! {
+      NoConfusingArrow,
+      "a => (a ? 1 : 2);",
+      "a => { return a ? 1 : 2; };",
+      "(a) => a >= 1;",
+      "a => a;",
+      "const x = a ? () => 1 : () => 2;",
+    };
+  }
+
+  #[test]
+  fn no_confusing_arrow_invalid() {
+    assert_lint_err! {
+      NoConfusingArrow,
+      "a => a ? 1 : 2;": [
+        {
+          col: 0,
+          message: "Arrow function used ambiguously with a conditional expression",
+          hint: "Wrap the conditional expression in parentheses or a block",
+        }
+      ],
+      "const f = (a, b) => a ? b : 0;": [
+        {
+          col: 10,
+          message: "Arrow function used ambiguously with a conditional expression",
+          hint: "Wrap the conditional expression in parentheses or a block",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_confusing_arrow_disallow_parens() {
+    assert_lint_ok_with_options! {
+      NoConfusingArrow,
+      json!({ "allowParens": false }),
+      "a => { return a ? 1 : 2; };",
+    };
+    assert_lint_err_with_options! {
+      NoConfusingArrow,
+      json!({ "allowParens": false }),
+      "a => (a ? 1 : 2);": [
+        {
+          col: 0,
+          message: "Arrow function used ambiguously with a conditional expression",
+          hint: "Wrap the conditional expression in a block",
+        }
+      ],
+      "a => a ? 1 : 2;": [
+        {
+          col: 0,
+          message: "Arrow function used ambiguously with a conditional expression",
+          hint: "Wrap the conditional expression in a block",
+        }
+      ],
+    };
+  }
+}
@@ -2,18 +2,50 @@
 use super::Context;
 use super::LintRule;
 
+use crate::swc_util::find_lhs_ids;
+use serde::Deserialize;
+use std::collections::HashSet;
 use swc_common::Span;
 use swc_common::Spanned;
 use swc_ecmascript::ast::{
-  BinaryOp, CondExpr, Expr, IfStmt, Lit, Module, UnaryOp,
+  AssignExpr, BinaryOp, CondExpr, DoWhileStmt, Expr, ForInStmt, ForOfStmt,
+  ForStmt, Ident, IfStmt, Lit, Module, Pat, UnaryExpr, UnaryOp, UpdateExpr,
+  VarDecl, VarDeclKind, VarDeclOrPat, WhileStmt,
 };
-use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::{
+  noop_visit_type, Node, Visit, VisitAll, VisitAllWith, VisitWith,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoConstantConditionOptions {
+  /// Also reports constant conditions of loops. Disable it to allow
+  /// intentional infinite loops, as in `while (true) {}`.
+  pub check_loops: bool,
+}
 
-pub struct NoConstantCondition;
+impl Default for NoConstantConditionOptions {
+  fn default() -> Self {
+    NoConstantConditionOptions { check_loops: true }
+  }
+}
+
+pub struct NoConstantCondition {
+  options: NoConstantConditionOptions,
+}
+
+impl NoConstantCondition {
+  pub fn with_options(options: NoConstantConditionOptions) -> Box<Self> {
+    Box::new(NoConstantCondition { options })
+  }
+}
 
 impl LintRule for NoConstantCondition {
   fn new() -> Box<Self> {
-    Box::new(NoConstantCondition)
+    Self::with_options(NoConstantConditionOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -25,18 +57,145 @@ impl LintRule for NoConstantCondition {
   }
 
   fn lint_module(&self, context: &mut Context, module: &Module) {
-    let mut visitor = NoConstantConditionVisitor::new(context);
+    let mut collector = ConstantBindingCollector::default();
+    module.visit_with(module, &mut collector);
+    let mut visitor = NoConstantConditionVisitor::new(
+      context,
+      &self.options,
+      collector.constant_bindings(),
+    );
     module.visit_all_with(module, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows constant expressions as conditions.
+
+A condition which is always truthy or always falsy, like a literal, an object or a function, is usually a mistake or a leftover from debugging.
+
+### Options:
+- `checkLoops`: whether the conditions of `while`, `do...while` and `for` loops are checked, `true` by default. Set it to `false` to allow intentional infinite loops, as in `while (true) {}`.
+
+A loop condition is also constant if it's a `let` or `const` variable, or its negation, which is initialized to a literal and never reassigned.
+
+### Invalid:
+```typescript
+if (true) {}
+if (x = 0) {}
+const result = {} ? a : b;
+while (false) {}
+const done = false;
+while (!done) {}
+```
+
+### Valid:
+```typescript
+if (x) {}
+if (x === 0) {}
+const result = x ? a : b;
+while (x < 10) {}
+for (;;) {}
+```"#
+  }
+}
+
+/// Collects the `let` and `const` bindings which are initialized to a literal
+/// and never reassigned.
+#[derive(Default)]
+struct ConstantBindingCollector {
+  literal_inits: HashSet<Id>,
+  reassigned: HashSet<Id>,
 }
 
-struct NoConstantConditionVisitor<'c> {
+impl ConstantBindingCollector {
+  fn constant_bindings(self) -> HashSet<Id> {
+    let reassigned = self.reassigned;
+    self
+      .literal_inits
+      .into_iter()
+      .filter(|id| !reassigned.contains(id))
+      .collect()
+  }
+}
+
+impl Visit for ConstantBindingCollector {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, parent: &dyn Node) {
+    if var_decl.kind != VarDeclKind::Var {
+      for decl in &var_decl.decls {
+        if let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) {
+          if let Expr::Lit(_) = &**init {
+            self.literal_inits.insert(ident.to_id());
+          }
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_var_decl(self, var_decl, parent);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, parent: &dyn Node) {
+    self
+      .reassigned
+      .extend(find_lhs_ids::<Id>(&assign_expr.left).into_iter());
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, parent);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, parent: &dyn Node) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.reassigned.insert(ident.to_id());
+    }
+    swc_ecmascript::visit::visit_update_expr(self, update_expr, parent);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, parent: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_in_stmt.left {
+      let ids: Vec<Id> = find_ids(pat);
+      self.reassigned.extend(ids);
+    }
+    swc_ecmascript::visit::visit_for_in_stmt(self, for_in_stmt, parent);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, parent: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_of_stmt.left {
+      let ids: Vec<Id> = find_ids(pat);
+      self.reassigned.extend(ids);
+    }
+    swc_ecmascript::visit::visit_for_of_stmt(self, for_of_stmt, parent);
+  }
+}
+
+/// Returns the identifier if `expr` is an identifier or its negation, as in
+/// `done` or `!done`.
+fn as_negated_ident(expr: &Expr) -> Option<&Ident> {
+  match expr {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Paren(paren) => as_negated_ident(&paren.expr),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang,
+      arg,
+      ..
+    }) => as_negated_ident(arg),
+    _ => None,
+  }
+}
+
+struct NoConstantConditionVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoConstantConditionOptions,
+  constant_bindings: HashSet<Id>,
 }
 
-impl<'c> NoConstantConditionVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> NoConstantConditionVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoConstantConditionOptions,
+    constant_bindings: HashSet<Id>,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      constant_bindings,
+    }
   }
 
   fn add_diagnostic(&mut self, span: Span) {
@@ -156,9 +315,31 @@ impl<'c> NoConstantConditionVisitor<'c> {
       self.add_diagnostic(span);
     }
   }
+
+  fn report_loop(&mut self, condition: &Expr) {
+    if !self.options.check_loops {
+      return;
+    }
+    if self.is_constant(condition, None, true) {
+      self.add_diagnostic(condition.span());
+      return;
+    }
+    let is_constant_binding = as_negated_ident(condition)
+      .map_or(false, |ident| {
+        self.constant_bindings.contains(&ident.to_id())
+      });
+    if is_constant_binding {
+      self.context.add_diagnostic_with_hint(
+        condition.span(),
+        "no-constant-condition",
+        "Use of a constant expressions as conditions is not allowed.",
+        "The loop condition is constant, as the variable is never reassigned",
+      );
+    }
+  }
 }
 
-impl<'c> VisitAll for NoConstantConditionVisitor<'c> {
+impl<'c, 'o> VisitAll for NoConstantConditionVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
@@ -169,15 +350,8 @@ impl<'c> VisitAll for NoConstantConditionVisitor<'c> {
     self.report(&if_stmt.test);
   }
 
-  /* TODO(bartlomieju): temporarly disabled because
-    deno_std uses while (true) {} loops
-  fn visit_while_stmt(
-    &mut self,
-    while_stmt: &WhileStmt,
-    _parent: &dyn Node,
-  ) {
-    self.report(&while_stmt.test)
-    while_stmt.visit_children_with(self);
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.report_loop(&while_stmt.test);
   }
 
   fn visit_do_while_stmt(
@@ -185,21 +359,14 @@ impl<'c> VisitAll for NoConstantConditionVisitor<'c> {
     do_while_stmt: &DoWhileStmt,
     _parent: &dyn Node,
   ) {
-    self.report(&do_while_stmt.test)
-    do_while_stmt.visit_children_with(self);
+    self.report_loop(&do_while_stmt.test);
   }
 
-  fn visit_for_stmt(
-    &mut self,
-    for_stmt: &ForStmt,
-    _parent: &dyn Node,
-  ) {
-    if let Some(cond) = for_stmt.test.as_ref() {
-      self.report(cond)
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    if let Some(test) = &for_stmt.test {
+      self.report_loop(test);
     }
-    for_stmt.visit_children_with(self);
   }
-  */
 }
 
 #[cfg(test)]
@@ -279,26 +446,7 @@ mod tests {
          else
           ;"#,
       r#"if ([...x]+'' === 'y'){}"#,
-      r#"for(;true;);"#,
-      r#"for(;``;);"#,
-      r#"for(;`foo`;);"#,
-      r#"for(;`foo${bar}`;);"#,
-      r#"do{}while(true)"#,
-      r#"do{}while(t = -2)"#,
-      r#"do{}while(``)"#,
-      r#"do{}while(`foo`)"#,
-      r#"do{}while(`foo${bar}`)"#,
-      r#"while([]);"#,
-      r#"while(~!0);"#,
-      r#"while(x = 1);"#,
-      r#"while(function(){});"#,
-      r#"while(true);"#,
-      r#"while(() => {});"#,
-      r#"while(`foo`);"#,
-      r#"while(``);"#,
-      r#"while(`${'foo'}`);"#,
-      r#"while(`${'foo' + 'bar'}`);"#,
-      r#"while(typeof x){}"#,
+      r#"while(x) { while(y) {} }"#,
 
       // nested
       r#"if (foo) { if (bar) {} }"#,
@@ -395,6 +543,103 @@ mod tests {
     assert_lint_err::<NoConstantCondition>(r#"foo ? true ? 1 : 2 : 3"#, 6);
   }
 
+  #[test]
+  fn no_constant_condition_loops() {
+    assert_lint_err::<NoConstantCondition>(r#"for(;true;);"#, 5);
+    assert_lint_err::<NoConstantCondition>(r#"for(;``;);"#, 5);
+    assert_lint_err::<NoConstantCondition>(r#"for(;`foo`;);"#, 5);
+    assert_lint_err::<NoConstantCondition>(r#"for(;`foo${bar}`;);"#, 5);
+    assert_lint_err::<NoConstantCondition>(r#"do{}while(true)"#, 10);
+    assert_lint_err::<NoConstantCondition>(r#"do{}while(t = -2)"#, 10);
+    assert_lint_err::<NoConstantCondition>(r#"do{}while(``)"#, 10);
+    assert_lint_err::<NoConstantCondition>(r#"do{}while(`foo`)"#, 10);
+    assert_lint_err::<NoConstantCondition>(r#"do{}while(`foo${bar}`)"#, 10);
+    assert_lint_err::<NoConstantCondition>(r#"while([]);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(~!0);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(x = 1);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(function(){});"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(true);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(false) {}"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(() => {});"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(`foo`);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(``);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(`${'foo'}`);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(`${'foo' + 'bar'}`);"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(typeof x){}"#, 6);
+    assert_lint_err::<NoConstantCondition>(r#"while(x) { if(true) {} }"#, 14);
+  }
+
+  #[test]
+  fn no_constant_condition_constant_bindings() {
+    assert_lint_ok! {
+      NoConstantCondition,
+      "let done = false; while (!done) { done = step(); }",
+      "let i = 0; while (i) { i--; }",
+      "let [done] = [false]; while (!done) { [done] = step(); }",
+      "let done = false; for (done of xs) {} while (!done) {}",
+      "let done = false; for ([done] of xs) {} while (!done) {}",
+      "let key = ''; for (key in obj) {} while (!key) {}",
+      "var done = false; while (!done) {}",
+      "const done = f(); while (!done) {}",
+      "const done = false; if (!done) {}",
+      "const done = false; function f(done) { while (!done) {} }",
+      "while (!done) {}",
+    };
+    assert_lint_err! {
+      NoConstantCondition,
+      "const done = false; while (!done) {}": [
+        {
+          col: 27,
+          message: "Use of a constant expressions as conditions is not allowed.",
+          hint: "The loop condition is constant, as the variable is never reassigned",
+        }
+      ],
+      "let running = true; do {} while (running);": [
+        {
+          col: 33,
+          message: "Use of a constant expressions as conditions is not allowed.",
+          hint: "The loop condition is constant, as the variable is never reassigned",
+        }
+      ],
+      "const limit = 10; for (; limit;) {}": [
+        {
+          col: 25,
+          message: "Use of a constant expressions as conditions is not allowed.",
+          hint: "The loop condition is constant, as the variable is never reassigned",
+        }
+      ],
+    };
+    assert_lint_ok_with_options! {
+      NoConstantCondition,
+      serde_json::json!({ "checkLoops": false }),
+      "const done = false; while (!done) {}",
+    };
+  }
+
+  #[test]
+  fn no_constant_condition_check_loops_false() {
+    let options = serde_json::json!({ "checkLoops": false });
+    assert_lint_ok_with_options! {
+      NoConstantCondition,
+      options,
+      r#"while(true);"#,
+      r#"while(true) { if (x) break; }"#,
+      r#"for(;true;);"#,
+      r#"do{}while(true)"#,
+      r#"while(false) {}"#,
+    };
+    assert_lint_err_with_options! {
+      NoConstantCondition,
+      options,
+      r#"while(true) { if (true) break; }"#: [
+        {
+          col: 18,
+          message: "Use of a constant expressions as conditions is not allowed.",
+        }
+      ],
+    };
+  }
+
   // TODO(humancalico) make these tests pass
   #[test]
   fn failing() {
@@ -410,11 +655,6 @@ mod tests {
     // assert_lint_ok::<NoConstantCondition>(r#"if(true && abc==='str' || def ==='str'){}"#);
     // assert_lint_ok::<NoConstantCondition>(r#"if(true && typeof abc==='string'){}"#);
 
-    // TODO(humancalico) add a configuration option for { checkLoops: false } https://eslint.org/docs/rules/no-constant-condition#checkloops
-    // assert_lint_ok::<NoConstantCondition>(r#"while(true);"#);
-    // assert_lint_ok::<NoConstantCondition>(r#"for(;true;);"#);
-    // assert_lint_ok::<NoConstantCondition>(r#"do{}while(true)"#);
-
     // assert_lint_ok::<NoConstantCondition>(r#"function* foo(){while(true){yield 'foo';}}"#,);
     // assert_lint_ok::<NoConstantCondition>(r#"function* foo(){for(;true;){yield 'foo';}}"#);
     // assert_lint_ok::<NoConstantCondition>(r#"function* foo(){do{yield 'foo';}while(true)}"#);
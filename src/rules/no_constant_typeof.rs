@@ -0,0 +1,143 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::valid_typeof::is_valid_typeof_string;
+use super::{Context, LintRule};
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, Expr, Lit, Module, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+
+pub struct NoConstantTypeof;
+
+impl LintRule for NoConstantTypeof {
+  fn new() -> Box<Self> {
+    Box::new(NoConstantTypeof)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-constant-typeof"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoConstantTypeofVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows comparing the result of `typeof` against a string it can never return.
+
+Such a comparison is constant: `===` is always `false` and `!==` is always `true`. This is usually caused by a typo, or by expecting `typeof` to distinguish e.g. arrays or `null`.
+
+### Invalid:
+```typescript
+typeof x === "strnig";
+typeof x === "array";
+typeof x !== "null";
+```
+
+### Valid:
+```typescript
+typeof x === "string";
+Array.isArray(x);
+x === null;
+```"#
+  }
+}
+
+struct NoConstantTypeofVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoConstantTypeofVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+fn is_typeof(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::TypeOf,
+      ..
+    })
+  )
+}
+
+impl<'c> VisitAll for NoConstantTypeofVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if !matches!(
+      bin_expr.op,
+      BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq
+    ) {
+      return;
+    }
+
+    let operand = if is_typeof(&bin_expr.left) {
+      &bin_expr.right
+    } else if is_typeof(&bin_expr.right) {
+      &bin_expr.left
+    } else {
+      return;
+    };
+
+    if let Expr::Lit(Lit::Str(str)) = &**operand {
+      if !is_valid_typeof_string(&str.value) {
+        self.context.add_diagnostic_with_hint(
+          bin_expr.span,
+          "no-constant-typeof",
+          format!("`typeof` never returns \"{}\", so this comparison is constant", str.value),
+          "Valid `typeof` results are \"undefined\", \"object\", \"boolean\", \"number\", \"string\", \"function\", \"symbol\" and \"bigint\"",
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_constant_typeof_valid() {
+    assert_lint_ok! {
+      NoConstantTypeof,
+      r#"typeof x === "string";"#,
+      r#"typeof x == "undefined";"#,
+      r#""bigint" !== typeof x;"#,
+      "typeof x === y;",
+      "typeof x === typeof y;",
+      r#"x === "strnig";"#,
+      r#"typeof x > "strnig";"#,
+    };
+  }
+
+  #[test]
+  fn no_constant_typeof_invalid() {
+    assert_lint_err! {
+      NoConstantTypeof,
+      r#"typeof x === "strnig";"#: [
+        {
+          col: 0,
+          message: "`typeof` never returns \"strnig\", so this comparison is constant",
+          hint: "Valid `typeof` results are \"undefined\", \"object\", \"boolean\", \"number\", \"string\", \"function\", \"symbol\" and \"bigint\"",
+        }
+      ],
+      r#"if ("array" == typeof x) {}"#: [
+        {
+          col: 4,
+          message: "`typeof` never returns \"array\", so this comparison is constant",
+          hint: "Valid `typeof` results are \"undefined\", \"object\", \"boolean\", \"number\", \"string\", \"function\", \"symbol\" and \"bigint\"",
+        }
+      ],
+      r#"typeof x !== "null";"#: [
+        {
+          col: 0,
+          message: "`typeof` never returns \"null\", so this comparison is constant",
+          hint: "Valid `typeof` results are \"undefined\", \"object\", \"boolean\", \"number\", \"string\", \"function\", \"symbol\" and \"bigint\"",
+        }
+      ],
+    };
+  }
+}
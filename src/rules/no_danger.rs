@@ -0,0 +1,129 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::JSXAttr;
+use swc_ecmascript::ast::JSXAttrName;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoDanger;
+
+impl LintRule for NoDanger {
+  fn new() -> Box<Self> {
+    Box::new(NoDanger)
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    "no-danger"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoDangerVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the `dangerouslySetInnerHTML` JSX attribute.
+
+Setting HTML from code is risky, because it's easy to inadvertently expose users to a cross-site scripting (XSS) attack.
+
+This rule only lints JSX, so it's skipped unless the source is parsed as JSX or TSX.
+
+### Invalid:
+```typescript
+<div dangerouslySetInnerHTML={{ __html: "Hello World" }} />
+```
+
+### Valid:
+```typescript
+<div>Hello World</div>
+```"#
+  }
+}
+
+struct NoDangerVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDangerVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for NoDangerVisitor<'c> {
+  fn visit_jsx_attr(&mut self, jsx_attr: &JSXAttr, parent: &dyn Node) {
+    if let JSXAttrName::Ident(ident) = &jsx_attr.name {
+      if ident.sym == *"dangerouslySetInnerHTML" {
+        self.context.add_diagnostic_with_hint(
+          jsx_attr.span,
+          "no-danger",
+          "Do not use `dangerouslySetInnerHTML`",
+          "Render the content as JSX children, or sanitize the HTML if it must be set directly",
+        );
+      }
+    }
+    swc_ecmascript::visit::visit_jsx_attr(self, jsx_attr, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_danger_valid() {
+    assert_lint_ok! {
+      NoDanger,
+      "<div className='foo' />",
+      "<div>{text}</div>",
+      "<div data-dangerouslySetInnerHTML='foo' />",
+      "const dangerouslySetInnerHTML = { __html: x };",
+      "<Foo bar={{ dangerouslySetInnerHTML: x }} />",
+    };
+  }
+
+  #[test]
+  fn no_danger_invalid() {
+    assert_lint_err! {
+      NoDanger,
+      "<div dangerouslySetInnerHTML={{__html: x}} />": [
+        {
+          col: 5,
+          message: "Do not use `dangerouslySetInnerHTML`",
+          hint: "Render the content as JSX children, or sanitize the HTML if it must be set directly",
+        }
+      ],
+      "<Foo dangerouslySetInnerHTML={html}><span /></Foo>": [
+        {
+          col: 5,
+          message: "Do not use `dangerouslySetInnerHTML`",
+          hint: "Render the content as JSX children, or sanitize the HTML if it must be set directly",
+        }
+      ],
+      r#"
+const el = (
+  <div>
+    <p dangerouslySetInnerHTML={{ __html: x }} />
+  </div>
+);
+      "#: [
+        {
+          line: 4,
+          col: 7,
+          message: "Do not use `dangerouslySetInnerHTML`",
+          hint: "Render the content as JSX children, or sanitize the HTML if it must be set directly",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,368 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::no_useless_assignment::{
+  as_assignment, as_overwrite, find_reference,
+};
+use super::Context;
+use super::LintRule;
+use crate::scopes::BindingKind;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BreakStmt, Class, ContinueStmt, Decl, DoWhileStmt, ForInStmt,
+  ForOfStmt, ForStmt, Function, Ident, Module, Pat, ReturnStmt, Stmt,
+  ThrowStmt, VarDeclKind, WhileStmt,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoDeadStoreInLoop;
+
+impl LintRule for NoDeadStoreInLoop {
+  fn new() -> Box<Self> {
+    Box::new(NoDeadStoreInLoop)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-dead-store-in-loop"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoDeadStoreInLoopVisitor::new(context, module);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assignments in a loop body which are overwritten before being read.
+
+Such assignments are repeated on every iteration, but their value is always
+replaced before anything reads it.
+
+Only variables which are not referenced from any function are checked, since
+calling such a function could read the variable at any time. `let` bindings
+declared in the loop body are left to `no-useless-assignment`.
+
+### Invalid:
+```typescript
+let x;
+while (cond()) {
+  x = compute();
+  x = other();
+  use(x);
+}
+```
+```typescript
+for (const item of items) {
+  var x = compute(item);
+  x = other(item);
+  use(x);
+}
+```
+
+### Valid:
+```typescript
+for (const item of items) {
+  let x = compute(item);
+  x = other(x);
+  use(x);
+}
+```"#
+  }
+}
+
+struct NoDeadStoreInLoopVisitor<'c, 'm> {
+  context: &'c mut Context,
+  module: &'m Module,
+}
+
+impl<'c, 'm> NoDeadStoreInLoopVisitor<'c, 'm> {
+  fn new(context: &'c mut Context, module: &'m Module) -> Self {
+    Self { context, module }
+  }
+
+  /// Checks the stores made directly in the statements of a loop body.
+  fn check_loop_body(&mut self, body: &Stmt) {
+    let stmts = match body {
+      Stmt::Block(block_stmt) => &block_stmt.stmts,
+      _ => return,
+    };
+    // The `let` bindings of the loop body are checked by no-useless-assignment.
+    let body_bindings = stmts.iter().flat_map(let_bindings).collect::<Vec<_>>();
+    for (index, stmt) in stmts.iter().enumerate() {
+      for (ident, span) in stores(stmt) {
+        let id = ident.to_id();
+        if body_bindings.contains(&id) {
+          continue;
+        }
+        if self.is_tracked(&id) && self.is_overwritten(&stmts[index + 1..], &id)
+        {
+          self.context.add_diagnostic_with_hint(
+            span,
+            "no-dead-store-in-loop",
+            format!(
+              "The value assigned to `{}` is overwritten before it is read",
+              ident.sym
+            ),
+            "Remove the assignment, or use the value before it is overwritten",
+          );
+        }
+      }
+    }
+  }
+
+  /// Checks if `id` is a local variable that no function can read.
+  fn is_tracked(&self, id: &Id) -> bool {
+    let is_variable = matches!(
+      self.context.scope.var(id).map(|var| var.kind()),
+      Some(BindingKind::Var)
+        | Some(BindingKind::Let)
+        | Some(BindingKind::Param)
+    );
+    is_variable && !find_reference(self.module, id).in_closure
+  }
+
+  /// Checks if `id` is overwritten by one of `stmts` before being read.
+  fn is_overwritten(&self, stmts: &[Stmt], id: &Id) -> bool {
+    for stmt in stmts {
+      if as_overwrite(stmt, id).is_some() {
+        return true;
+      }
+      if find_reference(stmt, id).found {
+        return false;
+      }
+      // After leaving the iteration, the value may be read by the loop's
+      // condition or after the loop.
+      if may_leave_iteration(stmt) {
+        return false;
+      }
+    }
+    false
+  }
+}
+
+/// Checks if `stmt` contains a `break`, `continue`, `return` or `throw`
+/// statement, ignoring nested functions.
+fn may_leave_iteration(stmt: &Stmt) -> bool {
+  let mut finder = ExitFinder { found: false };
+  stmt.visit_with(stmt, &mut finder);
+  finder.found
+}
+
+struct ExitFinder {
+  found: bool,
+}
+
+impl Visit for ExitFinder {
+  noop_visit_type!();
+
+  fn visit_break_stmt(&mut self, _: &BreakStmt, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_continue_stmt(&mut self, _: &ContinueStmt, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_return_stmt(&mut self, _: &ReturnStmt, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_throw_stmt(&mut self, _: &ThrowStmt, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+/// Returns the identifiers that `stmt` binds with `let`.
+fn let_bindings(stmt: &Stmt) -> Vec<Id> {
+  match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) if var_decl.kind == VarDeclKind::Let => {
+      var_decl
+        .decls
+        .iter()
+        .filter_map(|decl| match &decl.name {
+          Pat::Ident(ident) => Some(ident.to_id()),
+          _ => None,
+        })
+        .collect()
+    }
+    _ => vec![],
+  }
+}
+
+/// Returns the variables that `stmt` stores a value to, as in `let x = 1;` or
+/// `x = 1;`, along with the span of each store.
+fn stores(stmt: &Stmt) -> Vec<(&Ident, Span)> {
+  match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) => var_decl
+      .decls
+      .iter()
+      .enumerate()
+      .filter_map(|(index, decl)| match (&decl.name, &decl.init) {
+        (Pat::Ident(ident), Some(_)) => {
+          // The value may be read by one of the following declarators.
+          let id = ident.to_id();
+          let is_read = var_decl.decls[index + 1..]
+            .iter()
+            .any(|decl| find_reference(decl, &id).found);
+          if is_read {
+            None
+          } else {
+            Some((ident, decl.span))
+          }
+        }
+        _ => None,
+      })
+      .collect(),
+    _ => match as_assignment(stmt) {
+      Some((assign_expr, ident)) => vec![(ident, assign_expr.span)],
+      None => vec![],
+    },
+  }
+}
+
+impl<'c, 'm> Visit for NoDeadStoreInLoopVisitor<'c, 'm> {
+  noop_visit_type!();
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, parent: &dyn Node) {
+    self.check_loop_body(&for_stmt.body);
+    swc_ecmascript::visit::visit_for_stmt(self, for_stmt, parent);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, parent: &dyn Node) {
+    self.check_loop_body(&for_in_stmt.body);
+    swc_ecmascript::visit::visit_for_in_stmt(self, for_in_stmt, parent);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, parent: &dyn Node) {
+    self.check_loop_body(&for_of_stmt.body);
+    swc_ecmascript::visit::visit_for_of_stmt(self, for_of_stmt, parent);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, parent: &dyn Node) {
+    self.check_loop_body(&while_stmt.body);
+    swc_ecmascript::visit::visit_while_stmt(self, while_stmt, parent);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    parent: &dyn Node,
+  ) {
+    self.check_loop_body(&do_while_stmt.body);
+    swc_ecmascript::visit::visit_do_while_stmt(self, do_while_stmt, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::LinterBuilder;
+  use crate::rules::no_useless_assignment::NoUselessAssignment;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_dead_store_in_loop_valid() {
+    assert_lint_ok! {
+      NoDeadStoreInLoop,
+      "for (const a of b) { let x = compute(); use(x); x = other(); use(x); }",
+      "for (const a of b) { let x = compute(); x = other(x); use(x); }",
+      "for (const a of b) { let x = compute(); x = other(); use(x); }",
+      "for (const a of b) { let x = compute(); if (c) { x = other(); } use(x); }",
+      "for (const a of b) { let x = compute(), y = x; x = other(); use(x, y); }",
+      "let x; while (c) { x = compute(); if (d) { break; } x = other(); use(x); }",
+      "let x; while (x) { x = compute(); continue; x = other(); }",
+      "function f() { let x; while (c) { x = compute(); if (d) { return; } x = other(); use(x); } }",
+      "let x; const f = () => x; while (c) { x = compute(); f(); x = other(); }",
+      "while (c) { x = compute(); x = other(); use(x); }",
+      "while (c) { const x = compute(); use(x); }",
+      "for (;;) x = compute();",
+      "let x; while (c) { x = compute(); } use(x);",
+      "let x; while (c) { x += 1; x = other(); use(x); }",
+    };
+  }
+
+  #[test]
+  fn no_dead_store_in_loop_invalid() {
+    assert_lint_err! {
+      NoDeadStoreInLoop,
+      "let x; for (const a of b) { x = compute(); x = other(); use(x); }": [
+        {
+          col: 28,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "let x; while (c) { x = compute(); x = other(); use(x); }": [
+        {
+          col: 19,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "function f(x) { do { x = compute(); g(); x = other(); } while (x); }": [
+        {
+          col: 21,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "for (let i = 0; i < n; i++) { var x = 1; var y = 2; x = 3; y = 4; use(x, y); }": [
+        {
+          col: 34,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        },
+        {
+          col: 45,
+          message: "The value assigned to `y` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      r#"
+let x;
+for (const a in b) {
+  x = compute();
+  x = other();
+  x = last();
+  use(x);
+}
+      "#: [
+        {
+          line: 4,
+          col: 2,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        },
+        {
+          line: 5,
+          col: 2,
+          message: "The value assigned to `x` is overwritten before it is read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+    };
+  }
+  #[test]
+  fn no_dead_store_in_loop_no_duplicates() {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDeadStoreInLoop::new(), NoUselessAssignment::new()])
+      .build();
+    let diagnostics = linter
+      .lint(
+        "deno_lint_test.ts".to_string(),
+        "for (const a of b) { let x = compute(); x = other(); use(x); }"
+          .to_string(),
+      )
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "no-useless-assignment");
+  }
+}
@@ -29,7 +29,30 @@ impl LintRule for NoDebugger {
     let mut visitor = NoDebuggerVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `debugger` statement.
+
+`debugger` pauses the execution when a debugger is attached, which is useful
+while developing but shouldn't be left in code which is shipped.
+
+### Invalid:
+```typescript
+function isLongString(x: string) {
+  debugger;
+  return x.length > 100;
+}
+```
+
+### Valid:
+```typescript
+function isLongString(x: string) {
+  return x.length > 100;
+}
+```"#
+  }
 }
+
 struct NoDebuggerVisitor<'c> {
   context: &'c mut Context,
 }
@@ -61,6 +84,55 @@ mod tests {
   use super::*;
   use crate::test_util::*;
 
+  #[test]
+  fn no_debugger_valid() {
+    assert_lint_ok! {
+      NoDebugger,
+      "function foo() { return 1; }",
+      r#"const debuggerStmt = "debugger";"#,
+      "const debugger_ = () => {};",
+    };
+  }
+
+  #[test]
+  fn no_debugger_invalid() {
+    assert_lint_err! {
+      NoDebugger,
+      "debugger;": [
+        {
+          col: 0,
+          message: "`debugger` statement is not allowed",
+        }
+      ],
+      "const foo = () => { debugger; };": [
+        {
+          col: 20,
+          message: "`debugger` statement is not allowed",
+        }
+      ],
+      // Columns are in UTF-16 code units, the emoji counts as two of them.
+      r#"const s = "😀"; debugger;"#: [
+        {
+          col: 16,
+          message: "`debugger` statement is not allowed",
+        }
+      ],
+      "/* é */ debugger;": [
+        {
+          col: 8,
+          message: "`debugger` statement is not allowed",
+        }
+      ],
+      "// 😀😀\nconst s = \"é😀\"; debugger;": [
+        {
+          line: 2,
+          col: 17,
+          message: "`debugger` statement is not allowed",
+        }
+      ],
+    };
+  }
+
   #[test]
   fn no_debugger_test() {
     assert_lint_err::<NoDebugger>(
@@ -0,0 +1,142 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::static_property_name;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper, Module};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoDenoExit;
+
+impl LintRule for NoDenoExit {
+  fn new() -> Box<Self> {
+    Box::new(NoDenoExit)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-deno-exit"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoDenoExitVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calls to `Deno.exit()`.
+
+Exiting the process from library code takes the decision away from the caller,
+who can neither recover from the error nor clean up. Throw an error instead and
+let the entry point of the program decide whether to exit.
+
+### Invalid:
+```typescript
+if (!config) {
+  console.error("missing config");
+  Deno.exit(1);
+}
+```
+
+### Valid:
+```typescript
+if (!config) {
+  throw new Error("missing config");
+}
+```"#
+  }
+}
+
+struct NoDenoExitVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDenoExitVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn is_deno_exit(&self, callee: &Expr) -> bool {
+    let member_expr = match callee {
+      Expr::Member(member_expr) => member_expr,
+      _ => return false,
+    };
+    if static_property_name(member_expr) != Some("exit") {
+      return false;
+    }
+
+    match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => match &**obj {
+        Expr::Ident(ident) => {
+          ident.sym == *"Deno" && self.context.is_global_ident(ident)
+        }
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    }
+  }
+}
+
+impl<'c> VisitAll for NoDenoExitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if self.is_deno_exit(callee) {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          "no-deno-exit",
+          "`Deno.exit()` should not be called",
+          "Throw an error instead, so that callers can handle it",
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_deno_exit_valid() {
+    assert_lint_ok! {
+      NoDenoExit,
+      "const Deno = { exit() {} }; Deno.exit(1);",
+      "function f(Deno) { Deno.exit(1); }",
+      "process.exit(1);",
+      "Deno.exitCode = 1;",
+      "foo.Deno.exit(1);",
+      "throw new Error('failed');",
+    };
+  }
+
+  #[test]
+  fn no_deno_exit_invalid() {
+    assert_lint_err! {
+      NoDenoExit,
+      "Deno.exit(1);": [
+        {
+          col: 0,
+          message: "`Deno.exit()` should not be called",
+          hint: "Throw an error instead, so that callers can handle it",
+        }
+      ],
+      "if (err) { Deno.exit(); }": [
+        {
+          col: 11,
+          message: "`Deno.exit()` should not be called",
+          hint: "Throw an error instead, so that callers can handle it",
+        }
+      ],
+      "function f() { Deno['exit'](2); }": [
+        {
+          col: 15,
+          message: "`Deno.exit()` should not be called",
+          hint: "Throw an error instead, so that callers can handle it",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,200 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::static_property_name;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, MemberExpr, Module};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+/// Deprecated members of the `Deno` namespace, along with the hint describing
+/// their replacement.
+static DEPRECATED_APIS: &[(&str, &str)] = &[
+  (
+    "Buffer",
+    "Use `Buffer` from `https://deno.land/std/io/buffer.ts` instead",
+  ),
+  (
+    "copy",
+    "Use `copy` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "customInspect",
+    "Use `Symbol.for(\"Deno.customInspect\")` instead",
+  ),
+  (
+    "iter",
+    "Use `iterateReader` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "iterSync",
+    "Use `iterateReaderSync` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "readAll",
+    "Use `readAll` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "readAllSync",
+    "Use `readAllSync` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "writeAll",
+    "Use `writeAll` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+  (
+    "writeAllSync",
+    "Use `writeAllSync` from `https://deno.land/std/streams/conversion.ts` instead",
+  ),
+];
+
+pub struct NoDeprecatedDenoApi;
+
+impl LintRule for NoDeprecatedDenoApi {
+  fn new() -> Box<Self> {
+    Box::new(NoDeprecatedDenoApi)
+  }
+
+  fn tags(&self) -> &[&'static str] {
+    &["recommended"]
+  }
+
+  fn code(&self) -> &'static str {
+    "no-deprecated-deno-api"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoDeprecatedDenoApiVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Warns the usage of the deprecated `Deno` APIs.
+
+Some members of the `Deno` namespace are deprecated and will be removed in the
+future. Most of them have been moved to the standard library.
+
+The deprecated APIs are `Deno.Buffer`, `Deno.copy`, `Deno.customInspect`,
+`Deno.iter`, `Deno.iterSync`, `Deno.readAll`, `Deno.readAllSync`,
+`Deno.writeAll` and `Deno.writeAllSync`.
+
+### Invalid:
+```typescript
+const buf = new Deno.Buffer();
+const data = await Deno.readAll(reader);
+```
+
+### Valid:
+```typescript
+import { Buffer } from "https://deno.land/std/io/buffer.ts";
+import { readAll } from "https://deno.land/std/streams/conversion.ts";
+const buf = new Buffer();
+const data = await readAll(reader);
+```"#
+  }
+}
+
+struct NoDeprecatedDenoApiVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDeprecatedDenoApiVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn is_global_deno(&self, obj: &ExprOrSuper) -> bool {
+    match obj {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => {
+          ident.sym == *"Deno" && self.context.is_global_ident(ident)
+        }
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    }
+  }
+}
+
+impl<'c> VisitAll for NoDeprecatedDenoApiVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    let prop = match static_property_name(member_expr) {
+      Some(prop) => prop,
+      None => return,
+    };
+    let hint = match DEPRECATED_APIS.iter().find(|(name, _)| prop == *name) {
+      Some((_, hint)) => hint,
+      None => return,
+    };
+    if !self.is_global_deno(&member_expr.obj) {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      member_expr.span,
+      "no-deprecated-deno-api",
+      format!("`Deno.{}` is deprecated", prop),
+      *hint,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_deprecated_deno_api_valid() {
+    assert_lint_ok! {
+      NoDeprecatedDenoApi,
+      "await Deno.readFile('./a.txt');",
+      "Deno.readTextFileSync('./a.txt');",
+      "const Deno = { readAll() {} }; Deno.readAll();",
+      "function f(Deno) { return Deno.copy(a, b); }",
+      "foo.Deno.readAll(r);",
+      "readAll(r);",
+    };
+  }
+
+  #[test]
+  fn no_deprecated_deno_api_invalid() {
+    assert_lint_err! {
+      NoDeprecatedDenoApi,
+      "await Deno.readAll(x);": [
+        {
+          col: 6,
+          message: "`Deno.readAll` is deprecated",
+          hint: "Use `readAll` from `https://deno.land/std/streams/conversion.ts` instead",
+        }
+      ],
+      "const buf = new Deno.Buffer();": [
+        {
+          col: 16,
+          message: "`Deno.Buffer` is deprecated",
+          hint: "Use `Buffer` from `https://deno.land/std/io/buffer.ts` instead",
+        }
+      ],
+      "for await (const chunk of Deno.iter(r)) {}": [
+        {
+          col: 26,
+          message: "`Deno.iter` is deprecated",
+          hint: "Use `iterateReader` from `https://deno.land/std/streams/conversion.ts` instead",
+        }
+      ],
+      "Deno['copy'](r, w);": [
+        {
+          col: 0,
+          message: "`Deno.copy` is deprecated",
+          hint: "Use `copy` from `https://deno.land/std/streams/conversion.ts` instead",
+        }
+      ],
+    };
+  }
+}
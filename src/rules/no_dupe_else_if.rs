@@ -27,6 +27,28 @@ impl LintRule for NoDupeElseIf {
     let mut visitor = NoDupeElseIfVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the same condition twice in an if-else-if chain.
+
+Only the first branch whose condition is truthy runs, so a condition that
+repeats an earlier one can never execute. The same goes for a condition that is
+covered by an earlier one, like `a && b` after `a`, or `a` after `a || b`.
+
+### Invalid:
+```typescript
+if (a) {} else if (a) {}
+if (a || b) {} else if (a) {}
+if (a) {} else if (a && b) {}
+```
+
+### Valid:
+```typescript
+if (a) {} else if (b) {}
+if (a && b) {} else if (a) {}
+if (a) {} else if (a || b) {}
+```"#
+  }
 }
 
 /// A visitor to check the `no-dupe-else-if` rule.
@@ -93,8 +115,8 @@ impl<'c> Visit for NoDupeElseIfVisitor<'c> {
               .any(|or_operands| or_operands.is_empty())
             {
               self
-              .context
-              .add_diagnostic(span, "no-dupe-else-if", "This branch can never execute. Its condition is a duplicate or covered by previous conditions in the if-else-if chain.");
+                .context
+                .add_diagnostic_with_hint(span, "no-dupe-else-if", "This branch can never execute. Its condition is a duplicate or covered by previous conditions in the if-else-if chain.", "Remove or rework the condition so that it is reachable");
               break;
             }
           }
@@ -276,6 +298,27 @@ if (a) {
     };
   }
 
+  #[test]
+  fn no_dupe_else_if_message() {
+    assert_lint_err! {
+      NoDupeElseIf,
+      "if (a) {} else if (a) {}": [
+        {
+          col: 19,
+          message: "This branch can never execute. Its condition is a duplicate or covered by previous conditions in the if-else-if chain.",
+          hint: "Remove or rework the condition so that it is reachable",
+        }
+      ],
+      "if (a && b) {} else if (c) {} else if (b && a) {}": [
+        {
+          col: 39,
+          message: "This branch can never execute. Its condition is a duplicate or covered by previous conditions in the if-else-if chain.",
+          hint: "Remove or rework the condition so that it is reachable",
+        }
+      ],
+    };
+  }
+
   #[test]
   fn no_dupe_else_if_invalid() {
     assert_lint_err::<NoDupeElseIf>(
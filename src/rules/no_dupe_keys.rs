@@ -4,7 +4,7 @@ use super::LintRule;
 use crate::swc_util::Key;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use swc_common::Span;
+use swc_common::{Span, Spanned};
 use swc_ecmascript::ast::{
   GetterProp, KeyValueProp, MethodProp, Module, ObjectLit, Prop, PropOrSpread,
   SetterProp,
@@ -61,6 +61,12 @@ var foo = {
   bar: "baz",
   quxx: "qux"
 };
+```
+```typescript
+var foo = {
+  get bar() { return this._bar; },
+  set bar(value) { this._bar = value; }
+};
 ```"#
   }
 }
@@ -84,7 +90,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
 
   fn check_key<S: Into<String>>(
     &mut self,
-    obj_span: Span,
+    span: Span,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
   ) {
@@ -93,7 +99,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
 
       match keys.entry(key) {
         Entry::Occupied(occupied) => {
-          self.report(obj_span, occupied.key());
+          self.report(span, occupied.key());
         }
         Entry::Vacant(vacant) => {
           vacant.insert(PropertyInfo::default());
@@ -104,7 +110,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
 
   fn check_getter<S: Into<String>>(
     &mut self,
-    obj_span: Span,
+    span: Span,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
   ) {
@@ -116,7 +122,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
           if occupied.get().setter_only() {
             occupied.get_mut().getter = true;
           } else {
-            self.report(obj_span, occupied.key());
+            self.report(span, occupied.key());
           }
         }
         Entry::Vacant(vacant) => {
@@ -131,7 +137,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
 
   fn check_setter<S: Into<String>>(
     &mut self,
-    obj_span: Span,
+    span: Span,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
   ) {
@@ -143,7 +149,7 @@ impl<'c> NoDupeKeysVisitor<'c> {
           if occupied.get().getter_only() {
             occupied.get_mut().setter = true;
           } else {
-            self.report(obj_span, occupied.key());
+            self.report(span, occupied.key());
           }
         }
         Entry::Vacant(vacant) => {
@@ -177,11 +183,11 @@ impl<'c> VisitAll for NoDupeKeysVisitor<'c> {
   noop_visit_type!();
 
   fn visit_object_lit(&mut self, obj_lit: &ObjectLit, _parent: &dyn Node) {
-    let span = obj_lit.span;
     let mut keys: HashMap<String, PropertyInfo> = HashMap::new();
 
     for prop in &obj_lit.props {
       if let PropOrSpread::Prop(prop) = prop {
+        let span = prop.span();
         match &**prop {
           Prop::Shorthand(ident) => {
             self.check_key(span, Some(ident.as_ref()), &mut keys);
@@ -230,6 +236,8 @@ mod tests {
       r#"var x = { a: b, [a]: b };"#,
       r#"var x = { a: b, ...c }"#,
       r#"var x = { get a() {}, set a (value) {} };"#,
+      r#"var x = { set a(value) {}, get a() {} };"#,
+      r#"var x = { 1: a, "2": b, [3]: c };"#,
       r#"var x = ({ null: 1, [/(?<zero>0)/]: 2 })"#,
       r#"var {a, a} = obj"#,
       r#"var x = { 012: 1, 12: 2 };"#,
@@ -250,37 +258,37 @@ let x = {
   fn no_dupe_keys_invalid() {
     assert_lint_err::<NoDupeKeys>(
       r#"var foo = { bar: "baz", bar: "qux" };"#,
-      10,
+      24,
     );
     assert_lint_err_n::<NoDupeKeys>(
       r#"var foo = { bar: "baz", bar: "qux", quux: "boom", quux: "bang" };"#,
-      vec![10, 10],
+      vec![24, 50],
     );
     assert_lint_err::<NoDupeKeys>(
       r#"var foo = { bar: "baz", "bar": "qux" };"#,
-      10,
+      24,
     );
-    assert_lint_err::<NoDupeKeys>(r#"var foo = { 1: "baz", 0x1: "qux" };"#, 10);
+    assert_lint_err::<NoDupeKeys>(r#"var foo = { 1: "baz", 0x1: "qux" };"#, 22);
     assert_lint_err::<NoDupeKeys>(
       r#"var foo = { bar: "baz", get bar() {} };"#,
-      10,
+      24,
     );
     assert_lint_err::<NoDupeKeys>(
       r#"var foo = { bar: "baz", set bar() {} };"#,
-      10,
+      24,
     );
-    assert_lint_err::<NoDupeKeys>(r#"var x = { a: b, ['a']: b };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { '': 1, '': 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { '': 1, [``]: 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { 012: 1, 10: 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { 0b1: 1, 1: 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { 0o1: 1, 1: 2 };"#, 8);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { a: b, ['a']: b };"#, 16);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { '': 1, '': 2 };"#, 17);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { '': 1, [``]: 2 };"#, 17);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { 012: 1, 10: 2 };"#, 18);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { 0b1: 1, 1: 2 };"#, 18);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { 0o1: 1, 1: 2 };"#, 18);
     // TODO(magurotuna): this leads to panic due to swc error
     // It seems like tsc v4.0.2 cannot handle this either
     // playground: https://www.typescriptlang.org/play?target=99&ts=4.0.2#code/MYewdgzgLgBCBGArGBeGBvAUDGBGMAXDACwBMANJgL4DcQA
     // assert_lint_err::<NoDupeKeys>(r#"var x = { 1n: 1, 1: 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { 1_0: 1, 10: 2 };"#, 8);
-    assert_lint_err::<NoDupeKeys>(r#"var x = { "z": 1, z: 2 };"#, 8);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { 1_0: 1, 10: 2 };"#, 18);
+    assert_lint_err::<NoDupeKeys>(r#"var x = { "z": 1, z: 2 };"#, 18);
     assert_lint_err_on_line::<NoDupeKeys>(
       r#"
 var foo = {
@@ -288,16 +296,16 @@ var foo = {
   bar: 1,
 }
 "#,
+      4,
       2,
-      10,
     );
     assert_lint_err::<NoDupeKeys>(
       r#"var x = { a: 1, b: { a: 2 }, get b() {} };"#,
-      8,
+      29,
     );
     assert_lint_err::<NoDupeKeys>(
       r#"var x = ({ '/(?<zero>0)/': 1, [/(?<zero>0)/]: 2 })"#,
-      9,
+      30,
     );
 
     // nested
@@ -310,8 +318,43 @@ let x = {
   },
 };
 "#,
-      3,
-      7,
+      5,
+      4,
     );
   }
+
+  #[test]
+  fn no_dupe_keys_accessors_and_numeric_keys() {
+    assert_lint_err! {
+      NoDupeKeys,
+      r#"var x = { get a() {}, get a() {} };"#: [
+        {
+          col: 22,
+          message: "Duplicate key 'a'",
+        }
+      ],
+      r#"var x = { get a() {}, set a(v) {}, get a() {} };"#: [
+        {
+          col: 35,
+          message: "Duplicate key 'a'",
+        }
+      ],
+      r#"var x = { 1: x, "1": y };"#: [
+        {
+          col: 16,
+          message: "Duplicate key '1'",
+        }
+      ],
+      r#"var x = { 1.0: x, [1]: y, "1": z };"#: [
+        {
+          col: 18,
+          message: "Duplicate key '1'",
+        },
+        {
+          col: 26,
+          message: "Duplicate key '1'",
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,194 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_atoms::js_word;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, Module,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoDynamicImportNonliteralOptions {
+  /// Allow template literals with interpolated expressions, as in
+  /// `` import(`./locales/${lang}.ts`) ``.
+  pub allow_template_literals: bool,
+}
+
+pub struct NoDynamicImportNonliteral {
+  options: NoDynamicImportNonliteralOptions,
+}
+
+impl NoDynamicImportNonliteral {
+  pub fn with_options(options: NoDynamicImportNonliteralOptions) -> Box<Self> {
+    Box::new(NoDynamicImportNonliteral { options })
+  }
+}
+
+impl LintRule for NoDynamicImportNonliteral {
+  fn new() -> Box<Self> {
+    Self::with_options(NoDynamicImportNonliteralOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-dynamic-import-nonliteral"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor =
+      NoDynamicImportNonliteralVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows dynamic imports whose specifier is not a literal.
+
+When the specifier of `import()` is computed at runtime, it's impossible to tell
+which modules may be loaded without running the code, which makes it hard to
+audit and can allow loading arbitrary code.
+
+String literals and template literals without expressions are allowed. The
+`allowTemplateLiterals` option (default `false`) also allows template literals
+with expressions.
+
+### Invalid:
+```typescript
+const mod = await import(name);
+const locale = await import(`./locales/${lang}.ts`);
+```
+
+### Valid:
+```typescript
+const mod = await import("./mod.ts");
+const other = await import(`./other.ts`);
+```"#
+  }
+}
+
+struct NoDynamicImportNonliteralVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoDynamicImportNonliteralOptions,
+}
+
+impl<'c, 'o> NoDynamicImportNonliteralVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoDynamicImportNonliteralOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn is_allowed_specifier(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Lit(Lit::Str(_)) => true,
+      Expr::Tpl(tpl) => {
+        tpl.exprs.is_empty() || self.options.allow_template_literals
+      }
+      Expr::Paren(paren_expr) => self.is_allowed_specifier(&paren_expr.expr),
+      _ => false,
+    }
+  }
+}
+
+impl<'c, 'o> VisitAll for NoDynamicImportNonliteralVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Ident(ident) => ident.sym == js_word!("import"),
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+
+    let is_allowed = match call_expr.args.first() {
+      Some(ExprOrSpread { spread: None, expr }) => {
+        self.is_allowed_specifier(expr)
+      }
+      _ => false,
+    };
+    if !is_allowed {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        "no-dynamic-import-nonliteral",
+        "Dynamic import specifier should be a literal",
+        "Import a string literal so that the loaded modules can be audited statically",
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_dynamic_import_nonliteral_valid() {
+    assert_lint_ok! {
+      NoDynamicImportNonliteral,
+      r#"import("./a.ts");"#,
+      "import('./a.ts');",
+      "import(`./a.ts`);",
+      r#"await import(("./a.ts"));"#,
+      "foo(x);",
+      r#"import { a } from "./a.ts";"#,
+    };
+  }
+
+  #[test]
+  fn no_dynamic_import_nonliteral_invalid() {
+    assert_lint_err! {
+      NoDynamicImportNonliteral,
+      "import(x);": [
+        {
+          col: 0,
+          message: "Dynamic import specifier should be a literal",
+          hint: "Import a string literal so that the loaded modules can be audited statically",
+        }
+      ],
+      "const m = await import(`./locales/${lang}.ts`);": [
+        {
+          col: 16,
+          message: "Dynamic import specifier should be a literal",
+          hint: "Import a string literal so that the loaded modules can be audited statically",
+        }
+      ],
+      r#"import("./" + name);"#: [
+        {
+          col: 0,
+          message: "Dynamic import specifier should be a literal",
+          hint: "Import a string literal so that the loaded modules can be audited statically",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_dynamic_import_nonliteral_allow_template_literals() {
+    assert_lint_ok_with_options! {
+      NoDynamicImportNonliteral,
+      json!({ "allowTemplateLiterals": true }),
+      "import(`./locales/${lang}.ts`);",
+    };
+    assert_lint_err_with_options! {
+      NoDynamicImportNonliteral,
+      json!({ "allowTemplateLiterals": true }),
+      "import(x);": [
+        {
+          col: 0,
+          message: "Dynamic import specifier should be a literal",
+          hint: "Import a string literal so that the loaded modules can be audited statically",
+        }
+      ],
+    };
+  }
+}
@@ -1,16 +1,32 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use serde::Deserialize;
 use swc_ecmascript::ast::{
-  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Function, Module,
-  SwitchStmt,
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, Constructor, Function,
+  Module, SwitchStmt,
 };
 use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
 
-pub struct NoEmpty;
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoEmptyOptions {
+  /// Allows empty `catch` clauses.
+  pub allow_empty_catch: bool,
+}
+
+pub struct NoEmpty {
+  options: NoEmptyOptions,
+}
+
+impl NoEmpty {
+  pub fn with_options(options: NoEmptyOptions) -> Box<Self> {
+    Box::new(NoEmpty { options })
+  }
+}
 
 impl LintRule for NoEmpty {
   fn new() -> Box<Self> {
-    Box::new(NoEmpty)
+    Self::with_options(NoEmptyOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -22,7 +38,7 @@ impl LintRule for NoEmpty {
   }
 
   fn lint_module(&self, context: &mut Context, module: &Module) {
-    let mut visitor = NoEmptyVisitor::new(context);
+    let mut visitor = NoEmptyVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
   }
 
@@ -31,6 +47,8 @@ impl LintRule for NoEmpty {
 
 Empty block statements are legal but often represent that something was missed and can make code less readable. This rule ignores block statements that only contain comments. This rule also ignores empty constructors and function bodies (including arrow functions), which are covered by the `no-empty-function` rule.
 
+The `allowEmptyCatch` option (default `false`) allows empty `catch` clauses.
+
 ### Valid:
 ```typescript
 if (foo) {
@@ -82,17 +100,18 @@ try {
   }
 }
 
-struct NoEmptyVisitor<'c> {
+struct NoEmptyVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoEmptyOptions,
 }
 
-impl<'c> NoEmptyVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> NoEmptyVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoEmptyOptions) -> Self {
+    Self { context, options }
   }
 }
 
-impl<'c> Visit for NoEmptyVisitor<'c> {
+impl<'c, 'o> Visit for NoEmptyVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
@@ -119,6 +138,20 @@ impl<'c> Visit for NoEmptyVisitor<'c> {
     }
   }
 
+  fn visit_catch_clause(
+    &mut self,
+    catch_clause: &CatchClause,
+    _parent: &dyn Node,
+  ) {
+    if self.options.allow_empty_catch && catch_clause.body.stmts.is_empty() {
+      if let Some(param) = &catch_clause.param {
+        param.visit_with(catch_clause, self);
+      }
+      return;
+    }
+    catch_clause.visit_children_with(self);
+  }
+
   fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
     if block_stmt.stmts.is_empty() {
       if !block_stmt.contains_comments(&self.context) {
@@ -154,6 +187,7 @@ impl ContainsComments for BlockStmt {
     context
       .leading_comments
       .values()
+      .chain(context.trailing_comments.values())
       .flatten()
       .any(|comment| self.span.contains(comment.span))
   }
@@ -163,6 +197,7 @@ impl ContainsComments for BlockStmt {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn no_empty_valid() {
@@ -194,6 +229,10 @@ if (foo) {
 }
       "#,
       "const testFunction = (): void => {};",
+      "try { foo(); } catch (err) { /* ignore */ }",
+      "try { foo(); } catch {
+  // ignore
+}",
       r#"
       switch (foo) {
         case 1:
@@ -323,4 +362,37 @@ switch (
       14,
     );
   }
+
+  #[test]
+  fn no_empty_allow_empty_catch() {
+    assert_lint_ok_with_options! {
+      NoEmpty,
+      json!({ "allowEmptyCatch": true }),
+      "try { foo(); } catch (e) {}",
+      "try { foo(); } catch {}",
+    };
+    assert_lint_err_with_options! {
+      NoEmpty,
+      json!({ "allowEmptyCatch": true }),
+      "try {} catch (e) {}": [
+        {
+          col: 4,
+          message: "Empty block statement",
+        }
+      ],
+      "try { foo(); } catch (e) { if (e) {} }": [
+        {
+          col: 34,
+          message: "Empty block statement",
+        }
+      ],
+      "try { foo(); } catch (e) {} finally {}": [
+        {
+          col: 36,
+          message: "Empty block statement",
+        }
+      ],
+    };
+    assert_lint_err::<NoEmpty>("try { foo(); } catch {}", 21);
+  }
 }
@@ -31,6 +31,31 @@ impl LintRule for NoExAssign {
     let mut visitor = NoExAssignVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows reassigning the exception parameter of a `catch` clause.
+
+Once the exception is overwritten, there's no way to access the original error anymore.
+
+### Invalid:
+```typescript
+try {
+  someFunc();
+} catch (e) {
+  e = true;
+}
+```
+
+### Valid:
+```typescript
+try {
+  someFunc();
+} catch (e) {
+  const anotherVar = true;
+  e.handled = true;
+}
+```"#
+  }
 }
 
 struct NoExAssignVisitor<'c> {
@@ -46,22 +71,27 @@ impl<'c> NoExAssignVisitor<'c> {
 impl<'c> Visit for NoExAssignVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _node: &dyn Node) {
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, node: &dyn Node) {
     let ids = find_lhs_ids(&assign_expr.left);
 
+    // The scope analysis resolves the assigned identifiers, so parameters of
+    // nested `catch` clauses and other shadowing bindings are told apart.
     for id in ids {
       let var = self.context.scope.var(&id);
 
       if let Some(var) = var {
         if let BindingKind::CatchClause = var.kind() {
-          self.context.add_diagnostic(
+          self.context.add_diagnostic_with_hint(
             assign_expr.span,
             "no-ex-assign",
             "Reassigning exception parameter is not allowed",
+            "Use a different variable for the assigned value",
           );
         }
       }
     }
+
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, node);
   }
 }
 
@@ -80,6 +110,12 @@ try {} catch (ex) { something = 1; }
 try {} catch (ex) { return 1; }
 function foo() { try { } catch (e) { return false; } }
       "#,
+      "try {} catch (e) { e.foo = 1; }",
+      "try {} catch (e) { e[0] = 1; }",
+      "try {} catch (e) { let e2 = e; e2 = 1; }",
+      "try {} catch (e) { function foo() { let e; e = 1; } }",
+      "try {} catch (e) { (e2) => { e2 = 1; }; }",
+      "try {} catch (e) { } e = 1;",
     };
   }
 
@@ -95,5 +131,43 @@ try {} catch ({message}) { message = 1; }
       "#,
       vec![(2, 19), (3, 20), (4, 20), (5, 21), (6, 27)],
     );
+    assert_lint_err! {
+      NoExAssign,
+      "try {} catch (e) { a = e = 1; }": [
+        {
+          col: 23,
+          message: "Reassigning exception parameter is not allowed",
+          hint: "Use a different variable for the assigned value",
+        }
+      ],
+      "try {} catch (e) { try {} catch (e) { e = 1; } }": [
+        {
+          col: 38,
+          message: "Reassigning exception parameter is not allowed",
+          hint: "Use a different variable for the assigned value",
+        }
+      ],
+      "try {} catch (e) { try {} catch (e2) { e = 1; } }": [
+        {
+          col: 39,
+          message: "Reassigning exception parameter is not allowed",
+          hint: "Use a different variable for the assigned value",
+        }
+      ],
+      "try {} catch (e) { try {} catch (e) {} e = 1; }": [
+        {
+          col: 39,
+          message: "Reassigning exception parameter is not allowed",
+          hint: "Use a different variable for the assigned value",
+        }
+      ],
+      "try {} catch ([a, { b }]) { b = 1; }": [
+        {
+          col: 28,
+          message: "Reassigning exception parameter is not allowed",
+          hint: "Use a different variable for the assigned value",
+        }
+      ],
+    };
   }
 }
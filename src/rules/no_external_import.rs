@@ -0,0 +1,253 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_atoms::js_word;
+use swc_ecmascript::ast::{
+  CallExpr, ExportAll, Expr, ExprOrSpread, ExprOrSuper, ImportDecl, Lit,
+  Module, NamedExport, Str,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoExternalImportOptions {
+  /// Hosts which modules may be imported from, as in `"deno.land"`.
+  pub allow_list: Vec<String>,
+}
+
+pub struct NoExternalImport {
+  options: NoExternalImportOptions,
+}
+
+impl NoExternalImport {
+  pub fn with_options(options: NoExternalImportOptions) -> Box<Self> {
+    Box::new(NoExternalImport { options })
+  }
+}
+
+impl LintRule for NoExternalImport {
+  fn new() -> Box<Self> {
+    Self::with_options(NoExternalImportOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-external-import"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoExternalImportVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows importing modules from remote URLs.
+
+Deno can import modules directly from `http:` and `https:` URLs. Code loaded
+this way is not reviewed as part of the project, so a policy may require that
+remote modules are only loaded from trusted hosts, or vendored instead.
+
+Static imports, re-exports and dynamic imports with a literal specifier are
+checked. The `allowList` option lists the hosts which modules may be imported
+from.
+
+### Invalid:
+```typescript
+import { serve } from "https://deno.land/std/http/server.ts";
+export * from "http://example.com/mod.ts";
+const mod = await import("https://example.com/mod.ts");
+```
+
+### Valid:
+```typescript
+import { serve } from "./deps.ts";
+const mod = await import("./mod.ts");
+```"#
+  }
+}
+
+struct NoExternalImportVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoExternalImportOptions,
+}
+
+impl<'c, 'o> NoExternalImportVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoExternalImportOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_specifier(&mut self, specifier: &Str) {
+    let host = match remote_host(&specifier.value) {
+      Some(host) => host,
+      None => return,
+    };
+    if self
+      .options
+      .allow_list
+      .iter()
+      .any(|allowed| allowed.eq_ignore_ascii_case(host))
+    {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      specifier.span,
+      "no-external-import",
+      format!("Importing modules from `{}` is not allowed", host),
+      "Vendor the module locally, or add the host to the allow list",
+    );
+  }
+}
+
+/// Returns the host of `specifier` if it's an `http:` or `https:` URL.
+fn remote_host(specifier: &str) -> Option<&str> {
+  let scheme_end = specifier.find("://")?;
+  let scheme = &specifier[..scheme_end];
+  if !scheme.eq_ignore_ascii_case("http")
+    && !scheme.eq_ignore_ascii_case("https")
+  {
+    return None;
+  }
+
+  let rest = &specifier[scheme_end + 3..];
+  let authority = match rest.find(|c| c == '/' || c == '?' || c == '#') {
+    Some(end) => &rest[..end],
+    None => rest,
+  };
+  let host_and_port = match authority.rfind('@') {
+    Some(at) => &authority[at + 1..],
+    None => authority,
+  };
+  let host = match host_and_port.rfind(':') {
+    Some(colon) if !host_and_port.ends_with(']') => &host_and_port[..colon],
+    _ => host_and_port,
+  };
+  Some(host)
+}
+
+impl<'c, 'o> VisitAll for NoExternalImportVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_import_decl(
+    &mut self,
+    import_decl: &ImportDecl,
+    _parent: &dyn Node,
+  ) {
+    self.check_specifier(&import_decl.src);
+  }
+
+  fn visit_named_export(
+    &mut self,
+    named_export: &NamedExport,
+    _parent: &dyn Node,
+  ) {
+    if let Some(src) = &named_export.src {
+      self.check_specifier(src);
+    }
+  }
+
+  fn visit_export_all(&mut self, export_all: &ExportAll, _parent: &dyn Node) {
+    self.check_specifier(&export_all.src);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Ident(ident) => ident.sym == js_word!("import"),
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+
+    if let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first() {
+      if let Expr::Lit(Lit::Str(specifier)) = &**expr {
+        self.check_specifier(specifier);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_external_import_valid() {
+    assert_lint_ok! {
+      NoExternalImport,
+      r#"import x from "./a.ts";"#,
+      r#"import x from "../a.ts";"#,
+      r#"import x from "file:///a.ts";"#,
+      r#"export * from "./a.ts";"#,
+      r#"export { a } from "./a.ts";"#,
+      r#"const m = await import("./a.ts");"#,
+      "const m = await import(url);",
+    };
+  }
+
+  #[test]
+  fn no_external_import_invalid() {
+    assert_lint_err! {
+      NoExternalImport,
+      r#"import x from "https://deno.land/x/a.ts";"#: [
+        {
+          col: 14,
+          message: "Importing modules from `deno.land` is not allowed",
+          hint: "Vendor the module locally, or add the host to the allow list",
+        }
+      ],
+      r#"export * from "http://example.com:8080/a.ts";"#: [
+        {
+          col: 14,
+          message: "Importing modules from `example.com` is not allowed",
+          hint: "Vendor the module locally, or add the host to the allow list",
+        }
+      ],
+      r#"export { a } from "HTTPS://example.com";"#: [
+        {
+          col: 18,
+          message: "Importing modules from `example.com` is not allowed",
+          hint: "Vendor the module locally, or add the host to the allow list",
+        }
+      ],
+      r#"const m = await import("https://example.com/a.ts");"#: [
+        {
+          col: 23,
+          message: "Importing modules from `example.com` is not allowed",
+          hint: "Vendor the module locally, or add the host to the allow list",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_external_import_allow_list() {
+    assert_lint_ok_with_options! {
+      NoExternalImport,
+      json!({ "allowList": ["deno.land"] }),
+      r#"import x from "https://deno.land/x/a.ts";"#,
+      r#"import x from "https://user@DENO.land:443/x/a.ts";"#,
+    };
+    assert_lint_err_with_options! {
+      NoExternalImport,
+      json!({ "allowList": ["deno.land"] }),
+      r#"import x from "https://deno.land.example.com/a.ts";"#: [
+        {
+          col: 14,
+          message: "Importing modules from `deno.land.example.com` is not allowed",
+          hint: "Vendor the module locally, or add the host to the allow list",
+        }
+      ],
+    };
+  }
+}
@@ -1,16 +1,45 @@
 use super::LintRule;
+use crate::diagnostic::Suggestion;
 use crate::linter::Context;
-use swc_common::{comments::Comment, Spanned, DUMMY_SP};
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use swc_common::{comments::Comment, Span, Spanned, DUMMY_SP};
 use swc_ecmascript::{
   ast::*,
   visit::{noop_visit_type, Node, Visit, VisitWith},
 };
 
-pub struct NoFallthrough;
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoFallthroughOptions {
+  /// Matches the comments marking an intentional fallthrough, instead of the
+  /// default "falls through" and similar phrases.
+  #[serde(deserialize_with = "deserialize_regex")]
+  pub comment_pattern: Option<Regex>,
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Option<Regex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Option::<String>::deserialize(deserializer)?
+    .map(|pattern| Regex::new(&pattern).map_err(serde::de::Error::custom))
+    .transpose()
+}
+
+pub struct NoFallthrough {
+  options: NoFallthroughOptions,
+}
+
+impl NoFallthrough {
+  pub fn with_options(options: NoFallthroughOptions) -> Box<Self> {
+    Box::new(NoFallthrough { options })
+  }
+}
 
 impl LintRule for NoFallthrough {
   fn new() -> Box<Self> {
-    Box::new(NoFallthrough)
+    Self::with_options(NoFallthroughOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -26,16 +55,96 @@ impl LintRule for NoFallthrough {
     context: &mut Context,
     module: &swc_ecmascript::ast::Module,
   ) {
-    let mut visitor = NoFallthroughVisitor { context };
+    let mut visitor = NoFallthroughVisitor {
+      context,
+      options: &self.options,
+    };
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the implicit fallthrough of case statements.
+
+A case which doesn't end with `break`, `return`, `throw` or `continue` runs
+into the next one, which is easy to do by accident. An intentional fallthrough
+can be marked with a comment containing "falls through", "fall through" or
+"fallthrough".
+
+The `commentPattern` option (default unset) is a regular expression which
+replaces those phrases. It's matched against the comment text with the
+surrounding whitespace trimmed, as in `"^break omitted$"`.
+
+### Invalid:
+```typescript
+switch (foo) {
+  case 0:
+    a();
+  case 1:
+    b();
+}
+```
+
+### Valid:
+```typescript
+switch (foo) {
+  case 0:
+    a();
+    break;
+  case 1:
+    b();
+    // falls through
+  case 2:
+    c();
+}
+```"#
+  }
 }
 
-struct NoFallthroughVisitor<'c> {
+struct NoFallthroughVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoFallthroughOptions,
+}
+
+impl<'c, 'o> NoFallthroughVisitor<'c, 'o> {
+  fn allow_fall_through(&self, comments: &[Comment]) -> bool {
+    comments
+      .iter()
+      .any(|comment| match &self.options.comment_pattern {
+        Some(pattern) => pattern.is_match(comment.text.trim()),
+        None => {
+          let l = comment.text.to_ascii_lowercase();
+          l.contains("fallthrough")
+            || l.contains("falls through")
+            || l.contains("fall through")
+        }
+      })
+  }
+
+  /// Suggests to either end the case `prev_span` with `break;` or to mark its
+  /// fallthrough into the case `next_span` as intentional.
+  fn suggestions(&self, prev_span: Span, next_span: Span) -> Vec<Suggestion> {
+    let end = Span::new(prev_span.hi, prev_span.hi, prev_span.ctxt);
+    // A line comment would comment out the next case if it's on the same line.
+    let between = Span::new(prev_span.hi, next_span.lo, prev_span.ctxt);
+    let comment = match self.context.source_map.span_to_snippet(between) {
+      Ok(snippet) if snippet.contains('\n') => " // falls through",
+      _ => " /* falls through */",
+    };
+
+    vec![
+      Suggestion {
+        description: "Add `break;` to the end of the case".to_string(),
+        changes: vec![self.context.create_text_change(end, " break;")],
+      },
+      Suggestion {
+        description: "Mark the fallthrough as intentional".to_string(),
+        changes: vec![self.context.create_text_change(end, comment)],
+      },
+    ]
+  }
 }
 
-impl<'c> Visit for NoFallthroughVisitor<'c> {
+impl<'c, 'o> Visit for NoFallthroughVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_switch_cases(&mut self, cases: &[SwitchCase], parent: &dyn Node) {
@@ -49,15 +158,17 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
         let mut emit = true;
         if let Some(comments) = self.context.leading_comments.get(&case.span.lo)
         {
-          if allow_fall_through(&comments) {
+          if self.allow_fall_through(comments) {
             emit = false;
           }
         }
         if emit {
-          self.context.add_diagnostic(
+          let suggestions = self.suggestions(prev_span, case.span);
+          self.context.add_diagnostic_with_suggestions(
             prev_span,
             "no-fallthrough",
             "Fallthrough is not allowed",
+            suggestions,
           );
         }
       }
@@ -67,7 +178,7 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
       // Handle return / throw / break / continue
       for (idx, stmt) in case.cons.iter().enumerate() {
         let last = idx + 1 == case.cons.len();
-        let metadata = self.context.control_flow.meta(stmt.span().lo);
+        let metadata = self.context.control_flow().meta(stmt.span().lo);
         stops_exec |= metadata.map(|v| v.stops_execution()).unwrap_or(false);
         if stops_exec {
           should_emit_err = false;
@@ -77,7 +188,7 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
           if let Some(comments) =
             self.context.trailing_comments.get(&stmt.span().hi)
           {
-            if allow_fall_through(&comments) {
+            if self.allow_fall_through(comments) {
               should_emit_err = false;
               // User comment beats everything
               prev_span = case.span;
@@ -112,23 +223,22 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
   }
 }
 
-fn allow_fall_through(comments: &[Comment]) -> bool {
-  for comment in comments {
-    let l = comment.text.to_ascii_lowercase();
-    if l.contains("fallthrough")
-      || l.contains("falls through")
-      || l.contains("fall through")
-    {
-      return true;
-    }
-  }
-  false
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::diagnostic::LintDiagnostic;
+  use crate::linter::{apply_text_changes, LinterBuilder};
   use crate::test_util::*;
+  use serde_json::json;
+
+  fn lint(src: &str) -> Vec<LintDiagnostic> {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoFallthrough::new()])
+      .build();
+    linter
+      .lint("deno_lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint")
+  }
 
   #[test]
   fn no_fallthrough_valid() {
@@ -164,6 +274,9 @@ mod tests {
       "switch (foo) { case 0: try {} finally { break; } default: b(); }",
       "switch (foo) { case 0: try { throw 0; } catch (err) { break; } default: b(); }",
       "switch (foo) { case 0: do { throw 0; } while(a); default: b(); }",
+      "switch (foo) { case 0: outer: for (;;) { break outer; } break; case 1: b(); }",
+      "switch (foo) { case 0: for (;;) { inner: for (;;) { break inner; } } case 1: b(); }",
+      "switch (foo) { case 0: for (;;) { inner: while (a) { if (b) { break inner; } } } case 1: b(); }",
     };
   }
 
@@ -197,6 +310,10 @@ mod tests {
       "switch(foo) { case 0: while (a) { break; } default: b() }",
       14,
     );
+    assert_lint_err::<NoFallthrough>(
+      "switch(foo) { case 0: do { break; } while (a); default: b() }",
+      14,
+    );
     assert_lint_err::<NoFallthrough>(
       "switch(foo) { case 0:\n\n default: b() }",
       14,
@@ -210,14 +327,95 @@ mod tests {
       "switch(foo) { case 0: a(); /* falling through */ default: b() }",
       14,
     );
-  }
 
-  #[test]
-  #[ignore = "It ends with break statement"]
-  fn no_fallthrough_invalid_2() {
+    // A labeled break only exits the statement with that label.
     assert_lint_err::<NoFallthrough>(
-      "switch(foo) { case 0: do { break; } while (a); default: b() }",
-      47,
+      "switch(foo) { case 0: inner: for (;;) { break inner; } case 1: b() }",
+      14,
+    );
+    assert_lint_err::<NoFallthrough>(
+      "switch(foo) { case 0: inner: while (a) { if (b) { break inner; } } default: b() }",
+      14,
+    );
+    assert_lint_err::<NoFallthrough>(
+      "switch(foo) { case 0: inner: { a(); break inner; } case 1: b() }",
+      14,
     );
   }
+
+  #[test]
+  fn no_fallthrough_comment_pattern() {
+    assert_lint_ok_with_options! {
+      NoFallthrough,
+      json!({ "commentPattern": "^break omitted$" }),
+      "switch(foo) { case 0: a(); // break omitted\n case 1: b(); }",
+      "switch(foo) { case 0: a(); /* break omitted */ case 1: b(); }",
+    };
+    assert_lint_err_with_options! {
+      NoFallthrough,
+      json!({ "commentPattern": "^break omitted$" }),
+      "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }": [
+        {
+          col: 14,
+          message: "Fallthrough is not allowed",
+        }
+      ],
+      "switch(foo) { case 0: a(); /* break omitted here */ case 1: b(); }": [
+        {
+          col: 14,
+          message: "Fallthrough is not allowed",
+        }
+      ],
+    };
+
+    assert!(serde_json::from_str::<NoFallthroughOptions>(
+      r#"{ "commentPattern": "(" }"#
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn no_fallthrough_suggestions() {
+    let cases = [
+      (
+        "switch(foo) { case 0: a(); case 1: b(); }",
+        "switch(foo) { case 0: a(); break; case 1: b(); }",
+        "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }",
+      ),
+      (
+        "switch(foo) { case 0: a();\ncase 1: b(); }",
+        "switch(foo) { case 0: a(); break;\ncase 1: b(); }",
+        "switch(foo) { case 0: a(); // falls through\ncase 1: b(); }",
+      ),
+      (
+        "switch(foo) { case 0:\n // comment\n default: b() }",
+        "switch(foo) { case 0: break;\n // comment\n default: b() }",
+        "switch(foo) { case 0: // falls through\n // comment\n default: b() }",
+      ),
+      (
+        "switch(foo) { case 0: if (a) { break; } default: b() }",
+        "switch(foo) { case 0: if (a) { break; } break; default: b() }",
+        "switch(foo) { case 0: if (a) { break; } /* falls through */ default: b() }",
+      ),
+    ];
+
+    for (src, with_break, with_comment) in cases.iter() {
+      let diagnostics = lint(src);
+      assert_eq!(diagnostics.len(), 1);
+      assert!(!diagnostics[0].fixable);
+      let suggestions = &diagnostics[0].suggestions;
+      assert_eq!(suggestions.len(), 2);
+
+      let fixed = apply_text_changes(src, suggestions[0].changes.clone());
+      assert_eq!(fixed, *with_break);
+      let fixed = apply_text_changes(src, suggestions[1].changes.clone());
+      assert_eq!(fixed, *with_comment);
+
+      // Both suggestions result in valid source without the fallthrough.
+      parse(with_break);
+      assert_lint_ok::<NoFallthrough>(with_break);
+      parse(with_comment);
+      assert_lint_ok::<NoFallthrough>(with_comment);
+    }
+  }
 }
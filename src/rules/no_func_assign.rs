@@ -2,7 +2,10 @@
 use super::Context;
 use super::LintRule;
 use crate::{scopes::BindingKind, swc_util::find_lhs_ids};
-use swc_ecmascript::ast::AssignExpr;
+use swc_common::Span;
+use swc_ecmascript::ast::{AssignExpr, Expr, UpdateExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
@@ -30,6 +33,33 @@ impl LintRule for NoFuncAssign {
     let mut visitor = NoFuncAssignVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows reassigning a function declaration.
+
+Overwriting a function declaration is most likely a mistake, and makes the
+code harder to follow since the function no longer does what its declaration
+says.
+
+### Invalid:
+```typescript
+function foo() {}
+foo = bar;
+
+function baz() {}
+baz++;
+```
+
+### Valid:
+```typescript
+let foo = function () {};
+foo = bar;
+
+function baz(baz) {
+  baz = 1;
+}
+```"#
+  }
 }
 
 struct NoFuncAssignVisitor<'c> {
@@ -40,36 +70,70 @@ impl<'c> NoFuncAssignVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  fn check_id(&mut self, id: &Id, span: Span) {
+    // The scope analysis resolves the identifier, so parameters and variables
+    // shadowing the function in nested scopes are not reported.
+    if let Some(var) = self.context.scope.var(id) {
+      if let BindingKind::Function = var.kind() {
+        self.context.add_diagnostic_with_hint(
+          span,
+          "no-func-assign",
+          "Reassigning function declaration is not allowed",
+          "Use a `let` variable instead if the function needs to be replaced",
+        );
+      }
+    }
+  }
 }
 
 impl<'c> Visit for NoFuncAssignVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _node: &dyn Node) {
-    let ids = find_lhs_ids(&assign_expr.left);
-
-    for id in ids {
-      let var = self.context.scope.var(&id);
-      if let Some(var) = var {
-        if let BindingKind::Function = var.kind() {
-          self.context.add_diagnostic(
-            assign_expr.span,
-            "no-func-assign",
-            "Reassigning function declaration is not allowed",
-          );
-        }
-      }
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, node: &dyn Node) {
+    for id in find_lhs_ids(&assign_expr.left) {
+      self.check_id(&id, assign_expr.span);
     }
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, node);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, node: &dyn Node) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.check_id(&ident.to_id(), update_expr.span);
+    }
+    swc_ecmascript::visit::visit_update_expr(self, update_expr, node);
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::test_util::assert_lint_err_on_line;
+  use crate::test_util::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/no-func-assign.js
+  // MIT Licensed.
 
   #[test]
-  fn no_func_assign() {
+  fn no_func_assign_valid() {
+    assert_lint_ok! {
+      NoFuncAssign,
+      "function foo() { var foo = bar; }",
+      "function foo(foo) { foo = bar; }",
+      "function foo() { var foo; foo = bar; }",
+      "var foo = () => {}; foo = bar;",
+      "var foo = function() {}; foo = bar;",
+      "var foo = function() { foo = bar; };",
+      "function foo() {} function bar(foo) { foo = 1; foo++; }",
+      "function foo() {} function bar() { let foo; foo = 1; }",
+      "function foo() {} { const bar = (foo) => { foo = 1; }; }",
+      "function foo() {} foo.bar = 1;",
+      "import bar from 'bar'; function foo() { var foo = bar; }",
+    };
+  }
+
+  #[test]
+  fn no_func_assign_invalid() {
     assert_lint_err_on_line::<NoFuncAssign>(
       r#"
 const a = "a";
@@ -86,5 +150,69 @@ asdf = "foobar";
       11,
       0,
     );
+    assert_lint_err! {
+      NoFuncAssign,
+      "function foo() {}; foo = bar;": [
+        {
+          col: 19,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "function foo() { foo = bar; }": [
+        {
+          col: 17,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "foo = bar; function foo() { };": [
+        {
+          col: 0,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "[foo] = bar; function foo() { };": [
+        {
+          col: 0,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "({x: foo = 0} = bar); function foo() { };": [
+        {
+          col: 1,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "function foo() {} foo++; --foo;": [
+        {
+          col: 18,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        },
+        {
+          col: 25,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "function foo() {} bar = foo = 1;": [
+        {
+          col: 24,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+      "function foo() {} function bar() { foo = 1; }": [
+        {
+          col: 35,
+          message: "Reassigning function declaration is not allowed",
+          hint: "Use a `let` variable instead if the function needs to be replaced",
+        }
+      ],
+    };
   }
 }
@@ -1,6 +1,7 @@
 use super::LintRule;
 use crate::{globals::GLOBALS, linter::Context, swc_util::find_lhs_ids};
-use std::collections::HashSet;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use swc_common::Span;
 use swc_ecmascript::{
   ast::*,
@@ -11,11 +12,37 @@ use swc_ecmascript::{
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
-pub struct NoGlobalAssign;
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GlobalMode {
+  /// Assigning to the global is reported.
+  Readonly,
+  /// Assigning to the global is allowed.
+  Writable,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoGlobalAssignOptions {
+  /// Overrides for whether a global can be assigned to, as in
+  /// `{ "myGlobal": "readonly", "window": "writable" }`. Names that aren't
+  /// builtin globals can be added as well.
+  pub globals: HashMap<String, GlobalMode>,
+}
+
+pub struct NoGlobalAssign {
+  options: NoGlobalAssignOptions,
+}
+
+impl NoGlobalAssign {
+  pub fn with_options(options: NoGlobalAssignOptions) -> Box<Self> {
+    Box::new(NoGlobalAssign { options })
+  }
+}
 
 impl LintRule for NoGlobalAssign {
   fn new() -> Box<Self> {
-    Box::new(NoGlobalAssign)
+    Self::with_options(NoGlobalAssignOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -36,9 +63,37 @@ impl LintRule for NoGlobalAssign {
     };
     module.visit_with(module, &mut collector);
 
-    let mut visitor = NoGlobalAssignVisitor::new(context, collector.bindings);
+    let mut visitor =
+      NoGlobalAssignVisitor::new(context, &self.options, collector.bindings);
     module.visit_with(module, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assignment to builtin globals.
+
+Builtin globals like `undefined`, `NaN` or `window` are read-only, or are
+relied upon by other code, so reassigning them is likely a mistake. Top level
+bindings shadowing a global can still be assigned to.
+
+### Options:
+- `globals`: a map from names to `"readonly"` or `"writable"`, to report
+  assignments to other globals or to allow assigning to a builtin one.
+
+### Invalid:
+```typescript
+undefined = 1;
+NaN = 2;
+window = {};
+```
+
+### Valid:
+```typescript
+let window;
+window = {};
+
+a = 1;
+```"#
+  }
 }
 
 struct Collector {
@@ -94,16 +149,25 @@ impl Visit for Collector {
   fn visit_expr(&mut self, _: &Expr, _: &dyn Node) {}
 }
 
-struct NoGlobalAssignVisitor<'c> {
+struct NoGlobalAssignVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoGlobalAssignOptions,
   /// This hashset only contains top level bindings, so using HashSet<JsWord>
   /// also can be an option.
   bindings: HashSet<Id>,
 }
 
-impl<'c> NoGlobalAssignVisitor<'c> {
-  fn new(context: &'c mut Context, bindings: HashSet<Id>) -> Self {
-    Self { context, bindings }
+impl<'c, 'o> NoGlobalAssignVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoGlobalAssignOptions,
+    bindings: HashSet<Id>,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      bindings,
+    }
   }
 
   fn check(&mut self, span: Span, id: Id) {
@@ -116,20 +180,25 @@ impl<'c> NoGlobalAssignVisitor<'c> {
       return;
     }
 
-    // We only care about globals.
-    if !GLOBALS.contains(&&*id.0) {
+    // We only care about globals, unless they are configured otherwise.
+    let is_readonly = match self.options.globals.get(&*id.0) {
+      Some(mode) => *mode == GlobalMode::Readonly,
+      None => GLOBALS.contains(&&*id.0),
+    };
+    if !is_readonly {
       return;
     }
 
-    self.context.add_diagnostic(
+    self.context.add_diagnostic_with_hint(
       span,
       "no-global-assign",
       "Assignment to global is not allowed",
+      "Remove the assignment to the global variable",
     );
   }
 }
 
-impl<'c> Visit for NoGlobalAssignVisitor<'c> {
+impl<'c, 'o> Visit for NoGlobalAssignVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_assign_expr(&mut self, e: &AssignExpr, _: &dyn Node) {
@@ -138,6 +207,8 @@ impl<'c> Visit for NoGlobalAssignVisitor<'c> {
     for ident in idents {
       self.check(ident.span, ident.to_id());
     }
+
+    e.right.visit_with(e, self);
   }
 
   fn visit_update_expr(&mut self, e: &UpdateExpr, _: &dyn Node) {
@@ -153,6 +224,7 @@ impl<'c> Visit for NoGlobalAssignVisitor<'c> {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn no_global_assign_valid() {
@@ -162,6 +234,10 @@ mod tests {
       "var string;",
       "top = 0;",
       "require = 0;",
+      "let window; window = 1;",
+      "function undefined() {} undefined = 1;",
+      "function foo(NaN) { NaN = 1; }",
+      "window.foo = 1;",
     };
   }
 
@@ -176,5 +252,63 @@ mod tests {
       vec![2, 14],
     );
     assert_lint_err::<NoGlobalAssign>("Array = 1;", 0);
+
+    assert_lint_err! {
+      NoGlobalAssign,
+      "undefined = 1;": [
+        {
+          col: 0,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        }
+      ],
+      "NaN = Infinity = 1;": [
+        {
+          col: 0,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        },
+        {
+          col: 6,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        }
+      ],
+      "const f = () => { window = {}; };": [
+        {
+          col: 18,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_global_assign_globals_option() {
+    assert_lint_ok_with_options! {
+      NoGlobalAssign,
+      json!({ "globals": { "window": "writable", "myGlobal": "writable" } }),
+      "window = {};",
+      "myGlobal = 1;",
+    };
+    assert_lint_err_with_options! {
+      NoGlobalAssign,
+      json!({ "globals": { "myGlobal": "readonly" } }),
+      "myGlobal = 1;": [
+        {
+          col: 0,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        }
+      ],
+      "let myGlobal; myGlobal = 1; undefined = 1;": [
+        {
+          col: 28,
+          message: "Assignment to global is not allowed",
+          hint: "Remove the assignment to the global variable",
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,460 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, Tpl, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoImplicitCoercionOptions {
+  /// Reports coercions to boolean, as in `!!x` or `~arr.indexOf(x)`.
+  pub boolean: bool,
+  /// Reports coercions to number, as in `+x` or `1 * x`.
+  pub number: bool,
+  /// Reports coercions to string, as in `"" + x` or `` `${x}` ``.
+  pub string: bool,
+  /// Operators which are allowed, any of `"!!"`, `"~"`, `"+"`, `"*"` and
+  /// `` "``" `` for template literals.
+  pub allow: Vec<String>,
+}
+
+impl Default for NoImplicitCoercionOptions {
+  fn default() -> Self {
+    NoImplicitCoercionOptions {
+      boolean: true,
+      number: true,
+      string: true,
+      allow: vec![],
+    }
+  }
+}
+
+pub struct NoImplicitCoercion {
+  options: NoImplicitCoercionOptions,
+}
+
+impl NoImplicitCoercion {
+  pub fn with_options(options: NoImplicitCoercionOptions) -> Box<Self> {
+    Box::new(NoImplicitCoercion { options })
+  }
+}
+
+impl LintRule for NoImplicitCoercion {
+  fn new() -> Box<Self> {
+    Self::with_options(NoImplicitCoercionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-implicit-coercion"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoImplicitCoercionVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows shorthand type conversions.
+
+Shorthands like `!!x` or `+x` convert a value to another type tersely, but are
+harder to read than calling `Boolean`, `Number` or `String` explicitly.
+
+### Options:
+- `boolean`: whether to report coercions to boolean, `true` by default.
+- `number`: whether to report coercions to number, `true` by default.
+- `string`: whether to report coercions to string, `true` by default.
+- `allow`: a list of operators to allow, out of `"!!"`, `"~"`, `"+"`, `"*"`
+  and `` "``" `` for template literals.
+
+### Invalid:
+```typescript
+const b = !!foo;
+const found = ~foo.indexOf(bar);
+const n = +foo;
+const m = 1 * foo;
+const s = "" + foo;
+const t = `${foo}`;
+```
+
+### Valid:
+```typescript
+const b = Boolean(foo);
+const found = foo.includes(bar);
+const n = Number(foo);
+const m = -foo;
+const s = String(foo);
+const t = `foo: ${foo}`;
+```"#
+  }
+}
+
+struct NoImplicitCoercionVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoImplicitCoercionOptions,
+}
+
+impl<'c, 'o> NoImplicitCoercionVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoImplicitCoercionOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn is_allowed(&self, operator: &str) -> bool {
+    self.options.allow.iter().any(|allowed| allowed == operator)
+  }
+
+  fn snippet(&self, span: Span) -> String {
+    self
+      .context
+      .source_map
+      .span_to_snippet(span)
+      .expect("Failed to get snippet of expression")
+  }
+
+  fn report(&mut self, span: Span, to: &str, recommendation: String) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      "no-implicit-coercion",
+      format!("Implicit coercion to {} found", to),
+      format!("Use `{}` instead", recommendation),
+    );
+  }
+
+  fn check_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    match unary_expr.op {
+      UnaryOp::Bang if self.options.boolean && !self.is_allowed("!!") => {
+        if let Expr::Unary(UnaryExpr {
+          op: UnaryOp::Bang,
+          arg,
+          ..
+        }) = &*unary_expr.arg
+        {
+          let recommendation = format!("Boolean({})", self.snippet(arg.span()));
+          self.report(unary_expr.span, "boolean", recommendation);
+        }
+      }
+      UnaryOp::Tilde if self.options.boolean && !self.is_allowed("~") => {
+        if let Some((obj, call_expr)) = index_of_call(&unary_expr.arg) {
+          let args = call_expr
+            .args
+            .iter()
+            .map(|arg| self.snippet(arg.span()))
+            .collect::<Vec<_>>()
+            .join(", ");
+          let recommendation =
+            format!("{}.includes({})", self.snippet(obj.span()), args);
+          self.report(unary_expr.span, "boolean", recommendation);
+        }
+      }
+      UnaryOp::Plus if self.options.number && !self.is_allowed("+") => {
+        if !is_numeric(&unary_expr.arg) {
+          let recommendation =
+            format!("Number({})", self.snippet(unary_expr.arg.span()));
+          self.report(unary_expr.span, "number", recommendation);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn check_bin_expr(&mut self, bin_expr: &BinExpr) {
+    match bin_expr.op {
+      BinaryOp::Mul if self.options.number && !self.is_allowed("*") => {
+        let operand = match (&*bin_expr.left, &*bin_expr.right) {
+          (Expr::Lit(Lit::Num(num)), operand)
+          | (operand, Expr::Lit(Lit::Num(num)))
+            if num.value == 1.0 =>
+          {
+            operand
+          }
+          _ => return,
+        };
+        if !is_numeric(operand) {
+          let recommendation =
+            format!("Number({})", self.snippet(operand.span()));
+          self.report(bin_expr.span, "number", recommendation);
+        }
+      }
+      BinaryOp::Add if self.options.string && !self.is_allowed("+") => {
+        let operand = match (&*bin_expr.left, &*bin_expr.right) {
+          (Expr::Lit(Lit::Str(str_)), operand)
+          | (operand, Expr::Lit(Lit::Str(str_)))
+            if str_.value.is_empty() =>
+          {
+            operand
+          }
+          _ => return,
+        };
+        if !is_string(operand) {
+          let recommendation =
+            format!("String({})", self.snippet(operand.span()));
+          self.report(bin_expr.span, "string", recommendation);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn check_tpl(&mut self, tpl: &Tpl) {
+    if !self.options.string || self.is_allowed("``") {
+      return;
+    }
+    if tpl.exprs.len() == 1
+      && tpl.quasis.iter().all(|quasi| quasi.raw.value.is_empty())
+      && !is_string(&tpl.exprs[0])
+    {
+      let recommendation =
+        format!("String({})", self.snippet(tpl.exprs[0].span()));
+      self.report(tpl.span, "string", recommendation);
+    }
+  }
+}
+
+/// Returns the object and the call of `obj.indexOf(x)` or
+/// `obj.lastIndexOf(x)`.
+fn index_of_call(expr: &Expr) -> Option<(&Expr, &CallExpr)> {
+  if let Expr::Call(call_expr) = expr {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Member(member_expr) = &**callee {
+        if let (ExprOrSuper::Expr(obj), Expr::Ident(prop), false) =
+          (&member_expr.obj, &*member_expr.prop, member_expr.computed)
+        {
+          if prop.sym == *"indexOf" || prop.sym == *"lastIndexOf" {
+            return Some((obj, call_expr));
+          }
+        }
+      }
+    }
+  }
+  None
+}
+
+/// Whether the expression is a call to one of the functions `names`.
+fn is_call_to(expr: &Expr, names: &[&str]) -> bool {
+  if let Expr::Call(CallExpr {
+    callee: ExprOrSuper::Expr(callee),
+    ..
+  }) = expr
+  {
+    if let Expr::Ident(ident) = &**callee {
+      return names.iter().any(|name| ident.sym == **name);
+    }
+  }
+  false
+}
+
+fn is_numeric(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Num(_)))
+    || is_call_to(expr, &["Number", "parseInt", "parseFloat"])
+}
+
+fn is_string(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Str(_)) | Expr::Tpl(_))
+    || is_call_to(expr, &["String"])
+}
+
+impl<'c, 'o> Visit for NoImplicitCoercionVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, parent: &dyn Node) {
+    match expr {
+      Expr::Unary(unary_expr) => self.check_unary_expr(unary_expr),
+      Expr::Bin(bin_expr) => self.check_bin_expr(bin_expr),
+      // Tagged templates aren't `Expr::Tpl`, so they are never reported.
+      Expr::Tpl(tpl) => self.check_tpl(tpl),
+      _ => {}
+    }
+    swc_ecmascript::visit::visit_expr(self, expr, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/no-implicit-coercion.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_implicit_coercion_valid() {
+    assert_lint_ok! {
+      NoImplicitCoercion,
+      "Boolean(foo)",
+      "foo.indexOf(1) !== -1",
+      "foo.includes(1)",
+      "Number(foo)",
+      "parseInt(foo)",
+      "+1",
+      "+Number(foo)",
+      "+parseFloat(foo)",
+      "-foo",
+      "!foo",
+      "~foo",
+      "~foo.bar()",
+      "1 * 1234",
+      "2 * foo",
+      "foo * 1.5",
+      "String(foo)",
+      r#"foo + "bar""#,
+      r#""" + "foo""#,
+      r#""" + `${foo}`"#,
+      r#""" + String(foo)"#,
+      "`foo${bar}`",
+      "`${foo}bar`",
+      "`${foo}${bar}`",
+      "tag`${foo}`",
+      "`${'foo'}`",
+    };
+  }
+
+  #[test]
+  fn no_implicit_coercion_invalid() {
+    assert_lint_err! {
+      NoImplicitCoercion,
+      "+foo": [
+        {
+          col: 0,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(foo)` instead",
+        }
+      ],
+      "+foo.bar": [
+        {
+          col: 0,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(foo.bar)` instead",
+        }
+      ],
+      "1 * foo": [
+        {
+          col: 0,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(foo)` instead",
+        }
+      ],
+      "foo * 1": [
+        {
+          col: 0,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(foo)` instead",
+        }
+      ],
+      "!!foo": [
+        {
+          col: 0,
+          message: "Implicit coercion to boolean found",
+          hint: "Use `Boolean(foo)` instead",
+        }
+      ],
+      "if (!!foo.bar) {}": [
+        {
+          col: 4,
+          message: "Implicit coercion to boolean found",
+          hint: "Use `Boolean(foo.bar)` instead",
+        }
+      ],
+      "~foo.indexOf(1)": [
+        {
+          col: 0,
+          message: "Implicit coercion to boolean found",
+          hint: "Use `foo.includes(1)` instead",
+        }
+      ],
+      "~foo.bar.lastIndexOf(x, 2)": [
+        {
+          col: 0,
+          message: "Implicit coercion to boolean found",
+          hint: "Use `foo.bar.includes(x, 2)` instead",
+        }
+      ],
+      r#""" + foo"#: [
+        {
+          col: 0,
+          message: "Implicit coercion to string found",
+          hint: "Use `String(foo)` instead",
+        }
+      ],
+      r#"foo + ''"#: [
+        {
+          col: 0,
+          message: "Implicit coercion to string found",
+          hint: "Use `String(foo)` instead",
+        }
+      ],
+      "`${foo}`": [
+        {
+          col: 0,
+          message: "Implicit coercion to string found",
+          hint: "Use `String(foo)` instead",
+        }
+      ],
+      "const x = [+a, !!b];": [
+        {
+          col: 11,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(a)` instead",
+        },
+        {
+          col: 15,
+          message: "Implicit coercion to boolean found",
+          hint: "Use `Boolean(b)` instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_implicit_coercion_options() {
+    assert_lint_ok_with_options! {
+      NoImplicitCoercion,
+      json!({ "boolean": false }),
+      "!!foo",
+      "~foo.indexOf(1)",
+    };
+    assert_lint_ok_with_options! {
+      NoImplicitCoercion,
+      json!({ "number": false, "string": false }),
+      "+foo",
+      "1 * foo",
+      r#""" + foo"#,
+      "`${foo}`",
+    };
+    assert_lint_ok_with_options! {
+      NoImplicitCoercion,
+      json!({ "allow": ["!!", "~", "+", "*", "``"] }),
+      "!!foo",
+      "~foo.indexOf(1)",
+      "+foo",
+      "1 * foo",
+      r#""" + foo"#,
+      "`${foo}`",
+    };
+    assert_lint_err_with_options! {
+      NoImplicitCoercion,
+      json!({ "allow": ["!!"] }),
+      "!!foo; +foo;": [
+        {
+          col: 7,
+          message: "Implicit coercion to number found",
+          hint: "Use `Number(foo)` instead",
+        }
+      ],
+    };
+  }
+}
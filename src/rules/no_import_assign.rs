@@ -48,6 +48,36 @@ impl LintRule for NoImportAssign {
     );
     module.visit_with(module, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows reassigning imported bindings.
+
+ES module bindings are read-only, so assigning to an import throws a
+`TypeError` at runtime. The same goes for modifying the properties of a
+namespace import, which is frozen.
+
+### Invalid:
+```typescript
+import defaultMod, { namedMod } from "./mod.js";
+import * as modNameSpace from "./mod2.js";
+
+defaultMod = 0;
+namedMod = true;
+modNameSpace.someExportedMember = "hello";
+modNameSpace = {};
+```
+
+### Valid:
+```typescript
+import defaultMod, { namedMod } from "./mod.js";
+import * as modNameSpace from "./mod2.js";
+
+defaultMod.prop = 1;
+namedMod.prop = true;
+modNameSpace.someExportedMember.prop = "hello";
+modNameSpace.someExportedMember.prop++;
+```"#
+  }
 }
 
 struct Collector {
@@ -142,20 +172,16 @@ impl<'c> NoImportAssignVisitor<'c> {
     }
 
     if self.ns_imports.contains(&i.to_id()) {
-      self.context.add_diagnostic(
-        span,
-        "no-import-assign",
-        "Assignment to import is not allowed",
-      );
+      self
+        .context
+        .add_diagnostic_with_hint(span, "no-import-assign", "Assignment to import is not allowed", "Imported bindings are read-only, assign the value to a local variable instead");
       return;
     }
 
     if !is_assign_to_prop && self.imports.contains(&i.to_id()) {
-      self.context.add_diagnostic(
-        span,
-        "no-import-assign",
-        "Assignment to import is not allowed",
-      );
+      self
+        .context
+        .add_diagnostic_with_hint(span, "no-import-assign", "Assignment to import is not allowed", "Imported bindings are read-only, assign the value to a local variable instead");
     }
   }
 
@@ -670,4 +696,39 @@ mod tests {
       28,
     );
   }
+
+  #[test]
+  fn no_import_assign_message() {
+    assert_lint_err! {
+      NoImportAssign,
+      "import mod from 'mod'; mod = 1;": [
+        {
+          col: 23,
+          message: "Assignment to import is not allowed",
+          hint: "Imported bindings are read-only, assign the value to a local variable instead",
+        }
+      ],
+      "import { named } from 'mod'; named = 1;": [
+        {
+          col: 29,
+          message: "Assignment to import is not allowed",
+          hint: "Imported bindings are read-only, assign the value to a local variable instead",
+        }
+      ],
+      "import * as ns from 'mod'; ns.foo = 1;": [
+        {
+          col: 27,
+          message: "Assignment to import is not allowed",
+          hint: "Imported bindings are read-only, assign the value to a local variable instead",
+        }
+      ],
+      "import * as ns from 'mod'; ns['foo'] = 1;": [
+        {
+          col: 27,
+          message: "Assignment to import is not allowed",
+          hint: "Imported bindings are read-only, assign the value to a local variable instead",
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,127 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  ImportDecl, ImportSpecifier, Module, ModuleDecl, ModuleItem,
+};
+
+pub struct NoImportTypeSideEffects;
+
+impl LintRule for NoImportTypeSideEffects {
+  fn new() -> Box<Self> {
+    Box::new(NoImportTypeSideEffects)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-import-type-side-effects"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    for item in &module.body {
+      if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
+        check_import(context, import_decl);
+      }
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows imports whose specifiers all have inline `type` qualifiers.
+
+Transpilers which keep imports that are not fully type-only, e.g. with
+`verbatimModuleSyntax`, remove the inline type specifiers but keep
+`import {} from "./foo.ts"`, which still runs the side effects of the module.
+A top-level `import type` is removed completely.
+
+### Invalid:
+```typescript
+import { type Foo, type Bar } from "./foo.ts";
+```
+
+### Valid:
+```typescript
+import type { Foo, Bar } from "./foo.ts";
+import { type Foo, bar } from "./foo.ts";
+```"#
+  }
+}
+
+fn check_import(context: &mut Context, import_decl: &ImportDecl) {
+  if import_decl.type_only || import_decl.specifiers.is_empty() {
+    return;
+  }
+
+  let mut names = vec![];
+  for specifier in &import_decl.specifiers {
+    let named = match specifier {
+      ImportSpecifier::Named(named) => named,
+      _ => return,
+    };
+    // The name of the specifier starts after the inline `type` qualifier.
+    let name = named.imported.as_ref().unwrap_or(&named.local);
+    if name.span.lo == named.span.lo {
+      return;
+    }
+    names.push(match &named.imported {
+      Some(imported) => format!("{} as {}", imported.sym, named.local.sym),
+      None => named.local.sym.to_string(),
+    });
+  }
+
+  context.add_diagnostic_with_hint(
+    import_decl.span,
+    "no-import-type-side-effects",
+    "All specifiers of this import have inline `type` qualifiers",
+    format!("Use `import type {{ {} }}` instead", names.join(", ")),
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_import_type_side_effects_valid() {
+    assert_lint_ok! {
+      NoImportTypeSideEffects,
+      r#"import type { A, B } from "./foo.ts";"#,
+      r#"import { A, B } from "./foo.ts";"#,
+      r#"import { type } from "./foo.ts";"#,
+      r#"import { type as A } from "./foo.ts";"#,
+      r#"import A from "./foo.ts";"#,
+      r#"import * as foo from "./foo.ts";"#,
+      r#"import "./foo.ts";"#,
+    };
+  }
+
+  #[test]
+  #[ignore = "swc cannot parse this at the moment"]
+  fn no_import_type_side_effects_valid_inline_type() {
+    assert_lint_ok! {
+      NoImportTypeSideEffects,
+      r#"import { type A, B } from "./foo.ts";"#,
+      r#"import A, { type B } from "./foo.ts";"#,
+    };
+  }
+
+  #[test]
+  #[ignore = "swc cannot parse this at the moment"]
+  fn no_import_type_side_effects_invalid() {
+    assert_lint_err! {
+      NoImportTypeSideEffects,
+      r#"import { type A, type B } from "./foo.ts";"#: [
+        {
+          col: 0,
+          message: "All specifiers of this import have inline `type` qualifiers",
+          hint: "Use `import type { A, B }` instead",
+        }
+      ],
+      r#"import { type A as C } from "./foo.ts";"#: [
+        {
+          col: 0,
+          message: "All specifiers of this import have inline `type` qualifiers",
+          hint: "Use `import type { A as C }` instead",
+        }
+      ],
+    };
+  }
+}
@@ -32,6 +32,29 @@ impl LintRule for NoInvalidRegexp {
     let mut visitor = NoInvalidRegexpVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows invalid regular expressions.
+
+Regular expression literals and the patterns and flags passed as string
+literals to `RegExp` are parsed, and the syntax errors found, like unbalanced
+groups or unknown flags, are reported along with the error of the parser.
+Patterns built at runtime are not checked.
+
+### Invalid:
+```typescript
+const a = /(/;
+const b = new RegExp("[");
+const c = new RegExp(".", "z");
+```
+
+### Valid:
+```typescript
+const a = /()/;
+const b = new RegExp("[a-z]");
+const c = new RegExp(pattern);
+```"#
+  }
 }
 
 fn check_expr_for_string_literal(expr: &Expr) -> Option<String> {
@@ -80,26 +103,30 @@ impl<'c> NoInvalidRegexpVisitor<'c> {
   }
 
   fn check_regex(&mut self, pattern: &str, flags: &str, span: Span) {
-    if self.check_for_invalid_flags(flags)
-      || (!flags.is_empty()
-        && self.check_for_invalid_pattern(pattern, flags.contains('u')))
-      || (self.check_for_invalid_pattern(pattern, true)
-        && self.check_for_invalid_pattern(pattern, false))
-    {
-      self.context.add_diagnostic(
+    if let Err(error) = self.validate(pattern, flags) {
+      self.context.add_diagnostic_with_hint(
         span,
         "no-invalid-regexp",
         "Invalid RegExp literal",
+        error,
       );
     }
   }
 
-  fn check_for_invalid_flags(&self, flags: &str) -> bool {
-    self.validator.validate_flags(flags).is_err()
-  }
-
-  fn check_for_invalid_pattern(&mut self, source: &str, u_flag: bool) -> bool {
-    self.validator.validate_pattern(source, u_flag).is_err()
+  /// Returns the error of the parser if `pattern` or `flags` is invalid.
+  fn validate(&mut self, pattern: &str, flags: &str) -> Result<(), String> {
+    self.validator.validate_flags(flags)?;
+    if !flags.is_empty() {
+      return self
+        .validator
+        .validate_pattern(pattern, flags.contains('u'));
+    }
+    // The flags may be unknown, e.g. in `new RegExp(pattern, flags)`, so the
+    // pattern is valid if it's valid with or without the `u` flag.
+    self
+      .validator
+      .validate_pattern(pattern, true)
+      .or_else(|_| self.validator.validate_pattern(pattern, false))
   }
 }
 
@@ -117,17 +144,18 @@ impl<'c> Visit for NoInvalidRegexpVisitor<'c> {
   fn visit_call_expr(
     &mut self,
     call_expr: &swc_ecmascript::ast::CallExpr,
-    _paren: &dyn Node,
+    parent: &dyn Node,
   ) {
     if let swc_ecmascript::ast::ExprOrSuper::Expr(expr) = &call_expr.callee {
       self.handle_call_or_new_expr(&*expr, &call_expr.args, call_expr.span);
     }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
   }
 
   fn visit_new_expr(
     &mut self,
     new_expr: &swc_ecmascript::ast::NewExpr,
-    _parent: &dyn Node,
+    parent: &dyn Node,
   ) {
     if new_expr.args.is_some() {
       self.handle_call_or_new_expr(
@@ -136,6 +164,7 @@ impl<'c> Visit for NoInvalidRegexpVisitor<'c> {
         new_expr.span,
       );
     }
+    swc_ecmascript::visit::visit_new_expr(self, new_expr, parent);
   }
 }
 
@@ -204,5 +233,57 @@ let re = new RegExp('foo', x);"#,
       1,
       0,
     );
+    assert_lint_err_on_line::<NoInvalidRegexp>(r#"/(/;"#, 1, 0);
+    assert_lint_err_on_line::<NoInvalidRegexp>(
+      r#"foo.replace(/(/, "");"#,
+      1,
+      12,
+    );
+    assert_lint_err_on_line::<NoInvalidRegexp>(
+      r#"foo(new RegExp("["));"#,
+      1,
+      4,
+    );
+  }
+
+  #[test]
+  fn no_invalid_regexp_hint() {
+    assert_lint_err! {
+      NoInvalidRegexp,
+      "/(/;": [
+        {
+          col: 0,
+          message: "Invalid RegExp literal",
+          hint: "Unterminated group",
+        }
+      ],
+      r#"new RegExp("[");"#: [
+        {
+          col: 0,
+          message: "Invalid RegExp literal",
+          hint: "Unterminated character class",
+        }
+      ],
+      r#"new RegExp("a", "gg");"#: [
+        {
+          col: 0,
+          message: "Invalid RegExp literal",
+          hint: "Duplicated flag g",
+        }
+      ],
+      "/a)/;": [
+        {
+          col: 0,
+          message: "Invalid RegExp literal",
+          hint: "Unmatched ')'",
+        }
+      ],
+    };
+    assert_lint_ok! {
+      NoInvalidRegexp,
+      "new RegExp(dynamic);",
+      r#"new RegExp(dynamic, "g");"#,
+      r#"new RegExp("(" + dynamic);"#,
+    };
   }
 }
@@ -0,0 +1,433 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, CallExpr, Expr, ExprOrSuper, KeyValueProp, Lit, MemberExpr, Pat,
+  PatOrExpr, TsEnumMember, UnaryExpr, UnaryOp, VarDecl, VarDeclKind,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::{Visit, VisitWith};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMagicNumbersOptions {
+  /// Numbers which are allowed anywhere, as in `[0, 1, -1]`.
+  pub ignore: Vec<f64>,
+  /// Allows integers used as a computed property, as in `arr[2]`.
+  pub ignore_array_indexes: bool,
+  /// Requires the declarations of number constants to use `const`.
+  pub enforce_const: bool,
+  /// Also reports numbers assigned to object properties, as in
+  /// `obj.timeout = 60` or `{ timeout: 60 }`.
+  pub detect_objects: bool,
+}
+
+pub struct NoMagicNumbers {
+  options: NoMagicNumbersOptions,
+}
+
+impl NoMagicNumbers {
+  pub fn with_options(options: NoMagicNumbersOptions) -> Box<Self> {
+    Box::new(NoMagicNumbers { options })
+  }
+}
+
+impl LintRule for NoMagicNumbers {
+  fn new() -> Box<Self> {
+    Self::with_options(NoMagicNumbersOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-magic-numbers"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoMagicNumbersVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows numbers that aren't assigned to a named constant.
+
+A number used inline, as in `a * 60`, doesn't say what it stands for and has to
+be updated everywhere it's repeated. Declaring it as a named constant first
+documents its meaning.
+
+### Options:
+- `ignore`: a list of numbers which are allowed anywhere, as in `[0, 1, -1]`.
+- `ignoreArrayIndexes`: allows integers used as an index, as in `arr[2]`.
+- `enforceConst`: requires the declarations of numbers to use `const`.
+- `detectObjects`: also reports numbers assigned to object properties, as in
+  `obj.timeout = 60` or `{ timeout: 60 }`.
+
+### Invalid:
+```typescript
+const seconds = minutes * 60;
+let timeout;
+timeout = 60;
+```
+
+### Valid:
+```typescript
+const SECONDS_PER_MINUTE = 60;
+const seconds = minutes * SECONDS_PER_MINUTE;
+const config = { timeout: 60 };
+```"#
+  }
+}
+
+struct NoMagicNumbersVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoMagicNumbersOptions,
+}
+
+impl<'c, 'o> NoMagicNumbersVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoMagicNumbersOptions) -> Self {
+    Self { context, options }
+  }
+
+  fn is_ignored(&self, value: f64) -> bool {
+    self.options.ignore.contains(&value)
+  }
+
+  fn report(&mut self, span: Span) {
+    let raw = self
+      .context
+      .source_map
+      .span_to_snippet(span)
+      .expect("Failed to get snippet of number");
+    self.context.add_diagnostic_with_hint(
+      span,
+      "no-magic-numbers",
+      format!("Magic number `{}` found", raw),
+      "Assign the number to a named `const` and use it instead",
+    );
+  }
+}
+
+/// Returns the value and span of `expr` if it's a number literal, which may
+/// be negated as in `-1`.
+fn as_number(expr: &Expr) -> Option<(f64, Span)> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some((num.value, num.span)),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      span,
+    }) => match &**arg {
+      Expr::Lit(Lit::Num(num)) => Some((-num.value, *span)),
+      _ => None,
+    },
+    Expr::Paren(paren_expr) => as_number(&paren_expr.expr),
+    _ => None,
+  }
+}
+
+fn is_array_index(value: f64) -> bool {
+  value.fract() == 0.0 && value >= 0.0 && value < u32::MAX as f64
+}
+
+fn is_ident(pat_or_expr: &PatOrExpr) -> bool {
+  match pat_or_expr {
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Ident(_) => true,
+      Pat::Expr(expr) => matches!(&**expr, Expr::Ident(_)),
+      _ => false,
+    },
+    PatOrExpr::Expr(expr) => matches!(&**expr, Expr::Ident(_)),
+  }
+}
+
+fn is_parse_int(callee: &ExprOrSuper) -> bool {
+  match callee {
+    ExprOrSuper::Expr(expr) => {
+      matches!(&**expr, Expr::Ident(ident) if ident.sym == *"parseInt")
+    }
+    ExprOrSuper::Super(_) => false,
+  }
+}
+
+impl<'c, 'o> Visit for NoMagicNumbersVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, parent: &dyn Node) {
+    // Numbers in an allowed position are handled by their parent node, so any
+    // number reaching here is used inline.
+    if let Some((value, span)) = as_number(expr) {
+      if !self.is_ignored(value) {
+        self.report(span);
+      }
+      return;
+    }
+    swc_ecmascript::visit::visit_expr(self, expr, parent);
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    for decl in &var_decl.decls {
+      decl.name.visit_with(decl, self);
+      if let Some(init) = &decl.init {
+        match as_number(init) {
+          Some((value, span)) => {
+            if self.options.enforce_const
+              && var_decl.kind != VarDeclKind::Const
+              && !self.is_ignored(value)
+            {
+              self.context.add_diagnostic_with_hint(
+                span,
+                "no-magic-numbers",
+                "Number constants declarations must use `const`",
+                "Use `const` instead",
+              );
+            }
+          }
+          None => init.visit_with(decl, self),
+        }
+      }
+    }
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, parent: &dyn Node) {
+    if member_expr.computed && self.options.ignore_array_indexes {
+      if let Some((value, _)) = as_number(&member_expr.prop) {
+        if is_array_index(value) {
+          member_expr.obj.visit_with(member_expr, self);
+          return;
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_member_expr(self, member_expr, parent);
+  }
+
+  fn visit_key_value_prop(&mut self, prop: &KeyValueProp, parent: &dyn Node) {
+    if !self.options.detect_objects && as_number(&prop.value).is_some() {
+      prop.key.visit_with(prop, self);
+      return;
+    }
+    swc_ecmascript::visit::visit_key_value_prop(self, prop, parent);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, parent: &dyn Node) {
+    // Assigning to a property, as in `obj.a = 1`, names the number, while
+    // assigning to a variable after its declaration doesn't.
+    if !self.options.detect_objects
+      && !is_ident(&assign_expr.left)
+      && as_number(&assign_expr.right).is_some()
+    {
+      assign_expr.left.visit_with(assign_expr, self);
+      return;
+    }
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, parent);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    // The radix of `parseInt` is self-explanatory.
+    let radix = call_expr.args.get(1);
+    let has_radix = is_parse_int(&call_expr.callee)
+      && radix.map_or(false, |radix| {
+        radix.spread.is_none() && as_number(&radix.expr).is_some()
+      });
+    if !has_radix {
+      swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+      return;
+    }
+
+    call_expr.callee.visit_with(call_expr, self);
+    for (index, arg) in call_expr.args.iter().enumerate() {
+      if index != 1 {
+        arg.visit_with(call_expr, self);
+      }
+    }
+  }
+
+  fn visit_ts_enum_member(
+    &mut self,
+    enum_member: &TsEnumMember,
+    parent: &dyn Node,
+  ) {
+    // Enum members are named constants already.
+    if let Some(init) = &enum_member.init {
+      if as_number(init).is_some() {
+        return;
+      }
+    }
+    swc_ecmascript::visit::visit_ts_enum_member(self, enum_member, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/no-magic-numbers.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_magic_numbers_valid() {
+    assert_lint_ok! {
+      NoMagicNumbers,
+      "const SIXTY = 60;",
+      "const x = a * SIXTY;",
+      "const NEGATIVE = -1;",
+      "const PARENS = (2);",
+      "let limit = 10;",
+      "var TIMEOUT = 1000, RETRIES = 3;",
+      "const config = { timeout: 60 };",
+      "obj.timeout = 60;",
+      "obj['timeout'] = 60;",
+      "parseInt(x, 10);",
+      "enum Direction { Up = 1, Down = 2 }",
+      "type Digit = 1 | 2 | 3;",
+      "const [a = b] = c;",
+      "const s = 'string';",
+    };
+  }
+
+  #[test]
+  fn no_magic_numbers_invalid() {
+    assert_lint_err! {
+      NoMagicNumbers,
+      "const x = a * 60;": [
+        {
+          col: 14,
+          message: "Magic number `60` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "let timeout; timeout = 60;": [
+        {
+          col: 23,
+          message: "Magic number `60` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "const x = a + -1;": [
+        {
+          col: 14,
+          message: "Magic number `-1` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "const x = arr[2];": [
+        {
+          col: 14,
+          message: "Magic number `2` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "setTimeout(f, 1000);": [
+        {
+          col: 14,
+          message: "Magic number `1000` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "function f(x = 0.5) {}": [
+        {
+          col: 15,
+          message: "Magic number `0.5` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "parseInt(x, 10) * 100;": [
+        {
+          col: 18,
+          message: "Magic number `100` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "if (x > 0xff) { const y = { z: x * 2 }; }": [
+        {
+          col: 8,
+          message: "Magic number `0xff` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        },
+        {
+          col: 35,
+          message: "Magic number `2` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_magic_numbers_options() {
+    assert_lint_ok_with_options! {
+      NoMagicNumbers,
+      json!({ "ignore": [0, 1, -1], "ignoreArrayIndexes": true }),
+      "const x = a + 1;",
+      "if (x === -1) {}",
+      "const y = arr[0] + arr[2] + arr[1e3];",
+      "let count = 0;",
+    };
+    assert_lint_err_with_options! {
+      NoMagicNumbers,
+      json!({ "ignoreArrayIndexes": true }),
+      "const x = arr[-1];": [
+        {
+          col: 14,
+          message: "Magic number `-1` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "const x = arr[1.5];": [
+        {
+          col: 14,
+          message: "Magic number `1.5` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      NoMagicNumbers,
+      json!({ "enforceConst": true }),
+      "let SIXTY = 60;": [
+        {
+          col: 12,
+          message: "Number constants declarations must use `const`",
+          hint: "Use `const` instead",
+        }
+      ],
+      "var a = 1, b = 2;": [
+        {
+          col: 8,
+          message: "Number constants declarations must use `const`",
+          hint: "Use `const` instead",
+        },
+        {
+          col: 15,
+          message: "Number constants declarations must use `const`",
+          hint: "Use `const` instead",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      NoMagicNumbers,
+      json!({ "detectObjects": true }),
+      "const config = { timeout: 60 };": [
+        {
+          col: 26,
+          message: "Magic number `60` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+      "obj.timeout = 60;": [
+        {
+          col: 14,
+          message: "Magic number `60` found",
+          hint: "Assign the number to a named `const` and use it instead",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,436 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::iter::Peekable;
+use std::str::Chars;
+use swc_ecmascript::ast::Regex;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoMisleadingCharacterClass;
+
+impl LintRule for NoMisleadingCharacterClass {
+  fn new() -> Box<Self> {
+    Box::new(NoMisleadingCharacterClass)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-misleading-character-class"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoMisleadingCharacterClassVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows characters made of multiple code points in character classes.
+
+A character class matches a single code point, or a single UTF-16 code unit
+without the `u` flag. Characters which are displayed as one but made of several
+code points, like letters with combining marks, emojis with modifiers, flags or
+emojis joined with a zero width joiner, are split into their parts, so the
+class matches any of the parts on their own.
+
+### Invalid:
+```typescript
+/^[👍]$/; // surrogate pair without the `u` flag
+/^[e\u0301]$/u; // "e" followed by a combining acute accent
+/^[👶🏻]$/u; // emoji with a skin tone modifier
+/^[🇯🇵]$/u; // flag made of two regional indicator symbols
+/^[👨‍👩‍👦]$/u; // emojis joined with zero width joiners
+```
+
+### Valid:
+```typescript
+/^[abc]$/;
+/^[👍]$/u;
+/^(?:e\u0301|👶🏻)$/u;
+```"#
+  }
+}
+
+/// A reason a character class is misleading, in the order they're reported.
+#[derive(Clone, Copy, PartialEq)]
+enum Misleading {
+  SurrogatePairWithoutUFlag,
+  CombiningClass,
+  EmojiModifier,
+  RegionalIndicatorSymbol,
+  ZeroWidthJoiner,
+}
+
+impl Misleading {
+  fn message(self) -> &'static str {
+    match self {
+      Misleading::SurrogatePairWithoutUFlag => {
+        "Unexpected surrogate pair in character class"
+      }
+      Misleading::CombiningClass => {
+        "Unexpected combined character in character class"
+      }
+      Misleading::EmojiModifier => {
+        "Unexpected modified emoji in character class"
+      }
+      Misleading::RegionalIndicatorSymbol => {
+        "Unexpected national flag in character class"
+      }
+      Misleading::ZeroWidthJoiner => {
+        "Unexpected joined character sequence in character class"
+      }
+    }
+  }
+
+  fn hint(self) -> &'static str {
+    match self {
+      Misleading::SurrogatePairWithoutUFlag => "Add the `u` flag",
+      _ => "Use an alternation like `(?:a|b)` instead of a character class",
+    }
+  }
+}
+
+const ALL_MISLEADING: &[Misleading] = &[
+  Misleading::SurrogatePairWithoutUFlag,
+  Misleading::CombiningClass,
+  Misleading::EmojiModifier,
+  Misleading::RegionalIndicatorSymbol,
+  Misleading::ZeroWidthJoiner,
+];
+
+struct NoMisleadingCharacterClassVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoMisleadingCharacterClassVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for NoMisleadingCharacterClassVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_regex(&mut self, regex: &Regex, _parent: &dyn Node) {
+    let unicode = regex.flags.contains('u');
+    let sequences = character_sequences(&regex.exp, unicode);
+    for misleading in ALL_MISLEADING {
+      if sequences
+        .iter()
+        .any(|seq| is_misleading(seq, *misleading, unicode))
+      {
+        self.context.add_diagnostic_with_hint(
+          regex.span,
+          "no-misleading-character-class",
+          misleading.message(),
+          misleading.hint(),
+        );
+      }
+    }
+  }
+}
+
+fn is_misleading(seq: &[u32], misleading: Misleading, unicode: bool) -> bool {
+  // Checks if a character matching `is_kind` follows a character which
+  // doesn't match it.
+  let follows_other = |is_kind: fn(u32) -> bool| {
+    seq
+      .windows(2)
+      .any(|pair| !is_kind(pair[0]) && is_kind(pair[1]))
+  };
+  match misleading {
+    Misleading::SurrogatePairWithoutUFlag => {
+      !unicode
+        && seq
+          .windows(2)
+          .any(|pair| is_high_surrogate(pair[0]) && is_low_surrogate(pair[1]))
+    }
+    Misleading::CombiningClass => follows_other(is_combining_character),
+    Misleading::EmojiModifier => follows_other(is_emoji_modifier),
+    Misleading::RegionalIndicatorSymbol => seq
+      .windows(2)
+      .any(|pair| pair.iter().all(|c| is_regional_indicator_symbol(*c))),
+    Misleading::ZeroWidthJoiner => seq
+      .windows(3)
+      .any(|w| w[0] != ZWJ && w[1] == ZWJ && w[2] != ZWJ),
+  }
+}
+
+const ZWJ: u32 = 0x200d;
+
+fn is_high_surrogate(c: u32) -> bool {
+  (0xd800..=0xdbff).contains(&c)
+}
+
+fn is_low_surrogate(c: u32) -> bool {
+  (0xdc00..=0xdfff).contains(&c)
+}
+
+fn is_emoji_modifier(c: u32) -> bool {
+  (0x1f3fb..=0x1f3ff).contains(&c)
+}
+
+fn is_regional_indicator_symbol(c: u32) -> bool {
+  (0x1f1e6..=0x1f1ff).contains(&c)
+}
+
+fn is_combining_character(c: u32) -> bool {
+  lazy_static! {
+    static ref MARK: regex::Regex = regex::Regex::new(r"^\p{M}$").unwrap();
+  }
+  match std::char::from_u32(c) {
+    Some(c) => MARK.is_match(c.encode_utf8(&mut [0; 4])),
+    None => false,
+  }
+}
+
+/// Returns the sequences of adjacent characters in the character classes of
+/// `pattern`. Without the `u` flag, characters are UTF-16 code units.
+///
+/// Sequences are split at character sets like `\d` and at ranges, so
+/// `[a\db-c]` is split into `[a]`, `[b]` and `[c]`.
+fn character_sequences(pattern: &str, unicode: bool) -> Vec<Vec<u32>> {
+  let mut sequences = Vec::new();
+  let mut chars = pattern.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => {
+        chars.next();
+      }
+      '[' => {
+        if chars.peek() == Some(&'^') {
+          chars.next();
+        }
+        read_class(&mut chars, unicode, &mut sequences);
+      }
+      _ => {}
+    }
+  }
+  sequences
+}
+
+fn read_class(
+  chars: &mut Peekable<Chars>,
+  unicode: bool,
+  sequences: &mut Vec<Vec<u32>>,
+) {
+  let mut seq = Vec::new();
+  while let Some(c) = chars.next() {
+    if c == ']' {
+      break;
+    }
+    match read_class_atom(c, chars, unicode) {
+      Some(atom) => {
+        let is_range = chars.peek() == Some(&'-')
+          && chars.clone().nth(1).map_or(false, |next| next != ']');
+        if is_range {
+          // Only the last character of the atom is the start of the range.
+          chars.next();
+          seq.extend(atom);
+          sequences.push(std::mem::take(&mut seq));
+          if let Some(max) = chars.next() {
+            if let Some(max) = read_class_atom(max, chars, unicode) {
+              seq.extend(max);
+            }
+          }
+        } else {
+          seq.extend(atom);
+        }
+      }
+      None => {
+        if !seq.is_empty() {
+          sequences.push(std::mem::take(&mut seq));
+        }
+      }
+    }
+  }
+  if !seq.is_empty() {
+    sequences.push(seq);
+  }
+}
+
+/// Reads a character of a character class starting with `c`, returning
+/// `None` for character sets like `\d`. A character may be made of two UTF-16
+/// code units without the `u` flag.
+fn read_class_atom(
+  c: char,
+  chars: &mut Peekable<Chars>,
+  unicode: bool,
+) -> Option<Vec<u32>> {
+  let code_point = if c == '\\' {
+    match chars.next()? {
+      'u' => read_unicode_escape(chars, unicode).unwrap_or('u' as u32),
+      'x' => read_hex(chars, 2).unwrap_or('x' as u32),
+      'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'p' | 'P' => return None,
+      't' => '\t' as u32,
+      'n' => '\n' as u32,
+      'v' => 0x0b,
+      'f' => 0x0c,
+      'r' => '\r' as u32,
+      'b' => 0x08,
+      '0' => 0,
+      escaped => escaped as u32,
+    }
+  } else {
+    c as u32
+  };
+
+  if unicode || code_point <= 0xffff {
+    Some(vec![code_point])
+  } else {
+    let c = std::char::from_u32(code_point)?;
+    Some(
+      c.encode_utf16(&mut [0; 2])
+        .iter()
+        .map(|u| *u as u32)
+        .collect(),
+    )
+  }
+}
+
+/// Reads the rest of a `\uXXXX` or `\u{X}` escape after the `u`. With the `u`
+/// flag, a surrogate pair of two `\uXXXX` escapes is read as one code point.
+fn read_unicode_escape(
+  chars: &mut Peekable<Chars>,
+  unicode: bool,
+) -> Option<u32> {
+  if unicode && chars.peek() == Some(&'{') {
+    let mut lookahead = chars.clone();
+    lookahead.next();
+    let hex: String = lookahead.by_ref().take_while(|c| *c != '}').collect();
+    let code_point = u32::from_str_radix(&hex, 16).ok()?;
+    *chars = lookahead;
+    return Some(code_point);
+  }
+
+  let high = read_hex(chars, 4)?;
+  if unicode && is_high_surrogate(high) {
+    let mut lookahead = chars.clone();
+    if lookahead.next() == Some('\\') && lookahead.next() == Some('u') {
+      if let Some(low) = read_hex(&mut lookahead, 4) {
+        if is_low_surrogate(low) {
+          *chars = lookahead;
+          return Some(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00));
+        }
+      }
+    }
+  }
+  Some(high)
+}
+
+/// Reads `len` hex digits, leaving `chars` untouched if there aren't enough.
+fn read_hex(chars: &mut Peekable<Chars>, len: usize) -> Option<u32> {
+  let hex: String = chars.clone().take(len).collect();
+  if hex.len() != len || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+  for _ in 0..len {
+    chars.next();
+  }
+  u32::from_str_radix(&hex, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/no-misleading-character-class.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_misleading_character_class_valid() {
+    assert_lint_ok! {
+      NoMisleadingCharacterClass,
+      "var r = /[a-z]/;",
+      "var r = /[abc]/;",
+      "var r = /^[\u{1F44D}]$/u;",
+      "var r = /^[\\ud83d\\udc4d]$/u;",
+      "var r = /^[\\u{1F44D}]$/u;",
+      "var r = /^(?:A\u{301}|\u{1F476}\u{1F3FB})$/u;",
+      "var r = /^A\u{301}$/u;",
+      "var r = /^\u{1F476}\u{1F3FB}$/u;",
+      "var r = /^\u{1F1EF}\u{1F1F5}$/u;",
+      "var r = /^[\\ud83d-\\udc4d]$/;",
+      "var r = /^[\\d\\u0301]$/;",
+      "var r = /^[a-\\u0301]$/;",
+      "var r = /\\[A\u{301}\\]/;",
+      "var r = new RegExp('[\u{1F44D}]');",
+    };
+  }
+
+  #[test]
+  fn no_misleading_character_class_invalid() {
+    assert_lint_err! {
+      NoMisleadingCharacterClass,
+      "var r = /^[\u{1F44D}]$/;": [
+        {
+          col: 8,
+          message: "Unexpected surrogate pair in character class",
+          hint: "Add the `u` flag",
+        }
+      ],
+      "var r = /^[\\ud83d\\udc4d]$/;": [
+        {
+          col: 8,
+          message: "Unexpected surrogate pair in character class",
+          hint: "Add the `u` flag",
+        }
+      ],
+      "var r = /^[A\u{301}]$/u;": [
+        {
+          col: 8,
+          message: "Unexpected combined character in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[a\\u0301]$/;": [
+        {
+          col: 8,
+          message: "Unexpected combined character in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[\u{2747}\u{FE0F}]$/u;": [
+        {
+          col: 8,
+          message: "Unexpected combined character in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[\u{1F476}\u{1F3FB}]$/u;": [
+        {
+          col: 8,
+          message: "Unexpected modified emoji in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[\u{1F1EF}\u{1F1F5}]$/u;": [
+        {
+          col: 8,
+          message: "Unexpected national flag in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}]$/u;": [
+        {
+          col: 8,
+          message: "Unexpected joined character sequence in character class",
+          hint: "Use an alternation like `(?:a|b)` instead of a character class",
+        }
+      ],
+      "var r = /^[\u{1F476}\u{1F3FB}]$/;": [
+        {
+          col: 8,
+          message: "Unexpected surrogate pair in character class",
+          hint: "Add the `u` flag",
+        }
+      ],
+    };
+  }
+}
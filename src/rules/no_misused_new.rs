@@ -65,10 +65,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
         if let TsMethodSignature(signature) = &member {
           if let Expr::Ident(ident) = &*signature.key {
             if self.is_constructor_keyword(&ident) {
-              self.context.add_diagnostic(
+              self.context.add_diagnostic_with_hint(
                 ident.span,
                 "no-misused-new",
                 "Type aliases cannot be constructed, only classes",
+                "Use `new (): T` to describe a construct signature",
               );
             }
           }
@@ -88,10 +89,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
           if let Expr::Ident(ident) = &*signature.key {
             if self.is_constructor_keyword(&ident) {
               // constructor
-              self.context.add_diagnostic(
+              self.context.add_diagnostic_with_hint(
                 signature.span,
                 "no-misused-new",
                 "Interfaces cannot be constructed, only classes",
+                "Use `new (): T` to describe a construct signature",
               );
             }
           }
@@ -101,10 +103,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
             && self
               .match_parent_type(&n.id, &signature.type_ann.as_ref().unwrap())
           {
-            self.context.add_diagnostic(
+            self.context.add_diagnostic_with_hint(
               signature.span,
               "no-misused-new",
               "Interfaces cannot be constructed, only classes",
+              "Declare a class instead, or describe the constructor in a separate interface",
             );
           }
         }
@@ -135,10 +138,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
           )
         {
           // new
-          self.context.add_diagnostic(
+          self.context.add_diagnostic_with_hint(
             method.span,
             "no-misused-new",
             "Class cannot have method named `new`.",
+            "Use `constructor` to define the class constructor",
           );
         }
       }
@@ -260,4 +264,39 @@ declare abstract class C {
       4,
     )
   }
+
+  #[test]
+  fn no_misused_new_hint() {
+    assert_lint_err! {
+      NoMisusedNew,
+      "interface I { new (): I; }": [
+        {
+          col: 14,
+          message: "Interfaces cannot be constructed, only classes",
+          hint: "Declare a class instead, or describe the constructor in a separate interface",
+        }
+      ],
+      "interface I { constructor(): void; }": [
+        {
+          col: 14,
+          message: "Interfaces cannot be constructed, only classes",
+          hint: "Use `new (): T` to describe a construct signature",
+        }
+      ],
+      "type T = { constructor(): void; };": [
+        {
+          col: 11,
+          message: "Type aliases cannot be constructed, only classes",
+          hint: "Use `new (): T` to describe a construct signature",
+        }
+      ],
+      "class C { new(): C {} }": [
+        {
+          col: 10,
+          message: "Class cannot have method named `new`.",
+          hint: "Use `constructor` to define the class constructor",
+        }
+      ],
+    };
+  }
 }
@@ -2,6 +2,7 @@
 use super::Context;
 use super::LintRule;
 use regex::Regex;
+use serde::Deserialize;
 
 use swc_common::BytePos;
 use swc_common::Span;
@@ -14,13 +15,30 @@ use swc_ecmascript::visit::Visit;
 
 lazy_static! {
   static ref RE: Regex = Regex::new("^([\t ]*(\t | \t))").unwrap();
+  static ref SMART_TABS_RE: Regex = Regex::new("^([\t ]* \t)").unwrap();
 }
 
-pub struct NoMixedSpacesAndTabs;
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct NoMixedSpacesAndTabsOptions {
+  /// Allow spaces after tabs, which are used to align code while indenting
+  /// with tabs. Spaces followed by a tab are still reported.
+  pub smart_tabs: bool,
+}
+
+pub struct NoMixedSpacesAndTabs {
+  options: NoMixedSpacesAndTabsOptions,
+}
+
+impl NoMixedSpacesAndTabs {
+  pub fn with_options(options: NoMixedSpacesAndTabsOptions) -> Box<Self> {
+    Box::new(NoMixedSpacesAndTabs { options })
+  }
+}
 
 impl LintRule for NoMixedSpacesAndTabs {
   fn new() -> Box<Self> {
-    Box::new(NoMixedSpacesAndTabs)
+    Self::with_options(NoMixedSpacesAndTabsOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -71,11 +89,16 @@ impl LintRule for NoMixedSpacesAndTabs {
       }
     });
 
+    let re: &Regex = if self.options.smart_tabs {
+      &SMART_TABS_RE
+    } else {
+      &RE
+    };
     let excluded_ranges = excluded_ranges.iter();
     for line_index in 0..file.count_lines() {
       let line = file.get_line(line_index).unwrap();
       let (byte_pos, _hi) = file.line_bounds(line_index);
-      let whitespace_matches = RE.find_iter(&line);
+      let whitespace_matches = re.find_iter(&line);
       for whitespace_match in whitespace_matches {
         let range = whitespace_match.range();
         let span = Span::new(
@@ -121,6 +144,7 @@ impl Visit for NoMixedSpacesAndTabsVisitor {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn no_mixed_spaces_and_tabs_valid() {
@@ -184,4 +208,30 @@ mod tests {
     assert_lint_err_on_line::<NoMixedSpacesAndTabs>("  \t'';", 1, 0);
     assert_lint_err_on_line::<NoMixedSpacesAndTabs>("''\n\t ", 2, 0);
   }
+
+  #[test]
+  fn no_mixed_spaces_and_tabs_smart_tabs() {
+    assert_lint_ok_with_options! {
+      NoMixedSpacesAndTabs,
+      json!({ "smart-tabs": true }),
+      "function add(x, y) {\n\t return x + y;\n}",
+      "\tvar x = 5,\n\t    y = 7;",
+    };
+    assert_lint_err_with_options! {
+      NoMixedSpacesAndTabs,
+      json!({ "smart-tabs": true }),
+      " \tvar x = 5;": [
+        {
+          col: 0,
+          message: "Mixed spaces and tabs are not allowed.",
+        }
+      ],
+      "\t  \tvar x = 5;": [
+        {
+          col: 0,
+          message: "Mixed spaces and tabs are not allowed.",
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,154 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  ComputedPropName, Expr, Lit, MemberExpr, Module, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoNegZeroKey;
+
+impl LintRule for NoNegZeroKey {
+  fn new() -> Box<Self> {
+    Box::new(NoNegZeroKey)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-neg-zero-key"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoNegZeroKeyVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows using negative zero (`-0`) as a computed property key or index.
+
+Property keys are converted to strings, and `String(-0)` is `"0"`, so `obj[-0]` is the same as `obj[0]`. Using `-0` in these positions suggests that the distinction was expected to matter.
+
+### Invalid:
+```typescript
+const a = obj[-0];
+const b = { [-0]: "zero" };
+```
+
+### Valid:
+```typescript
+const a = obj[0];
+const b = { [0]: "zero" };
+```"#
+  }
+}
+
+struct NoNegZeroKeyVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoNegZeroKeyVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn check_key(&mut self, expr: &Expr) {
+    if let Expr::Unary(unary_expr) = expr {
+      if is_neg_zero(unary_expr) {
+        self.context.add_diagnostic_with_hint(
+          unary_expr.span,
+          "no-neg-zero-key",
+          "`-0` used as a property key is the same as `0`",
+          "Use `0` instead, or a `Map` with `Object.is` semantics if the sign matters",
+        );
+      }
+    }
+  }
+}
+
+fn is_neg_zero(unary_expr: &UnaryExpr) -> bool {
+  if let (UnaryOp::Minus, Expr::Lit(Lit::Num(number))) =
+    (unary_expr.op, &*unary_expr.arg)
+  {
+    return number.value == 0.0;
+  }
+  false
+}
+
+impl<'c> VisitAll for NoNegZeroKeyVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if member_expr.computed {
+      self.check_key(&member_expr.prop);
+    }
+  }
+
+  fn visit_computed_prop_name(
+    &mut self,
+    computed_prop_name: &ComputedPropName,
+    _parent: &dyn Node,
+  ) {
+    self.check_key(&computed_prop_name.expr);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_neg_zero_key_valid() {
+    assert_lint_ok! {
+      NoNegZeroKey,
+      "obj[0];",
+      "obj[-1];",
+      "obj[i];",
+      "const o = { [0]: 1 };",
+      "const o = { 0: 1 };",
+      "x = -0;",
+      "obj[x - 0];",
+      "Object.is(x, -0);",
+    };
+  }
+
+  #[test]
+  fn no_neg_zero_key_invalid() {
+    assert_lint_err! {
+      NoNegZeroKey,
+      "obj[-0];": [
+        {
+          col: 4,
+          message: "`-0` used as a property key is the same as `0`",
+          hint: "Use `0` instead, or a `Map` with `Object.is` semantics if the sign matters",
+        }
+      ],
+      "obj[-0] = 1;": [
+        {
+          col: 4,
+          message: "`-0` used as a property key is the same as `0`",
+          hint: "Use `0` instead, or a `Map` with `Object.is` semantics if the sign matters",
+        }
+      ],
+      "const o = { [-0.0]: 1 };": [
+        {
+          col: 13,
+          message: "`-0` used as a property key is the same as `0`",
+          hint: "Use `0` instead, or a `Map` with `Object.is` semantics if the sign matters",
+        }
+      ],
+      "class A { [-0]() {} }": [
+        {
+          col: 11,
+          message: "`-0` used as a property key is the same as `0`",
+          hint: "Use `0` instead, or a `Map` with `Object.is` semantics if the sign matters",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,152 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::DropSpan;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{Module, TsIntersectionType, TsType, TsUnionType};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+/// Syntactic subset of typescript-eslint's `no-redundant-type-constituents`:
+/// only exact duplicates are reported, no type information is used.
+pub struct NoDuplicateTypeConstituents;
+
+impl LintRule for NoDuplicateTypeConstituents {
+  fn new() -> Box<Self> {
+    Box::new(NoDuplicateTypeConstituents)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-duplicate-type-constituents"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoDuplicateTypeConstituentsVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows duplicate constituents in union and intersection types.
+
+Repeating the same type in a union or an intersection has no effect and is usually a copy-paste mistake.
+
+### Invalid:
+```typescript
+type T = string | string;
+type U = { a: string } & { a: string };
+function foo(x: number | string | number) {}
+```
+
+### Valid:
+```typescript
+type T = string | number;
+type U = { a: string } & { b: string };
+```"#
+  }
+}
+
+struct NoDuplicateTypeConstituentsVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDuplicateTypeConstituentsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn check_types(&mut self, types: &[Box<TsType>], kind: &str) {
+    let mut seen: Vec<TsType> = Vec::with_capacity(types.len());
+    for ty in types {
+      let span_dropped = (**ty).clone().drop_span();
+      if seen.contains(&span_dropped) {
+        let span = ty.span();
+        let snippet = self
+          .context
+          .source_map
+          .span_to_snippet(span)
+          .expect("Failed to get snippet of type");
+        self.context.add_diagnostic_with_hint(
+          span,
+          "no-duplicate-type-constituents",
+          format!("`{}` is duplicated in the {} type", snippet, kind),
+          "Remove the duplicate constituent",
+        );
+      } else {
+        seen.push(span_dropped);
+      }
+    }
+  }
+}
+
+impl<'c> VisitAll for NoDuplicateTypeConstituentsVisitor<'c> {
+  fn visit_ts_union_type(&mut self, union: &TsUnionType, _parent: &dyn Node) {
+    self.check_types(&union.types, "union");
+  }
+
+  fn visit_ts_intersection_type(
+    &mut self,
+    intersection: &TsIntersectionType,
+    _parent: &dyn Node,
+  ) {
+    self.check_types(&intersection.types, "intersection");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_type_constituents_valid() {
+    assert_lint_ok! {
+      NoDuplicateTypeConstituents,
+      "type U = string | number;",
+      "type U = string | string[];",
+      "type U = { a: string } & { b: string };",
+      "type U = Foo<string> | Foo<number>;",
+      "type U = 'a' | 'b';",
+      "type U = string | (string | number)[];",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_type_constituents_invalid() {
+    assert_lint_err! {
+      NoDuplicateTypeConstituents,
+      "type T = string | string;": [
+        {
+          col: 18,
+          message: "`string` is duplicated in the union type",
+          hint: "Remove the duplicate constituent",
+        }
+      ],
+      "function foo(x: number | string | number) {}": [
+        {
+          col: 34,
+          message: "`number` is duplicated in the union type",
+          hint: "Remove the duplicate constituent",
+        }
+      ],
+      "type T = { a: string } & { a: string };": [
+        {
+          col: 25,
+          message: "`{ a: string }` is duplicated in the intersection type",
+          hint: "Remove the duplicate constituent",
+        }
+      ],
+      "type T = 'a' | Foo<'a'> | 'a' | Foo<'a'>;": [
+        {
+          col: 26,
+          message: "`'a'` is duplicated in the union type",
+          hint: "Remove the duplicate constituent",
+        },
+        {
+          col: 32,
+          message: "`Foo<'a'>` is duplicated in the union type",
+          hint: "Remove the duplicate constituent",
+        }
+      ],
+    };
+  }
+}
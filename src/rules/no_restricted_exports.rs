@@ -0,0 +1,304 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  Decl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportSpecifier,
+  Ident, Module, NamedExport,
+};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedExportsOptions {
+  /// Names which must not be exported. `"default"` restricts
+  /// `export { x as default }`.
+  pub restricted_named_exports: Vec<String>,
+  /// Disallow default exports of any form.
+  pub restrict_default_exports: bool,
+}
+
+pub struct NoRestrictedExports {
+  options: NoRestrictedExportsOptions,
+}
+
+impl NoRestrictedExports {
+  pub fn with_options(options: NoRestrictedExportsOptions) -> Box<Self> {
+    Box::new(NoRestrictedExports { options })
+  }
+}
+
+impl LintRule for NoRestrictedExports {
+  fn new() -> Box<Self> {
+    Self::with_options(NoRestrictedExportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-restricted-exports"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoRestrictedExportsVisitor::new(context, &self.options);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows exporting specific names from a module.
+
+The `restrictedNamedExports` option lists the names which must not be exported. Including `"default"` restricts `export { x as default }`. The `restrictDefaultExports` option (default `false`) disallows default exports altogether.
+
+### Invalid (`{ "restrictedNamedExports": ["foo"] }`):
+```typescript
+export const foo = 1;
+export function foo() {}
+const bar = 1;
+export { bar as foo };
+export { foo } from "./mod.ts";
+```
+
+### Valid (`{ "restrictedNamedExports": ["foo"] }`):
+```typescript
+export const bar = 1;
+const foo = 1;
+export { foo as bar };
+```"#
+  }
+}
+
+struct NoRestrictedExportsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoRestrictedExportsOptions,
+}
+
+impl<'c, 'o> NoRestrictedExportsVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoRestrictedExportsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_exported_name(&mut self, ident: &Ident) {
+    if ident.sym == *"default" && self.options.restrict_default_exports {
+      self.report_default_export(ident.span);
+    } else if self
+      .options
+      .restricted_named_exports
+      .iter()
+      .any(|name| ident.sym == **name)
+    {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        "no-restricted-exports",
+        format!(
+          "'{}' is restricted from being used as an exported name",
+          ident.sym
+        ),
+        "Rename the export, or export it under a different name",
+      );
+    }
+  }
+
+  fn report_default_export(&mut self, span: Span) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      "no-restricted-exports",
+      "Default exports are restricted",
+      "Use a named export instead",
+    );
+  }
+}
+
+impl<'c, 'o> Visit for NoRestrictedExportsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_export_decl(
+    &mut self,
+    export_decl: &ExportDecl,
+    _parent: &dyn Node,
+  ) {
+    match &export_decl.decl {
+      Decl::Class(class_decl) => self.check_exported_name(&class_decl.ident),
+      Decl::Fn(fn_decl) => self.check_exported_name(&fn_decl.ident),
+      Decl::TsEnum(ts_enum_decl) => self.check_exported_name(&ts_enum_decl.id),
+      Decl::Var(var_decl) => {
+        for decl in &var_decl.decls {
+          let idents: Vec<Ident> = find_ids(&decl.name);
+          for ident in &idents {
+            self.check_exported_name(ident);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_named_export(
+    &mut self,
+    named_export: &NamedExport,
+    _parent: &dyn Node,
+  ) {
+    for specifier in &named_export.specifiers {
+      match specifier {
+        ExportSpecifier::Named(named) => self
+          .check_exported_name(named.exported.as_ref().unwrap_or(&named.orig)),
+        ExportSpecifier::Namespace(namespace) => {
+          self.check_exported_name(&namespace.name)
+        }
+        ExportSpecifier::Default(default) => {
+          self.check_exported_name(&default.exported)
+        }
+      }
+    }
+  }
+
+  fn visit_export_default_decl(
+    &mut self,
+    export_default_decl: &ExportDefaultDecl,
+    _parent: &dyn Node,
+  ) {
+    if self.options.restrict_default_exports {
+      self.report_default_export(export_default_decl.span);
+    }
+  }
+
+  fn visit_export_default_expr(
+    &mut self,
+    export_default_expr: &ExportDefaultExpr,
+    _parent: &dyn Node,
+  ) {
+    if self.options.restrict_default_exports {
+      self.report_default_export(export_default_expr.span);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_restricted_exports_valid() {
+    assert_lint_ok! {
+      NoRestrictedExports,
+      "export const foo = 1;",
+      "export default 1;",
+    };
+    assert_lint_ok_with_options! {
+      NoRestrictedExports,
+      json!({ "restrictedNamedExports": ["foo"] }),
+      "export const bar = 1;",
+      "const foo = 1; export { foo as bar };",
+      "export { bar } from './mod.ts';",
+      "import { foo } from './mod.ts';",
+      "export default function foo() {}",
+    };
+  }
+
+  #[test]
+  fn no_restricted_exports_invalid() {
+    assert_lint_err_with_options! {
+      NoRestrictedExports,
+      json!({ "restrictedNamedExports": ["foo"] }),
+      "export const foo = 1;": [
+        {
+          col: 13,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "export const { a: [foo] } = obj;": [
+        {
+          col: 19,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "export function foo() {}": [
+        {
+          col: 16,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "export class foo {}": [
+        {
+          col: 13,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "const bar = 1; export { bar as foo };": [
+        {
+          col: 31,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "export { foo } from './mod.ts';": [
+        {
+          col: 9,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+      "export * as foo from './mod.ts';": [
+        {
+          col: 12,
+          message: "'foo' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      NoRestrictedExports,
+      json!({ "restrictedNamedExports": ["default"] }),
+      "const a = 1; export { a as default };": [
+        {
+          col: 27,
+          message: "'default' is restricted from being used as an exported name",
+          hint: "Rename the export, or export it under a different name",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_restricted_exports_default() {
+    assert_lint_ok_with_options! {
+      NoRestrictedExports,
+      json!({ "restrictDefaultExports": true }),
+      "export const a = 1;",
+    };
+    assert_lint_err_with_options! {
+      NoRestrictedExports,
+      json!({ "restrictDefaultExports": true }),
+      "export default 1;": [
+        {
+          col: 0,
+          message: "Default exports are restricted",
+          hint: "Use a named export instead",
+        }
+      ],
+      "export default function () {}": [
+        {
+          col: 0,
+          message: "Default exports are restricted",
+          hint: "Use a named export instead",
+        }
+      ],
+      "const a = 1; export { a as default };": [
+        {
+          col: 27,
+          message: "Default exports are restricted",
+          hint: "Use a named export instead",
+        }
+      ],
+    };
+  }
+}
@@ -3,6 +3,7 @@ use super::Context;
 use super::LintRule;
 use crate::swc_util::Key;
 
+use serde::Deserialize;
 use swc_common::Span;
 use swc_ecmascript::ast::AssignExpr;
 use swc_ecmascript::ast::AssignOp;
@@ -20,11 +21,33 @@ use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct NoSelfAssign;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoSelfAssignOptions {
+  /// Also reports assignments of properties to themselves, as in
+  /// `foo.bar = foo.bar`.
+  pub props: bool,
+}
+
+impl Default for NoSelfAssignOptions {
+  fn default() -> Self {
+    NoSelfAssignOptions { props: true }
+  }
+}
+
+pub struct NoSelfAssign {
+  options: NoSelfAssignOptions,
+}
+
+impl NoSelfAssign {
+  pub fn with_options(options: NoSelfAssignOptions) -> Box<Self> {
+    Box::new(NoSelfAssign { options })
+  }
+}
 
 impl LintRule for NoSelfAssign {
   fn new() -> Box<Self> {
-    Box::new(NoSelfAssign)
+    Self::with_options(NoSelfAssignOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -40,18 +63,19 @@ impl LintRule for NoSelfAssign {
     context: &mut Context,
     module: &swc_ecmascript::ast::Module,
   ) {
-    let mut visitor = NoSelfAssignVisitor::new(context);
+    let mut visitor = NoSelfAssignVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
   }
 }
 
-struct NoSelfAssignVisitor<'c> {
+struct NoSelfAssignVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoSelfAssignOptions,
 }
 
-impl<'c> NoSelfAssignVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> NoSelfAssignVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoSelfAssignOptions) -> Self {
+    Self { context, options }
   }
 
   fn add_diagnostic(&mut self, span: Span, name: impl AsRef<str>) {
@@ -127,7 +151,7 @@ impl<'c> NoSelfAssignVisitor<'c> {
   }
 
   fn check_same_member(&mut self, left: &MemberExpr, right: &MemberExpr) {
-    if self.is_same_member(left, right) {
+    if self.options.props && self.is_same_member(left, right) {
       let name = (&*right.prop).get_key().expect("Should be identifier");
       self.add_diagnostic(right.span, name);
     }
@@ -268,7 +292,7 @@ impl<'c> NoSelfAssignVisitor<'c> {
   }
 }
 
-impl<'c> Visit for NoSelfAssignVisitor<'c> {
+impl<'c, 'o> Visit for NoSelfAssignVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_assign_expr(
@@ -293,6 +317,7 @@ impl<'c> Visit for NoSelfAssignVisitor<'c> {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn no_self_assign_valid() {
@@ -332,6 +357,7 @@ mod tests {
       "this.x = this.y",
       "this.x = options.x",
       "this.name = this.constructor.name",
+      "x = y",
     };
   }
 
@@ -356,7 +382,7 @@ mod tests {
     assert_lint_err::<NoSelfAssign>("({'a': b} = {[`a`]: b})", 20);
     assert_lint_err::<NoSelfAssign>("({1: b} = {[1]: b})", 16);
     assert_lint_err_n::<NoSelfAssign>("({a, b} = {a, b})", vec![11, 14]);
-    assert_lint_err_n::<NoSelfAssign>("({a, b} = {b, a})", vec![14, 11]);
+    assert_lint_err_n::<NoSelfAssign>("({a, b} = {b, a})", vec![11, 14]);
     assert_lint_err::<NoSelfAssign>("({a, b} = {c, a})", 14);
     assert_lint_err_n::<NoSelfAssign>(
       "({a: {b}, c: [d]} = {a: {b}, c: [d]})",
@@ -375,4 +401,42 @@ mod tests {
     assert_lint_err::<NoSelfAssign>("this.x = this.x", 9);
     assert_lint_err::<NoSelfAssign>("a['/(?<zero>0)/'] = a[/(?<zero>0)/]", 20);
   }
+
+  #[test]
+  fn no_self_assign_props() {
+    assert_lint_ok_with_options! {
+      NoSelfAssign,
+      json!({ "props": false }),
+      "a.b = a.b",
+      "a[b] = a[b]",
+      "this.x = this.x",
+      "[a.b] = [a.b]",
+    };
+    assert_lint_err_with_options! {
+      NoSelfAssign,
+      json!({ "props": false }),
+      "x = x": [
+        {
+          col: 4,
+          message: "\"x\" is assigned to itself",
+        }
+      ],
+      "[a, this.b] = [a, this.b]": [
+        {
+          col: 15,
+          message: "\"a\" is assigned to itself",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      NoSelfAssign,
+      json!({ "props": true }),
+      "foo.bar = foo.bar": [
+        {
+          col: 10,
+          message: "\"bar\" is assigned to itself",
+        }
+      ],
+    };
+  }
 }
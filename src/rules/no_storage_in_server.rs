@@ -0,0 +1,149 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::no_window::GlobalRefVisitor;
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::Module;
+use swc_ecmascript::visit::Visit;
+
+static STORAGE_GLOBALS: &[&str] = &["localStorage", "sessionStorage"];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoStorageInServerOptions {
+  /// Storage globals which may be used, as in `"sessionStorage"`.
+  pub allow: Vec<String>,
+}
+
+pub struct NoStorageInServer {
+  options: NoStorageInServerOptions,
+}
+
+impl NoStorageInServer {
+  pub fn with_options(options: NoStorageInServerOptions) -> Box<Self> {
+    Box::new(NoStorageInServer { options })
+  }
+}
+
+impl LintRule for NoStorageInServer {
+  fn new() -> Box<Self> {
+    Self::with_options(NoStorageInServerOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "no-browser-storage"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let options = &self.options;
+    let mut visitor = GlobalRefVisitor::new(context, |context, ident| {
+      if STORAGE_GLOBALS.contains(&&*ident.sym)
+        && !options.allow.iter().any(|name| ident.sym == **name)
+      {
+        context.add_diagnostic_with_hint(
+          ident.span,
+          "no-browser-storage",
+          format!("`{}` should not be used in server code", ident.sym),
+          "Store the data in a database or a cookie instead",
+        );
+      }
+    });
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the global `localStorage` and `sessionStorage`.
+
+Web Storage is a browser API. In server code it's shared by every request the
+process handles, which makes it unsuitable for storing per-user data. Store
+such data in a database or a cookie instead.
+
+`typeof localStorage` checks are allowed, since they are used to detect the
+environment. The `allow` option lists the storage globals which may be used.
+
+### Invalid:
+```typescript
+const token = localStorage.getItem("token");
+sessionStorage.setItem("user", id);
+```
+
+### Valid:
+```typescript
+const token = await db.get("token");
+if (typeof localStorage !== "undefined") {}
+```"#
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_storage_in_server_valid() {
+    assert_lint_ok! {
+      NoStorageInServer,
+      "const localStorage = new Map(); localStorage.get('x');",
+      "function f(sessionStorage) { return sessionStorage.getItem('x'); }",
+      "foo.localStorage.getItem('x');",
+      "const o = { localStorage: 1 };",
+      "if (typeof localStorage !== 'undefined') {}",
+    };
+  }
+
+  #[test]
+  fn no_storage_in_server_invalid() {
+    assert_lint_err! {
+      NoStorageInServer,
+      r#"localStorage.getItem("x");"#: [
+        {
+          col: 0,
+          message: "`localStorage` should not be used in server code",
+          hint: "Store the data in a database or a cookie instead",
+        }
+      ],
+      "sessionStorage['user'] = id;": [
+        {
+          col: 0,
+          message: "`sessionStorage` should not be used in server code",
+          hint: "Store the data in a database or a cookie instead",
+        }
+      ],
+      "const storage = { localStorage };": [
+        {
+          col: 18,
+          message: "`localStorage` should not be used in server code",
+          hint: "Store the data in a database or a cookie instead",
+        }
+      ],
+      "function f() { return localStorage; }": [
+        {
+          col: 22,
+          message: "`localStorage` should not be used in server code",
+          hint: "Store the data in a database or a cookie instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_storage_in_server_allow() {
+    assert_lint_ok_with_options! {
+      NoStorageInServer,
+      json!({ "allow": ["sessionStorage"] }),
+      "sessionStorage.getItem('x');",
+    };
+    assert_lint_err_with_options! {
+      NoStorageInServer,
+      json!({ "allow": ["sessionStorage"] }),
+      "localStorage.getItem('x');": [
+        {
+          col: 0,
+          message: "`localStorage` should not be used in server code",
+          hint: "Store the data in a database or a cookie instead",
+        }
+      ],
+    };
+  }
+}
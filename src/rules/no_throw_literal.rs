@@ -25,6 +25,27 @@ impl LintRule for NoThrowLiteral {
     let mut visitor = NoThrowLiteralVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows throwing values which can't be error objects.
+
+Error objects capture a stack trace where they are created, which makes the thrown value much easier to debug than a string or a plain object. Identifiers, calls and `new` expressions may evaluate to errors, so they are allowed.
+
+### Invalid:
+```typescript
+throw "oops";
+throw `Failed to load ${url}`;
+throw { code: 1 };
+throw undefined;
+```
+
+### Valid:
+```typescript
+throw new Error("oops");
+throw err;
+throw createError();
+```"#
+  }
 }
 
 struct NoThrowLiteralVisitor<'c> {
@@ -41,17 +62,25 @@ impl<'c> Visit for NoThrowLiteralVisitor<'c> {
   noop_visit_type!();
 
   fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
-    match &*throw_stmt.arg {
-      Expr::Lit(_) => self.context.add_diagnostic(
-        throw_stmt.span,
-        "no-throw-literal",
-        "expected an error object to be thrown",
-      ),
+    let mut arg = &*throw_stmt.arg;
+    while let Expr::Paren(paren) = arg {
+      arg = &paren.expr;
+    }
+    match arg {
+      Expr::Lit(_) | Expr::Tpl(_) | Expr::Object(_) => {
+        self.context.add_diagnostic_with_hint(
+          throw_stmt.span,
+          "no-throw-literal",
+          "expected an error object to be thrown",
+          "Throw an error object instead, as in `throw new Error(...)`",
+        )
+      }
       Expr::Ident(ident) if ident.sym == *"undefined" => {
-        self.context.add_diagnostic(
+        self.context.add_diagnostic_with_hint(
           throw_stmt.span,
           "no-throw-literal",
           "do not throw undefined",
+          "Throw an error object instead, as in `throw new Error(...)`",
         )
       }
       _ => {}
@@ -69,6 +98,15 @@ mod tests {
     assert_lint_ok! {
       NoThrowLiteral,
       "throw e",
+      "throw new Error()",
+      "throw new Error('kumiko')",
+      "throw Error('kumiko')",
+      "throw createError()",
+      "throw foo.error",
+      "throw this",
+      "class Foo { bar() { throw this; } }",
+      "function foo() { try { bar(); } catch (e) { throw e; } }",
+      "throw tag`kumiko`",
     };
   }
 
@@ -79,5 +117,43 @@ mod tests {
     assert_lint_err::<NoThrowLiteral>("throw 1096", 0);
     assert_lint_err::<NoThrowLiteral>("throw null", 0);
     assert_lint_err::<NoThrowLiteral>("throw undefined", 0);
+    assert_lint_err! {
+      NoThrowLiteral,
+      "throw \"oops\"": [
+        {
+          col: 0,
+          message: "expected an error object to be thrown",
+          hint: "Throw an error object instead, as in `throw new Error(...)`",
+        }
+      ],
+      "throw `oops ${reina}`": [
+        {
+          col: 0,
+          message: "expected an error object to be thrown",
+          hint: "Throw an error object instead, as in `throw new Error(...)`",
+        }
+      ],
+      "throw { code: 1 }": [
+        {
+          col: 0,
+          message: "expected an error object to be thrown",
+          hint: "Throw an error object instead, as in `throw new Error(...)`",
+        }
+      ],
+      "function foo() { throw ('kumiko'); }": [
+        {
+          col: 17,
+          message: "expected an error object to be thrown",
+          hint: "Throw an error object instead, as in `throw new Error(...)`",
+        }
+      ],
+      "throw undefined": [
+        {
+          col: 0,
+          message: "do not throw undefined",
+          hint: "Throw an error object instead, as in `throw new Error(...)`",
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,307 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::{hygiene::SyntaxContext, BytePos, Span, Spanned};
+use swc_ecmascript::ast::{CallExpr, ExprOrSuper, MemberExpr, TaggedTpl};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoUnexpectedMultiline;
+
+impl LintRule for NoUnexpectedMultiline {
+  fn new() -> Box<Self> {
+    Box::new(NoUnexpectedMultiline)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-unexpected-multiline"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = NoUnexpectedMultilineVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows line breaks that are joined into the previous expression.
+
+Automatic semicolon insertion doesn't end a statement at a line break when the
+next line starts with `(`, `[` or `` ` ``, so the next line continues the
+previous expression as a function call, a property access or a tagged template.
+
+### Invalid:
+```typescript
+const a = b
+(x || y).forEach(f);
+
+const c = d
+[1, 2].forEach(f);
+
+const e = tag
+`hello`;
+```
+
+### Valid:
+```typescript
+const a = b;
+(x || y).forEach(f);
+
+const c = d;
+[1, 2].forEach(f);
+
+const e = tag`hello`;
+```"#
+  }
+}
+
+struct NoUnexpectedMultilineVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUnexpectedMultilineVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Reports if the first token between `lo` and `hi` is `token` and a line
+  /// break precedes it.
+  fn check_break_before(
+    &mut self,
+    lo: BytePos,
+    hi: BytePos,
+    token: char,
+    message: &str,
+  ) {
+    if lo >= hi {
+      return;
+    }
+    let text = match self.context.source_map.span_to_snippet(Span::new(
+      lo,
+      hi,
+      SyntaxContext::empty(),
+    )) {
+      Ok(text) => text,
+      Err(_) => return,
+    };
+    let (offset, has_line_break) = skip_trivia(&text);
+    if has_line_break && text[offset..].starts_with(token) {
+      let token_lo = lo + BytePos(offset as u32);
+      self.context.add_diagnostic_with_hint(
+        Span::new(
+          token_lo,
+          token_lo + BytePos(token.len_utf8() as u32),
+          SyntaxContext::empty(),
+        ),
+        "no-unexpected-multiline",
+        message,
+        "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+      );
+    }
+  }
+}
+
+fn is_line_terminator(c: char) -> bool {
+  matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// Skips the whitespace and comments at the start of `text`, returning the
+/// byte offset of the first token and whether a line break was skipped.
+fn skip_trivia(text: &str) -> (usize, bool) {
+  let mut has_line_break = false;
+  let mut offset = 0;
+  while offset < text.len() {
+    let rest = &text[offset..];
+    if rest.starts_with("//") {
+      match rest.find(is_line_terminator) {
+        Some(end) => offset += end,
+        None => return (text.len(), has_line_break),
+      }
+    } else if rest.starts_with("/*") {
+      match rest[2..].find("*/") {
+        Some(end) => {
+          has_line_break |= rest[..end + 2].contains(is_line_terminator);
+          offset += end + 4;
+        }
+        None => return (text.len(), has_line_break),
+      }
+    } else {
+      match rest.chars().next() {
+        Some(c) if c.is_whitespace() || c == '\u{feff}' => {
+          has_line_break |= is_line_terminator(c);
+          offset += c.len_utf8();
+        }
+        _ => break,
+      }
+    }
+  }
+  (offset, has_line_break)
+}
+
+impl<'c> Visit for NoUnexpectedMultilineVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      let lo = match &call_expr.type_args {
+        Some(type_args) => type_args.span.hi,
+        None => callee.span().hi,
+      };
+      // Without arguments, the `(` is the first token after the callee.
+      let hi = match call_expr.args.first() {
+        Some(first_arg) => first_arg.span().lo,
+        None => call_expr.span.hi,
+      };
+      self.check_break_before(
+        lo,
+        hi,
+        '(',
+        "Unexpected newline between function and `(` of function call",
+      );
+    }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, parent: &dyn Node) {
+    if member_expr.computed {
+      self.check_break_before(
+        member_expr.obj.span().hi,
+        member_expr.prop.span().lo,
+        '[',
+        "Unexpected newline between object and `[` of property access",
+      );
+    }
+    swc_ecmascript::visit::visit_member_expr(self, member_expr, parent);
+  }
+
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, parent: &dyn Node) {
+    let lo = match &tagged_tpl.type_params {
+      Some(type_params) => type_params.span.hi,
+      None => tagged_tpl.tag.span().hi,
+    };
+    self.check_break_before(
+      lo,
+      tagged_tpl.span.hi,
+      '`',
+      "Unexpected newline between template tag and template literal",
+    );
+    swc_ecmascript::visit::visit_tagged_tpl(self, tagged_tpl, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/no-unexpected-multiline.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_unexpected_multiline_valid() {
+    assert_lint_ok! {
+      NoUnexpectedMultiline,
+      "var a = b(x);",
+      "var a = b;\n(x);",
+      "var a = b\n;(x);",
+      "var a = b[x];",
+      "var a = b;\n[x];",
+      "var a = b\n.c[x];",
+      "var a = tag`hello`;",
+      "var a = b;\n`hello`;",
+      "var a = (b)(x);",
+      "var a = b(\n  x\n);",
+      "var a = b[\n  x\n];",
+      "var a = b\n  ? c\n  : d;",
+      "var a = b();\n()=>{};",
+      "var a = b?.\n(x);",
+      "var a = b?.\n[x];",
+      "var a = b /* (\n */;(x);",
+      "var a = f<T>(x);",
+    };
+  }
+
+  #[test]
+  fn no_unexpected_multiline_invalid() {
+    assert_lint_err! {
+      NoUnexpectedMultiline,
+      "var a = b\n(x);": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = b\n[x];": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between object and `[` of property access",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = tag\n`hello`;": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between template tag and template literal",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = b\n  (x || y).forEach(f);": [
+        {
+          line: 2,
+          col: 2,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = (b) // comment\n(x);": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = b /*\n*/ (x);": [
+        {
+          line: 2,
+          col: 3,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "const a = b\n()": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = b\n();": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between function and `(` of function call",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+      "var a = b.c\n[1, 2].forEach(f);": [
+        {
+          line: 2,
+          col: 0,
+          message: "Unexpected newline between object and `[` of property access",
+          hint: "Add a semicolon at the end of the previous line if this is meant to be a separate statement",
+        }
+      ],
+    };
+  }
+}
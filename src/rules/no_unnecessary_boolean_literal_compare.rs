@@ -0,0 +1,175 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{BinExpr, BinaryOp, Bool, Expr, Lit, Module};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUnnecessaryBooleanLiteralCompare;
+
+impl LintRule for NoUnnecessaryBooleanLiteralCompare {
+  fn new() -> Box<Self> {
+    Box::new(NoUnnecessaryBooleanLiteralCompare)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-unnecessary-boolean-literal-compare"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoUnnecessaryBooleanLiteralCompareVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows comparing a boolean value against a boolean literal.
+
+`x === true` is the same as `x`, and `x === false` is the same as `!x`, as long as `x` is a boolean.
+
+Only strict comparisons (`===` and `!==`) are checked, since loose comparisons with boolean literals coerce their operands. Without type information this rule can't know whether the operand is a boolean, so make sure it is before simplifying.
+
+### Invalid:
+```typescript
+if (isReady === true) {}
+if (isReady !== false) {}
+if (isReady === false) {}
+```
+
+### Valid:
+```typescript
+if (isReady) {}
+if (!isReady) {}
+if (count === 1) {}
+```"#
+  }
+}
+
+struct NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+fn as_bool_lit(expr: &Expr) -> Option<bool> {
+  if let Expr::Lit(Lit::Bool(Bool { value, .. })) = expr {
+    Some(*value)
+  } else {
+    None
+  }
+}
+
+/// Checks if `!` can be prepended to `expr` without adding parentheses.
+fn can_negate_directly(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Ident(_)
+      | Expr::This(_)
+      | Expr::Member(_)
+      | Expr::Call(_)
+      | Expr::Paren(_)
+      | Expr::Unary(_)
+  )
+}
+
+impl<'c> VisitAll for NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    let negated = match bin_expr.op {
+      BinaryOp::EqEqEq => false,
+      BinaryOp::NotEqEq => true,
+      _ => return,
+    };
+
+    let (operand, value) =
+      match (as_bool_lit(&bin_expr.left), as_bool_lit(&bin_expr.right)) {
+        (None, Some(value)) => (&bin_expr.left, value),
+        (Some(value), None) => (&bin_expr.right, value),
+        _ => return,
+      };
+
+    let snippet = self
+      .context
+      .source_map
+      .span_to_snippet(operand.span())
+      .expect("Failed to get snippet of operand");
+    let replacement = if value != negated {
+      snippet
+    } else if can_negate_directly(operand) {
+      format!("!{}", snippet)
+    } else {
+      format!("!({})", snippet)
+    };
+
+    self.context.add_diagnostic_with_hint(
+      bin_expr.span,
+      "no-unnecessary-boolean-literal-compare",
+      "This comparison with a boolean literal is unnecessary",
+      format!(
+        "If the operand is always a boolean, replace the comparison with `{}`",
+        replacement
+      ),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "x === 1;",
+      "if (x) {}",
+      "if (!x) {}",
+      "x == true;",
+      "x != false;",
+      "true === false;",
+      "x === y;",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "x === true;": [
+        {
+          col: 0,
+          message: "This comparison with a boolean literal is unnecessary",
+          hint: "If the operand is always a boolean, replace the comparison with `x`",
+        }
+      ],
+      "if (x !== false) {}": [
+        {
+          col: 4,
+          message: "This comparison with a boolean literal is unnecessary",
+          hint: "If the operand is always a boolean, replace the comparison with `x`",
+        }
+      ],
+      "foo.bar() === false;": [
+        {
+          col: 0,
+          message: "This comparison with a boolean literal is unnecessary",
+          hint: "If the operand is always a boolean, replace the comparison with `!foo.bar()`",
+        }
+      ],
+      "true !== a instanceof B;": [
+        {
+          col: 0,
+          message: "This comparison with a boolean literal is unnecessary",
+          hint: "If the operand is always a boolean, replace the comparison with `!(a instanceof B)`",
+        }
+      ],
+    };
+  }
+}
@@ -1,10 +1,15 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::swc_util::{visit_function_depth, FunctionDepth};
+use swc_atoms::JsWord;
 use swc_common::Span;
-use swc_ecmascript::ast::Module;
-use swc_ecmascript::ast::Stmt::{Break, Continue, Return, Throw};
-use swc_ecmascript::ast::TryStmt;
-use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use swc_ecmascript::ast::{
+  BreakStmt, ContinueStmt, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt,
+  LabeledStmt, Module, ReturnStmt, SwitchStmt, ThrowStmt, TryStmt, WhileStmt,
+};
+use swc_ecmascript::visit::{
+  noop_visit_type, Node, Visit, VisitAll, VisitAllWith, VisitWith,
+};
 
 pub struct NoUnsafeFinally;
 
@@ -65,6 +70,20 @@ let foo = function() {
     console.log("hola!");
   }
 };
+```
+```typescript
+let foo = function() {
+  try {
+    return 1;
+  } finally {
+    const bar = () => {
+      return 2;
+    };
+    for (const x of xs) {
+      break;
+    }
+  }
+};
 ```"#
   }
 }
@@ -92,19 +111,125 @@ impl<'c> VisitAll for NoUnsafeFinallyVisitor<'c> {
 
   fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
     if let Some(finally_block) = &try_stmt.finalizer {
-      for stmt in &finally_block.stmts {
-        match stmt {
-          Break(_) => self.add_diagnostic(finally_block.span, "Break"),
-          Continue(_) => self.add_diagnostic(finally_block.span, "Continue"),
-          Return(_) => self.add_diagnostic(finally_block.span, "Return"),
-          Throw(_) => self.add_diagnostic(finally_block.span, "Throw"),
-          _ => {}
-        }
+      let mut finally_visitor = FinallyVisitor::default();
+      finally_block.visit_children_with(&mut finally_visitor);
+      for stmt_type in finally_visitor.unsafe_stmts {
+        self.add_diagnostic(finally_block.span, stmt_type);
       }
     }
   }
 }
 
+/// Finds the control flow statements of a `finally` block which jump out of
+/// it. Statements in nested functions belong to those functions, and the ones
+/// in nested `finally` blocks are reported for those blocks instead.
+#[derive(Default)]
+struct FinallyVisitor {
+  function_depth: FunctionDepth,
+  /// Number of enclosing loops within the `finally` block.
+  loop_depth: usize,
+  /// Number of enclosing `switch` statements within the `finally` block.
+  switch_depth: usize,
+  /// Labels declared within the `finally` block.
+  labels: Vec<JsWord>,
+  unsafe_stmts: Vec<&'static str>,
+}
+
+impl FinallyVisitor {
+  fn report(&mut self, stmt_type: &'static str) {
+    if self.function_depth.depth() == 0 {
+      self.unsafe_stmts.push(stmt_type);
+    }
+  }
+
+  fn has_label(&self, label: &JsWord) -> bool {
+    self.labels.iter().any(|l| l == label)
+  }
+
+  fn visit_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+    self.loop_depth += 1;
+    visit(self);
+    self.loop_depth -= 1;
+  }
+}
+
+impl Visit for FinallyVisitor {
+  noop_visit_type!();
+
+  visit_function_depth!(function_depth);
+
+  fn visit_return_stmt(&mut self, _return_stmt: &ReturnStmt, _: &dyn Node) {
+    self.report("Return");
+  }
+
+  fn visit_throw_stmt(&mut self, _throw_stmt: &ThrowStmt, _: &dyn Node) {
+    self.report("Throw");
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _: &dyn Node) {
+    let is_safe = match &break_stmt.label {
+      Some(label) => self.has_label(&label.sym),
+      None => self.loop_depth > 0 || self.switch_depth > 0,
+    };
+    if !is_safe {
+      self.report("Break");
+    }
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _: &dyn Node,
+  ) {
+    let is_safe = match &continue_stmt.label {
+      Some(label) => self.has_label(&label.sym),
+      None => self.loop_depth > 0,
+    };
+    if !is_safe {
+      self.report("Continue");
+    }
+  }
+
+  fn visit_labeled_stmt(&mut self, labeled_stmt: &LabeledStmt, _: &dyn Node) {
+    self.labels.push(labeled_stmt.label.sym.clone());
+    labeled_stmt.visit_children_with(self);
+    self.labels.pop();
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
+    self.switch_depth += 1;
+    switch_stmt.visit_children_with(self);
+    self.switch_depth -= 1;
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    self.visit_loop(|v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    self.visit_loop(|v| for_in_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
+    self.visit_loop(|v| for_of_stmt.visit_children_with(v));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    self.visit_loop(|v| while_stmt.visit_children_with(v));
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt, _: &dyn Node) {
+    self.visit_loop(|v| do_while_stmt.visit_children_with(v));
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _: &dyn Node) {
+    try_stmt.block.visit_with(try_stmt, self);
+    if let Some(handler) = &try_stmt.handler {
+      handler.visit_with(try_stmt, self);
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -154,6 +279,16 @@ let foo = function(a) {
   }
 };
       "#,
+      "try {} finally { () => { return; }; }",
+      "try {} finally { const f = () => { throw new Error(); }; }",
+      "try {} finally { (function() { return 1; })(); }",
+      "try {} finally { class A { foo() { return 1; } } }",
+      "try {} finally { const obj = { get a() { return 1; } }; }",
+      "try {} finally { for (const x of xs) { break; } }",
+      "try {} finally { while (a) { if (b) { continue; } } }",
+      "try {} finally { switch (a) { case 1: break; } }",
+      "try {} finally { label: { break label; } }",
+      "try {} finally { outer: for (;;) { for (;;) { continue outer; } } }",
     };
   }
 
@@ -232,5 +367,44 @@ finally {
       5,
       10,
     );
+    assert_lint_err! {
+      NoUnsafeFinally,
+      "function f() { try {} finally { return; } }": [
+        {
+          col: 30,
+          message: "Unsafe usage of ReturnStatement",
+        }
+      ],
+      "function f() { try {} finally { if (a) { return; } } }": [
+        {
+          col: 30,
+          message: "Unsafe usage of ReturnStatement",
+        }
+      ],
+      "try {} finally { () => {}; { throw new Error(); } }": [
+        {
+          col: 15,
+          message: "Unsafe usage of ThrowStatement",
+        }
+      ],
+      "for (;;) { try {} finally { switch (a) { case 1: continue; } } }": [
+        {
+          col: 26,
+          message: "Unsafe usage of ContinueStatement",
+        }
+      ],
+      "outer: for (;;) { try {} finally { for (;;) { break outer; } } }": [
+        {
+          col: 33,
+          message: "Unsafe usage of BreakStatement",
+        }
+      ],
+      "function f() { try {} finally { try {} catch (e) { return; } } }": [
+        {
+          col: 30,
+          message: "Unsafe usage of ReturnStatement",
+        }
+      ],
+    };
   }
 }
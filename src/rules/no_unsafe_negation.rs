@@ -32,6 +32,25 @@ impl LintRule for NoUnsafeNegation {
     let mut visitor = NoUnsafeNegationVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows negating the left operand of `in` and `instanceof`.
+
+The negation operator binds tighter than `in` and `instanceof`, so `!key in object` tests whether `!key`, which is `true` or `false`, is a key of `object`. This is almost never intended.
+
+### Invalid:
+```typescript
+if (!key in object) {}
+if (!foo instanceof Foo) {}
+```
+
+### Valid:
+```typescript
+if (!(key in object)) {}
+if (!(foo instanceof Foo)) {}
+if ((!foo) instanceof Boolean) {}
+```"#
+  }
 }
 
 struct NoUnsafeNegationVisitor<'c> {
@@ -47,18 +66,20 @@ impl<'c> NoUnsafeNegationVisitor<'c> {
 impl<'c> Visit for NoUnsafeNegationVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, parent: &dyn Node) {
     if bin_expr.op == BinaryOp::In || bin_expr.op == BinaryOp::InstanceOf {
       if let Expr::Unary(unary_expr) = &*bin_expr.left {
         if unary_expr.op == UnaryOp::Bang {
-          self.context.add_diagnostic(
+          self.context.add_diagnostic_with_hint(
             bin_expr.span,
             "no-unsafe-negation",
             "Unexpected negation of left operand",
+            "Add parentheses to clarify which range the negation operator should be applied to",
           );
         }
       }
     }
+    swc_ecmascript::visit::visit_bin_expr(self, bin_expr, parent);
   }
 }
 
@@ -77,6 +98,9 @@ mod tests {
       "!(1 in [1, 2, 3])",
       "!(key in object)",
       "!(foo instanceof Date)",
+      "(!a) in b",
+      "(!foo) instanceof Boolean",
+      "!a && b in c",
     };
   }
 
@@ -85,5 +109,34 @@ mod tests {
     assert_lint_err::<NoUnsafeNegation>("!1 in [1, 2, 3]", 0);
     assert_lint_err::<NoUnsafeNegation>("!key in object", 0);
     assert_lint_err::<NoUnsafeNegation>("!foo instanceof Date", 0);
+    assert_lint_err! {
+      NoUnsafeNegation,
+      "!a in b": [
+        {
+          col: 0,
+          message: "Unexpected negation of left operand",
+          hint: "Add parentheses to clarify which range the negation operator should be applied to",
+        }
+      ],
+      "if (x && !a in b) {}": [
+        {
+          col: 9,
+          message: "Unexpected negation of left operand",
+          hint: "Add parentheses to clarify which range the negation operator should be applied to",
+        }
+      ],
+      "foo(!a instanceof B, (!c in d) in e);": [
+        {
+          col: 4,
+          message: "Unexpected negation of left operand",
+          hint: "Add parentheses to clarify which range the negation operator should be applied to",
+        },
+        {
+          col: 22,
+          message: "Unexpected negation of left operand",
+          hint: "Add parentheses to clarify which range the negation operator should be applied to",
+        }
+      ],
+    };
   }
 }
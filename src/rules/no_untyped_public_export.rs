@@ -0,0 +1,251 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, Decl, DefaultDecl, Expr, Function, Lit, Module, ModuleDecl,
+  ModuleItem, Pat, VarDeclKind, VarDeclarator,
+};
+
+pub struct NoUntypedPublicExport;
+
+impl LintRule for NoUntypedPublicExport {
+  fn new() -> Box<Self> {
+    Box::new(NoUntypedPublicExport)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-untyped-public-export"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    // The implementation of an overloaded function isn't visible to its
+    // callers, only the overload signatures are.
+    let mut overloaded = HashSet::new();
+
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          match &export_decl.decl {
+            Decl::Fn(fn_decl) => {
+              let name = &fn_decl.ident.sym;
+              if fn_decl.function.body.is_none() {
+                overloaded.insert(name.clone());
+              } else if overloaded.contains(name) {
+                continue;
+              }
+              if !is_function_typed(&fn_decl.function) {
+                report_function(context, export_decl.span, name);
+              }
+            }
+            Decl::Var(var_decl) if var_decl.kind == VarDeclKind::Const => {
+              for decl in &var_decl.decls {
+                check_const_declarator(context, decl);
+              }
+            }
+            _ => {}
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+          export_default_decl,
+        )) => {
+          if let DefaultDecl::Fn(fn_expr) = &export_default_decl.decl {
+            let name = match &fn_expr.ident {
+              Some(ident) => &*ident.sym,
+              None => "default",
+            };
+            if fn_expr.function.body.is_some()
+              && !is_function_typed(&fn_expr.function)
+            {
+              report_function(context, export_default_decl.span, name);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires explicit types on the signatures of exported functions and constants.
+
+When the types of a module's public API have to be inferred from the
+implementation, tools publishing or type checking the module must analyze all
+of its code. Annotating the parameters and return types of exported functions,
+and the types of exported constants, keeps the public API fast to check.
+
+Constants initialized with a literal don't need an annotation, since their type
+is obvious. Implementations of overloaded functions are skipped, as only the
+overload signatures are public.
+
+### Invalid:
+```typescript
+export function add(a, b) {
+  return a + b;
+}
+export const double = (n: number) => n * 2;
+export const config = loadConfig();
+```
+
+### Valid:
+```typescript
+export function add(a: number, b: number): number {
+  return a + b;
+}
+export const double = (n: number): number => n * 2;
+export const config: Config = loadConfig();
+export const VERSION = "1.0.0";
+```"#
+  }
+}
+
+fn report_function(context: &mut Context, span: Span, name: &str) {
+  context.add_diagnostic_with_hint(
+    span,
+    "no-untyped-public-export",
+    format!("Exported function `{}` is missing explicit types", name),
+    "Add type annotations to all parameters and the return type",
+  );
+}
+
+fn check_const_declarator(context: &mut Context, decl: &VarDeclarator) {
+  if has_type_ann(&decl.name) {
+    return;
+  }
+
+  let is_typed = match decl.init.as_deref() {
+    Some(Expr::Fn(fn_expr)) => is_function_typed(&fn_expr.function),
+    Some(Expr::Arrow(arrow_expr)) => is_arrow_typed(arrow_expr),
+    Some(Expr::Lit(Lit::JSXText(_))) => false,
+    Some(Expr::Lit(_))
+    | Some(Expr::TsAs(_))
+    | Some(Expr::TsTypeAssertion(_))
+    | Some(Expr::TsConstAssertion(_)) => true,
+    _ => false,
+  };
+  if is_typed {
+    return;
+  }
+
+  let message = match &decl.name {
+    Pat::Ident(ident) => {
+      format!(
+        "Exported constant `{}` is missing an explicit type",
+        ident.sym
+      )
+    }
+    _ => "Exported constant is missing an explicit type".to_string(),
+  };
+  context.add_diagnostic_with_hint(
+    decl.span,
+    "no-untyped-public-export",
+    message,
+    "Add a type annotation to the constant",
+  );
+}
+
+fn is_function_typed(function: &Function) -> bool {
+  function.return_type.is_some()
+    && function.params.iter().all(|param| has_type_ann(&param.pat))
+}
+
+fn is_arrow_typed(arrow_expr: &ArrowExpr) -> bool {
+  arrow_expr.return_type.is_some() && arrow_expr.params.iter().all(has_type_ann)
+}
+
+fn has_type_ann(pat: &Pat) -> bool {
+  match pat {
+    Pat::Ident(ident) => ident.type_ann.is_some(),
+    Pat::Array(array_pat) => array_pat.type_ann.is_some(),
+    Pat::Object(object_pat) => object_pat.type_ann.is_some(),
+    Pat::Rest(rest_pat) => rest_pat.type_ann.is_some(),
+    Pat::Assign(assign_pat) => {
+      assign_pat.type_ann.is_some() || has_type_ann(&assign_pat.left)
+    }
+    Pat::Invalid(_) | Pat::Expr(_) => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_untyped_public_export_valid() {
+    assert_lint_ok! {
+      NoUntypedPublicExport,
+      "export function f(a: number): number { return a }",
+      "export function f(...args: string[]): void {}",
+      "export function f({ a }: { a: number }, b: string = ''): number { return a }",
+      "export default function (a: number): number { return a }",
+      "export function f(a: string): string;
+export function f(a: number): number;
+export function f(a) { return a }",
+      "function f(a) { return a }",
+      "export const a = 1;",
+      r#"export const a = "foo";"#,
+      "export const a: Foo = foo();",
+      "export const a = foo() as Foo;",
+      "export const a = { b: 1 } as const;",
+      "export const f = (a: number): number => a;",
+      "export const f = function (a: number): number { return a };",
+      "export let a = foo();",
+      "const a = foo(); export { a };",
+    };
+  }
+
+  #[test]
+  fn no_untyped_public_export_invalid() {
+    assert_lint_err! {
+      NoUntypedPublicExport,
+      "export function f(a) { return a }": [
+        {
+          col: 0,
+          message: "Exported function `f` is missing explicit types",
+          hint: "Add type annotations to all parameters and the return type",
+        }
+      ],
+      "export function f(a: number) { return a }": [
+        {
+          col: 0,
+          message: "Exported function `f` is missing explicit types",
+          hint: "Add type annotations to all parameters and the return type",
+        }
+      ],
+      "export async function f(): Promise<void> {} export function g(a, b: number): void {}": [
+        {
+          col: 44,
+          message: "Exported function `g` is missing explicit types",
+          hint: "Add type annotations to all parameters and the return type",
+        }
+      ],
+      "export default function (a) { return a }": [
+        {
+          col: 15,
+          message: "Exported function `default` is missing explicit types",
+          hint: "Add type annotations to all parameters and the return type",
+        }
+      ],
+      "export const f = (a: number) => a;": [
+        {
+          col: 13,
+          message: "Exported constant `f` is missing an explicit type",
+          hint: "Add a type annotation to the constant",
+        }
+      ],
+      "export const a = foo(), b = 1, { c } = bar();": [
+        {
+          col: 13,
+          message: "Exported constant `a` is missing an explicit type",
+          hint: "Add a type annotation to the constant",
+        },
+        {
+          col: 31,
+          message: "Exported constant is missing an explicit type",
+          hint: "Add a type annotation to the constant",
+        }
+      ],
+    };
+  }
+}
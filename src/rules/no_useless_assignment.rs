@@ -0,0 +1,478 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, AssignOp, BlockStmt, Class, Decl, Expr, Function,
+  GetterProp, Ident, Invalid, Module, ModuleItem, Pat, PatOrExpr, SetterProp,
+  Stmt, SwitchStmt, VarDeclKind,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUselessAssignment;
+
+impl LintRule for NoUselessAssignment {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessAssignment)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-useless-assignment"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoUselessAssignmentVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assigning a value to a variable which is never read before being overwritten or going out of scope.
+
+Such assignments are dead stores: the assigned value has no effect, which often points to a logic error.
+
+This rule only checks `let` bindings which are not referenced from any nested function, since those can be read at any time.
+
+### Invalid:
+```typescript
+let x = 1;
+x = 2;
+console.log(x);
+```
+```typescript
+function foo() {
+  let x = compute();
+  console.log(x);
+  x = compute();
+}
+```
+
+### Valid:
+```typescript
+let x = 1;
+if (cond) {
+  x = 2;
+}
+console.log(x);
+```
+```typescript
+let x = 1;
+x = x + 1;
+console.log(x);
+```"#
+  }
+}
+
+struct NoUselessAssignmentVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessAssignmentVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Checks the `let` bindings declared directly in `stmts`.
+  ///
+  /// `is_scope_end` is false for the module body, as module level bindings
+  /// can be observed after the module has been evaluated.
+  fn check_stmts(&mut self, stmts: &[&Stmt], is_scope_end: bool) {
+    for (index, stmt) in stmts.iter().enumerate() {
+      if let Stmt::Decl(Decl::Var(var_decl)) = stmt {
+        if var_decl.kind != VarDeclKind::Let {
+          continue;
+        }
+        for (decl_index, decl) in var_decl.decls.iter().enumerate() {
+          if let Pat::Ident(ident) = &decl.name {
+            // Closures can read the binding at any point, so we can't tell
+            // whether a store is dead. Hoisted functions may even be
+            // declared before the binding.
+            let id = ident.to_id();
+            if stmts
+              .iter()
+              .any(|stmt| find_reference(*stmt, &id).in_closure)
+            {
+              continue;
+            }
+
+            // The value may be read by one of the following declarators.
+            let is_read = var_decl.decls[decl_index + 1..]
+              .iter()
+              .any(|decl| find_reference(decl, &id).found);
+            let initial_store = match decl.init {
+              Some(_) if !is_read => Some(decl.span),
+              _ => None,
+            };
+            self.check_binding(
+              ident,
+              initial_store,
+              &stmts[index + 1..],
+              is_scope_end,
+            );
+          }
+        }
+      }
+    }
+  }
+
+  fn check_binding(
+    &mut self,
+    ident: &Ident,
+    initial_store: Option<Span>,
+    stmts: &[&Stmt],
+    is_scope_end: bool,
+  ) {
+    let id = ident.to_id();
+    let mut pending_store = initial_store;
+    for stmt in stmts {
+      if let Some(assign_expr) = as_overwrite(stmt, &id) {
+        if let Some(span) = pending_store {
+          self.report(span, ident);
+        }
+        pending_store = Some(assign_expr.span);
+        continue;
+      }
+
+      if find_reference(*stmt, &id).found {
+        pending_store = None;
+        continue;
+      }
+
+      let stops_execution = self
+        .context
+        .control_flow()
+        .meta(stmt.span().lo)
+        .map(|meta| meta.stops_execution())
+        .unwrap_or(false);
+      if stops_execution {
+        break;
+      }
+    }
+
+    if is_scope_end {
+      if let Some(span) = pending_store {
+        self.report(span, ident);
+      }
+    }
+  }
+
+  fn report(&mut self, span: Span, ident: &Ident) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      "no-useless-assignment",
+      format!("The value assigned to `{}` is never read", ident.sym),
+      "Remove the assignment, or use the value before it is overwritten",
+    );
+  }
+}
+
+/// If `stmt` is `<ident> = <expr>;`, returns the assignment and `<ident>`.
+pub(crate) fn as_assignment(stmt: &Stmt) -> Option<(&AssignExpr, &Ident)> {
+  if let Stmt::Expr(expr_stmt) = stmt {
+    if let Expr::Assign(assign_expr) = &*expr_stmt.expr {
+      if assign_expr.op != AssignOp::Assign {
+        return None;
+      }
+      let target = match &assign_expr.left {
+        PatOrExpr::Pat(pat) => match &**pat {
+          Pat::Ident(ident) => Some(ident),
+          Pat::Expr(expr) => match &**expr {
+            Expr::Ident(ident) => Some(ident),
+            _ => None,
+          },
+          _ => None,
+        },
+        PatOrExpr::Expr(expr) => match &**expr {
+          Expr::Ident(ident) => Some(ident),
+          _ => None,
+        },
+      }?;
+      return Some((assign_expr, target));
+    }
+  }
+  None
+}
+
+/// If `stmt` is `<id> = <expr>;` where `<expr>` doesn't reference `id`,
+/// returns the assignment.
+pub(crate) fn as_overwrite<'a>(
+  stmt: &'a Stmt,
+  id: &Id,
+) -> Option<&'a AssignExpr> {
+  let (assign_expr, target) = as_assignment(stmt)?;
+  if target.to_id() == *id && !find_reference(&*assign_expr.right, id).found {
+    Some(assign_expr)
+  } else {
+    None
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct Reference {
+  pub(crate) found: bool,
+  pub(crate) in_closure: bool,
+}
+
+pub(crate) fn find_reference<N>(node: &N, id: &Id) -> Reference
+where
+  N: for<'a> VisitWith<ReferenceFinder<'a>>,
+{
+  let mut finder = ReferenceFinder {
+    id,
+    closure_depth: 0,
+    reference: Reference::default(),
+  };
+  node.visit_with(&Invalid { span: DUMMY_SP }, &mut finder);
+  finder.reference
+}
+
+pub(crate) struct ReferenceFinder<'a> {
+  id: &'a Id,
+  closure_depth: usize,
+  reference: Reference,
+}
+
+impl ReferenceFinder<'_> {
+  fn enter_closure<N>(&mut self, node: &N)
+  where
+    N: for<'a> VisitWith<ReferenceFinder<'a>>,
+  {
+    self.closure_depth += 1;
+    node.visit_children_with(self);
+    self.closure_depth -= 1;
+  }
+}
+
+impl Visit for ReferenceFinder<'_> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _: &dyn Node) {
+    if ident.to_id() == *self.id {
+      self.reference.found = true;
+      if self.closure_depth > 0 {
+        self.reference.in_closure = true;
+      }
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.enter_closure(function);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.enter_closure(arrow_expr);
+  }
+
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    self.enter_closure(class);
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    self.enter_closure(getter_prop);
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    self.enter_closure(setter_prop);
+  }
+}
+
+impl<'c> Visit for NoUselessAssignmentVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_module(&mut self, module: &Module, parent: &dyn Node) {
+    let stmts = module
+      .body
+      .iter()
+      .filter_map(|item| match item {
+        ModuleItem::Stmt(stmt) => Some(stmt),
+        ModuleItem::ModuleDecl(_) => None,
+      })
+      .collect::<Vec<_>>();
+    self.check_stmts(&stmts, false);
+    swc_ecmascript::visit::visit_module(self, module, parent);
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, parent: &dyn Node) {
+    let stmts = block_stmt.stmts.iter().collect::<Vec<_>>();
+    self.check_stmts(&stmts, true);
+    swc_ecmascript::visit::visit_block_stmt(self, block_stmt, parent);
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, parent: &dyn Node) {
+    // Bindings declared in a case are scoped to the whole switch statement,
+    // and can only be used after falling through to the following cases.
+    let stmts = switch_stmt
+      .cases
+      .iter()
+      .flat_map(|case| &case.cons)
+      .collect::<Vec<_>>();
+    self.check_stmts(&stmts, true);
+    swc_ecmascript::visit::visit_switch_stmt(self, switch_stmt, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_assignment_valid() {
+    assert_lint_ok! {
+      NoUselessAssignment,
+      "let x = 1; use(x); x = 2; use(x);",
+      "let x = 1; if (cond) { x = 2; } use(x);",
+      "let x = 1; if (cond) { use(x); } x = 2; use(x);",
+      "let x = 1; x = x + 1; use(x);",
+      "let x = 1, y = x; x = 2; use(x, y);",
+      "switch (a) { case 0: let x = 1; use(x); x = 2; use(x); }",
+      "switch (a) { case 0: let x = 1; case 1: use(x); }",
+      "let x = 1; x += 1; use(x);",
+      "let x; x = 1; use(x);",
+      "let x = 1; const f = () => x; x = 2; f();",
+      "function f() { return x; } let x = 1; x = 2; f();",
+      "let x = 1; foo(x = 2); use(x);",
+      "var x = 1; x = 2; use(x);",
+      r#"
+function foo() {
+  let x = 1;
+  try {
+    x = 2;
+    bar();
+  } catch {
+    use(x);
+  }
+}
+      "#,
+      r#"
+function foo() {
+  let x = 0;
+  while (cond()) {
+    x = next(x);
+  }
+  return x;
+}
+      "#,
+      r#"
+function foo() {
+  let x = 0;
+  for (const y of ys) {
+    use(x);
+  }
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn no_useless_assignment_invalid() {
+    assert_lint_err! {
+      NoUselessAssignment,
+      "let x = 1; x = 2; use(x);": [
+        {
+          col: 4,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "let x; x = 1; x = 2; use(x);": [
+        {
+          col: 7,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "let x = 1; use(x); x = 2; x = 3; use(x);": [
+        {
+          col: 19,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "let x = 1, y = 2; x = y; use(x);": [
+        {
+          col: 4,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "switch (a) { case 0: let x = 1; x = 2; use(x); }": [
+        {
+          col: 25,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      "switch (a) { case 0: let x = 1; case 1: x = 2; use(x); }": [
+        {
+          col: 25,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      r#"
+function foo() {
+  let x = compute();
+  use(x);
+  x = compute();
+}
+      "#: [
+        {
+          line: 5,
+          col: 2,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      r#"
+function foo() {
+  let x = 1;
+  if (cond) {
+    return;
+  }
+  x = 2;
+  return x;
+}
+      "#: [
+        {
+          line: 3,
+          col: 6,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      r#"
+function foo() {
+  let x = 1;
+  use(x);
+  x = 2;
+  return;
+}
+      "#: [
+        {
+          line: 5,
+          col: 2,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+      r#"
+for (const item of items) {
+  let x = item;
+  use(x);
+  x = null;
+}
+      "#: [
+        {
+          line: 5,
+          col: 2,
+          message: "The value assigned to `x` is never read",
+          hint: "Remove the assignment, or use the value before it is overwritten",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::DropSpan;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, MemberExpr, Module,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUselessLengthCheck;
+
+impl LintRule for NoUselessLengthCheck {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessLengthCheck)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-useless-length-check"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoUselessLengthCheckVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows length checks that are redundant with a following `every` or `some` call.
+
+`Array#every` always returns `true` for an empty array, and `Array#some` always returns `false`. Checking that the array is empty before calling `every`, or that it is not empty before calling `some`, therefore has no effect on the result.
+
+### Invalid:
+```typescript
+if (arr.length === 0 || arr.every((x) => x > 0)) {}
+if (arr.length > 0 && arr.some((x) => x > 0)) {}
+```
+
+### Valid:
+```typescript
+if (arr.every((x) => x > 0)) {}
+if (arr.some((x) => x > 0)) {}
+// These change the result for empty arrays.
+if (arr.length > 0 && arr.every((x) => x > 0)) {}
+if (arr.length === 0 || arr.some((x) => x > 0)) {}
+```"#
+  }
+}
+
+struct NoUselessLengthCheckVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessLengthCheckVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+#[derive(PartialEq)]
+enum LengthCheck {
+  /// `arr.length === 0`
+  Empty,
+  /// `arr.length > 0`
+  NonEmpty,
+}
+
+fn is_zero(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Num(num)) if num.value == 0.0)
+}
+
+/// If `expr` is `<obj>.length`, returns `<obj>`.
+fn length_obj(expr: &Expr) -> Option<&Expr> {
+  if let Expr::Member(MemberExpr {
+    obj: ExprOrSuper::Expr(obj),
+    prop,
+    computed: false,
+    ..
+  }) = expr
+  {
+    if matches!(&**prop, Expr::Ident(ident) if ident.sym == *"length") {
+      return Some(&**obj);
+    }
+  }
+  None
+}
+
+/// If `expr` compares the length of an array against zero, returns the
+/// array and the kind of check.
+fn as_length_check(expr: &Expr) -> Option<(&Expr, LengthCheck)> {
+  if let Expr::Bin(BinExpr {
+    op, left, right, ..
+  }) = expr
+  {
+    let obj = length_obj(left)?;
+    if !is_zero(right) {
+      return None;
+    }
+    let check = match op {
+      BinaryOp::EqEqEq | BinaryOp::EqEq => LengthCheck::Empty,
+      BinaryOp::Gt | BinaryOp::NotEqEq | BinaryOp::NotEq => {
+        LengthCheck::NonEmpty
+      }
+      _ => return None,
+    };
+    return Some((obj, check));
+  }
+  None
+}
+
+/// If `expr` is a call of `<obj>.<name>(...)`, returns `<obj>` and `<name>`.
+fn as_method_call(expr: &Expr) -> Option<(&Expr, &str)> {
+  if let Expr::Call(CallExpr {
+    callee: ExprOrSuper::Expr(callee),
+    ..
+  }) = expr
+  {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**callee
+    {
+      if let Expr::Ident(prop) = &**prop {
+        return Some((&**obj, &*prop.sym));
+      }
+    }
+  }
+  None
+}
+
+fn is_same_expr(a: &Expr, b: &Expr) -> bool {
+  a.clone().drop_span() == b.clone().drop_span()
+}
+
+impl<'c> VisitAll for NoUselessLengthCheckVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    let (expected_check, expected_method) = match bin_expr.op {
+      BinaryOp::LogicalOr => (LengthCheck::Empty, "every"),
+      BinaryOp::LogicalAnd => (LengthCheck::NonEmpty, "some"),
+      _ => return,
+    };
+
+    for (check, call) in &[
+      (&bin_expr.left, &bin_expr.right),
+      (&bin_expr.right, &bin_expr.left),
+    ] {
+      if let (Some((check_obj, kind)), Some((call_obj, method))) =
+        (as_length_check(check), as_method_call(call))
+      {
+        if kind == expected_check
+          && method == expected_method
+          && is_same_expr(check_obj, call_obj)
+        {
+          self.context.add_diagnostic_with_hint(
+            check.span(),
+            "no-useless-length-check",
+            "The length check is redundant",
+            format!(
+              "`{}` already returns `{}` for an empty array",
+              expected_method,
+              expected_method == "every"
+            ),
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_length_check_valid() {
+    assert_lint_ok! {
+      NoUselessLengthCheck,
+      "arr.every(f);",
+      "arr.some(f);",
+      "arr.length > 0 && arr.every(f);",
+      "arr.length === 0 || arr.some(f);",
+      "arr.length === 0 && arr.every(f);",
+      "arr.length > 0 || arr.some(f);",
+      "arr.length === 1 || arr.every(f);",
+      "arr.length === 0 || other.every(f);",
+      "arr.length > 0 && other.some(f);",
+      "arr.length === 0 || arr.filter(f);",
+    };
+  }
+
+  #[test]
+  fn no_useless_length_check_invalid() {
+    assert_lint_err! {
+      NoUselessLengthCheck,
+      "arr.length === 0 || arr.every(f);": [
+        {
+          col: 0,
+          message: "The length check is redundant",
+          hint: "`every` already returns `true` for an empty array",
+        }
+      ],
+      "if (arr.length > 0 && arr.some(f)) {}": [
+        {
+          col: 4,
+          message: "The length check is redundant",
+          hint: "`some` already returns `false` for an empty array",
+        }
+      ],
+      "foo.bar.length !== 0 && foo.bar.some(f);": [
+        {
+          col: 0,
+          message: "The length check is redundant",
+          hint: "`some` already returns `false` for an empty array",
+        }
+      ],
+      "arr.every(f) || arr.length == 0;": [
+        {
+          col: 16,
+          message: "The length check is redundant",
+          hint: "`every` already returns `true` for an empty array",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,158 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{ArrayLit, Expr, Module, ObjectLit, PropOrSpread};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUselessSpread;
+
+impl LintRule for NoUselessSpread {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-useless-spread"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoUselessSpreadVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows spreading an array literal into an array literal, or an object literal into an object literal.
+
+The spread creates a temporary collection only to copy its elements, so the inner literal can be inlined into the outer one.
+
+### Invalid:
+```typescript
+const arr = [...[1, 2], 3];
+const obj = { ...{ a: 1 }, b: 2 };
+```
+
+### Valid:
+```typescript
+const arr = [1, 2, 3];
+const obj = { a: 1, b: 2 };
+const copy = [...arr];
+const merged = { ...obj, b: 2 };
+```"#
+  }
+}
+
+struct NoUselessSpreadVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessSpreadVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> VisitAll for NoUselessSpreadVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, _parent: &dyn Node) {
+    for elem in array_lit.elems.iter().flatten() {
+      if elem.spread.is_none() {
+        continue;
+      }
+      if let Expr::Array(inner) = &*elem.expr {
+        // Spreading turns holes into `undefined`, so inlining an array with
+        // holes is not equivalent.
+        if inner.elems.iter().any(|e| e.is_none()) {
+          continue;
+        }
+        self.context.add_diagnostic_with_hint(
+          elem.span(),
+          "no-useless-spread",
+          "Spreading an array literal into an array literal is unnecessary",
+          "Inline the elements into the outer array",
+        );
+      }
+    }
+  }
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _parent: &dyn Node) {
+    for prop in &object_lit.props {
+      if let PropOrSpread::Spread(spread) = prop {
+        if let Expr::Object(_) = &*spread.expr {
+          self.context.add_diagnostic_with_hint(
+            spread.span(),
+            "no-useless-spread",
+            "Spreading an object literal into an object literal is unnecessary",
+            "Inline the properties into the outer object",
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_spread_valid() {
+    assert_lint_ok! {
+      NoUselessSpread,
+      "[...arr];",
+      "[...arr, ...other];",
+      "[...foo()];",
+      "[[1, 2]];",
+      "[...[, 1]];",
+      "({ ...obj });",
+      "({ a: { b: 1 } });",
+      "f(...[1, 2]);",
+      "({ ...[1, 2] });",
+      "[...{ a: 1 }];",
+    };
+  }
+
+  #[test]
+  fn no_useless_spread_invalid() {
+    assert_lint_err! {
+      NoUselessSpread,
+      "[...[1, 2]];": [
+        {
+          col: 1,
+          message: "Spreading an array literal into an array literal is unnecessary",
+          hint: "Inline the elements into the outer array",
+        }
+      ],
+      "const a = [0, ...[], 3];": [
+        {
+          col: 14,
+          message: "Spreading an array literal into an array literal is unnecessary",
+          hint: "Inline the elements into the outer array",
+        }
+      ],
+      "const o = { ...{ a: 1 }, b: 2 };": [
+        {
+          col: 12,
+          message: "Spreading an object literal into an object literal is unnecessary",
+          hint: "Inline the properties into the outer object",
+        }
+      ],
+      "[...[...[1]]];": [
+        {
+          col: 1,
+          message: "Spreading an array literal into an array literal is unnecessary",
+          hint: "Inline the elements into the outer array",
+        },
+        {
+          col: 5,
+          message: "Spreading an array literal into an array literal is unnecessary",
+          hint: "Inline the elements into the outer array",
+        }
+      ],
+    };
+  }
+}
@@ -26,6 +26,27 @@ impl LintRule for NoVar {
     let mut visitor = NoVarVisitor::new(context);
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the `var` keyword.
+
+Variables declared with `var` are function-scoped and hoisted, which is a
+common source of bugs. `let` and `const` are block-scoped instead. The
+declarations aren't fixed automatically, since the change of scope can alter
+the behavior of the code.
+
+### Invalid:
+```typescript
+var foo = "bar";
+for (var i = 0; i < 10; i++) {}
+```
+
+### Valid:
+```typescript
+const foo = "bar";
+for (let i = 0; i < 10; i++) {}
+```"#
+  }
 }
 
 struct NoVarVisitor<'c> {
@@ -41,14 +62,18 @@ impl<'c> NoVarVisitor<'c> {
 impl<'c> Visit for NoVarVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
-    if var_decl.kind == VarDeclKind::Var {
-      self.context.add_diagnostic(
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, parent: &dyn Node) {
+    // Ambient declarations, as in `declare var x: number;`, are allowed since
+    // they only describe an existing global.
+    if var_decl.kind == VarDeclKind::Var && !var_decl.declare {
+      self.context.add_diagnostic_with_hint(
         var_decl.span,
         "no-var",
         "`var` keyword is not allowed",
+        "Use `let` or `const` instead",
       );
     }
+    swc_ecmascript::visit::visit_var_decl(self, var_decl, parent);
   }
 }
 
@@ -57,11 +82,64 @@ mod tests {
   use super::*;
   use crate::test_util::*;
 
+  #[test]
+  fn no_var_valid() {
+    assert_lint_ok! {
+      NoVar,
+      "let x = 1;",
+      "const x = 1;",
+      "for (let i = 0; i < 10; i++) {}",
+      "for (const x of xs) {}",
+      "declare var x: number;",
+    };
+  }
+
   #[test]
   fn no_var_invalid() {
     assert_lint_err::<NoVar>(
       r#"var someVar = "someString"; const c = "c"; let a = "a";"#,
       0,
     );
+    assert_lint_err! {
+      NoVar,
+      "var x = 1;": [
+        {
+          col: 0,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        }
+      ],
+      "for (var i = 0; i < 10; i++) {}": [
+        {
+          col: 5,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        }
+      ],
+      "for (var x in obj) {}": [
+        {
+          col: 5,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        }
+      ],
+      "var f = function() { var x = () => { var y; }; };": [
+        {
+          col: 0,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        },
+        {
+          col: 21,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        },
+        {
+          col: 37,
+          message: "`var` keyword is not allowed",
+          hint: "Use `let` or `const` instead",
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,177 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  Expr, Ident, MemberExpr, Module, Prop, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoWindow;
+
+impl LintRule for NoWindow {
+  fn new() -> Box<Self> {
+    Box::new(NoWindow)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-window"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = GlobalRefVisitor::new(context, |context, ident| {
+      if ident.sym == *"window" {
+        context.add_diagnostic_with_hint(
+          ident.span,
+          "no-window",
+          "`window` is deprecated",
+          "Use `globalThis`, or `self` in workers, instead",
+        );
+      }
+    });
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the global `window` object.
+
+`window` is deprecated in Deno and is not available in workers. Use
+`globalThis`, or `self` in workers, instead. `typeof window` checks are allowed,
+since they are used to detect the environment.
+
+### Invalid:
+```typescript
+const href = window.location.href;
+window.addEventListener("load", onLoad);
+```
+
+### Valid:
+```typescript
+const href = globalThis.location.href;
+globalThis.addEventListener("load", onLoad);
+if (typeof window !== "undefined") {}
+```"#
+  }
+}
+
+/// Visits the identifiers which refer to globals rather than to bindings of
+/// the module, and passes them to `on_global_ref`. Property names and the
+/// operands of `typeof`, which are used to detect the environment, are
+/// skipped.
+pub(crate) struct GlobalRefVisitor<'c, F> {
+  context: &'c mut Context,
+  on_global_ref: F,
+}
+
+impl<'c, F> GlobalRefVisitor<'c, F>
+where
+  F: FnMut(&mut Context, &Ident),
+{
+  pub(crate) fn new(context: &'c mut Context, on_global_ref: F) -> Self {
+    Self {
+      context,
+      on_global_ref,
+    }
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    if self.context.is_global_ident(ident) {
+      (self.on_global_ref)(&mut *self.context, ident);
+    }
+  }
+}
+
+impl<'c, F> Visit for GlobalRefVisitor<'c, F>
+where
+  F: FnMut(&mut Context, &Ident),
+{
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::TypeOf {
+      if let Expr::Ident(_) = &*unary_expr.arg {
+        return;
+      }
+    }
+    swc_ecmascript::visit::visit_unary_expr(self, unary_expr, parent);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check_ident(ident);
+      return;
+    }
+    swc_ecmascript::visit::visit_expr(self, expr, parent);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check_ident(ident);
+      return;
+    }
+    swc_ecmascript::visit::visit_prop(self, prop, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_window_valid() {
+    assert_lint_ok! {
+      NoWindow,
+      "globalThis.location.href;",
+      "self.postMessage('');",
+      "const window = {}; window.location;",
+      "function f(window) { return window.document; }",
+      "foo.window;",
+      "const o = { window: 1 };",
+      "if (typeof window !== 'undefined') {}",
+    };
+  }
+
+  #[test]
+  fn no_window_invalid() {
+    assert_lint_err! {
+      NoWindow,
+      "window.location;": [
+        {
+          col: 0,
+          message: "`window` is deprecated",
+          hint: "Use `globalThis`, or `self` in workers, instead",
+        }
+      ],
+      "const w = window;": [
+        {
+          col: 10,
+          message: "`window` is deprecated",
+          hint: "Use `globalThis`, or `self` in workers, instead",
+        }
+      ],
+      "foo({ window });": [
+        {
+          col: 6,
+          message: "`window` is deprecated",
+          hint: "Use `globalThis`, or `self` in workers, instead",
+        }
+      ],
+      "function f() { return window['document']; }": [
+        {
+          col: 22,
+          message: "`window` is deprecated",
+          hint: "Use `globalThis`, or `self` in workers, instead",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,173 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::static_property_name;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, MemberExpr, Module};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+/// Properties of `window` which are also available as globals, in the main
+/// thread as well as in workers.
+static GLOBAL_APIS: &[&str] = &[
+  "addEventListener",
+  "alert",
+  "atob",
+  "btoa",
+  "caches",
+  "clearInterval",
+  "clearTimeout",
+  "close",
+  "closed",
+  "confirm",
+  "console",
+  "crypto",
+  "dispatchEvent",
+  "fetch",
+  "localStorage",
+  "location",
+  "navigator",
+  "onload",
+  "onunload",
+  "performance",
+  "prompt",
+  "queueMicrotask",
+  "removeEventListener",
+  "sessionStorage",
+  "setInterval",
+  "setTimeout",
+];
+
+pub struct NoWindowPrefix;
+
+impl LintRule for NoWindowPrefix {
+  fn new() -> Box<Self> {
+    Box::new(NoWindowPrefix)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-window-prefix"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = NoWindowPrefixVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling Web APIs through the `window` object.
+
+APIs like `setTimeout` and `fetch` are available as globals, both in the main
+thread and in workers, where `window` does not exist. Accessing them without the
+`window.` prefix keeps the code working in both contexts.
+
+### Invalid:
+```typescript
+window.setTimeout(() => {}, 100);
+const res = await window.fetch("https://deno.land");
+```
+
+### Valid:
+```typescript
+setTimeout(() => {}, 100);
+const res = await fetch("https://deno.land");
+window.customProperty;
+```"#
+  }
+}
+
+struct NoWindowPrefixVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoWindowPrefixVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn is_global_window(&self, obj: &ExprOrSuper) -> bool {
+    match obj {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => {
+          ident.sym == *"window" && self.context.is_global_ident(ident)
+        }
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    }
+  }
+}
+
+impl<'c> VisitAll for NoWindowPrefixVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    let prop = match static_property_name(member_expr) {
+      Some(prop) => prop,
+      None => return,
+    };
+    if !GLOBAL_APIS.contains(&prop) || !self.is_global_window(&member_expr.obj)
+    {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      member_expr.span,
+      "no-window-prefix",
+      format!("`{}` should be accessed without the `window.` prefix", prop),
+      format!("Use `{}` instead, which is also available in workers", prop),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_window_prefix_valid() {
+    assert_lint_ok! {
+      NoWindowPrefix,
+      "fetch('https://deno.land');",
+      "setTimeout(() => {}, 0);",
+      "window.customThing;",
+      "window[fetch];",
+      "foo.window.fetch();",
+      "const window = { fetch() {} }; window.fetch();",
+      "function f(window) { window.setTimeout(); }",
+    };
+  }
+
+  #[test]
+  fn no_window_prefix_invalid() {
+    assert_lint_err! {
+      NoWindowPrefix,
+      "window.fetch('https://deno.land');": [
+        {
+          col: 0,
+          message: "`fetch` should be accessed without the `window.` prefix",
+          hint: "Use `fetch` instead, which is also available in workers",
+        }
+      ],
+      "const id = window.setTimeout(() => {}, 0);": [
+        {
+          col: 11,
+          message: "`setTimeout` should be accessed without the `window.` prefix",
+          hint: "Use `setTimeout` instead, which is also available in workers",
+        }
+      ],
+      "window['location'].href;": [
+        {
+          col: 0,
+          message: "`location` should be accessed without the `window.` prefix",
+          hint: "Use `location` instead, which is also available in workers",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,315 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, Expr, Function, Ident, Invalid, Module, Prop,
+  PropName, ThisExpr,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+use swc_ecmascript::visit::VisitWith;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShorthandStyle {
+  /// Require shorthand properties and methods wherever possible.
+  Always,
+  /// Disallow shorthand properties and methods.
+  Never,
+}
+
+impl Default for ShorthandStyle {
+  fn default() -> Self {
+    ShorthandStyle::Always
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ObjectShorthandOptions {
+  pub style: ShorthandStyle,
+  /// Under `"always"`, also report arrow functions with a block body, which
+  /// could be written as methods.
+  pub avoid_explicit_return_arrows: bool,
+}
+
+pub struct ObjectShorthand {
+  options: ObjectShorthandOptions,
+}
+
+impl ObjectShorthand {
+  pub fn with_options(options: ObjectShorthandOptions) -> Box<Self> {
+    Box::new(ObjectShorthand { options })
+  }
+}
+
+impl LintRule for ObjectShorthand {
+  fn new() -> Box<Self> {
+    Self::with_options(ObjectShorthandOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "object-shorthand"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = ObjectShorthandVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent use of shorthand syntax for object literal properties and methods.
+
+With `style: "always"` (default), properties whose value is a variable of the same name and methods written as function expressions are reported. With `style: "never"`, shorthand properties and methods are reported instead.
+
+The `avoidExplicitReturnArrows` option (default `false`) additionally reports arrow functions with a block body under `"always"`, unless they use `this` or `arguments`.
+
+### Invalid (`"always"`):
+```typescript
+const a = { foo: foo };
+const b = { bar: function () {} };
+```
+
+### Valid (`"always"`):
+```typescript
+const a = { foo };
+const b = { bar() {} };
+const c = { baz: () => 1 };
+```"#
+  }
+}
+
+struct ObjectShorthandVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o ObjectShorthandOptions,
+}
+
+impl<'c, 'o> ObjectShorthandVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o ObjectShorthandOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_always(&mut self, prop: &Prop) {
+    let key_value = match prop {
+      Prop::KeyValue(key_value) => key_value,
+      _ => return,
+    };
+
+    match &*key_value.value {
+      Expr::Ident(value) => {
+        if let PropName::Ident(key) = &key_value.key {
+          if key.sym == value.sym {
+            self.context.add_diagnostic_with_hint(
+              prop.span(),
+              "object-shorthand",
+              "Expected property shorthand",
+              format!(
+                "Replace `{}: {}` with `{}`",
+                key.sym, value.sym, key.sym
+              ),
+            );
+          }
+        }
+      }
+      Expr::Fn(fn_expr) if fn_expr.ident.is_none() => {
+        self.report_expected_method(prop);
+      }
+      Expr::Arrow(arrow_expr)
+        if self.options.avoid_explicit_return_arrows
+          && matches!(arrow_expr.body, BlockStmtOrExpr::BlockStmt(_))
+          && !uses_lexical_this(arrow_expr) =>
+      {
+        self.report_expected_method(prop);
+      }
+      _ => {}
+    }
+  }
+
+  fn report_expected_method(&mut self, prop: &Prop) {
+    self.context.add_diagnostic_with_hint(
+      prop.span(),
+      "object-shorthand",
+      "Expected method shorthand",
+      "Use the method syntax `name() { ... }`",
+    );
+  }
+
+  fn check_never(&mut self, prop: &Prop) {
+    match prop {
+      Prop::Shorthand(ident) => {
+        self.context.add_diagnostic_with_hint(
+          ident.span,
+          "object-shorthand",
+          "Expected longform property syntax",
+          format!(
+            "Replace `{}` with `{}: {}`",
+            ident.sym, ident.sym, ident.sym
+          ),
+        );
+      }
+      Prop::Method(_) => {
+        self.context.add_diagnostic_with_hint(
+          prop.span(),
+          "object-shorthand",
+          "Expected longform method syntax",
+          "Use the property syntax `name: function () { ... }`",
+        );
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Checks if `arrow_expr` refers to `this` or `arguments` of the enclosing
+/// function, which would change if it was converted to a method.
+fn uses_lexical_this(arrow_expr: &ArrowExpr) -> bool {
+  let mut finder = LexicalThisFinder { found: false };
+  arrow_expr.visit_with(&Invalid { span: DUMMY_SP }, &mut finder);
+  finder.found
+}
+
+struct LexicalThisFinder {
+  found: bool,
+}
+
+impl Visit for LexicalThisFinder {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_ident(&mut self, ident: &Ident, _: &dyn Node) {
+    if ident.sym == *"arguments" {
+      self.found = true;
+    }
+  }
+
+  // Non-arrow functions have their own `this` and `arguments`.
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+}
+
+impl<'c, 'o> VisitAll for ObjectShorthandVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    match self.options.style {
+      ShorthandStyle::Always => self.check_always(prop),
+      ShorthandStyle::Never => self.check_never(prop),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn object_shorthand_valid() {
+    assert_lint_ok! {
+      ObjectShorthand,
+      "const a = { foo };",
+      "const a = { foo: bar };",
+      "const a = { 'foo': foo };",
+      "const a = { foo() {} };",
+      "const a = { foo: function foo() {} };",
+      "const a = { foo: () => {} };",
+      "const a = { get foo() { return 1; } };",
+      "const a = { [foo]: foo };",
+      "const { foo: foo } = obj;",
+    };
+  }
+
+  #[test]
+  fn object_shorthand_invalid() {
+    assert_lint_err! {
+      ObjectShorthand,
+      "const a = { foo: foo };": [
+        {
+          col: 12,
+          message: "Expected property shorthand",
+          hint: "Replace `foo: foo` with `foo`",
+        }
+      ],
+      "const a = { m: function () { return 1; } };": [
+        {
+          col: 12,
+          message: "Expected method shorthand",
+          hint: "Use the method syntax `name() { ... }`",
+        }
+      ],
+      "const a = { m: async function* () {} };": [
+        {
+          col: 12,
+          message: "Expected method shorthand",
+          hint: "Use the method syntax `name() { ... }`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn object_shorthand_never() {
+    assert_lint_ok_with_options! {
+      ObjectShorthand,
+      json!({ "style": "never" }),
+      "const a = { foo: foo };",
+      "const a = { get foo() { return 1; } };",
+    };
+    assert_lint_err_with_options! {
+      ObjectShorthand,
+      json!({ "style": "never" }),
+      "const a = { foo };": [
+        {
+          col: 12,
+          message: "Expected longform property syntax",
+          hint: "Replace `foo` with `foo: foo`",
+        }
+      ],
+      "const a = { m() {} };": [
+        {
+          col: 12,
+          message: "Expected longform method syntax",
+          hint: "Use the property syntax `name: function () { ... }`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn object_shorthand_avoid_explicit_return_arrows() {
+    assert_lint_ok_with_options! {
+      ObjectShorthand,
+      json!({ "avoidExplicitReturnArrows": true }),
+      "const a = { m: () => 1 };",
+      "const a = { m: () => { this.x(); } };",
+    };
+    assert_lint_err_with_options! {
+      ObjectShorthand,
+      json!({ "avoidExplicitReturnArrows": true }),
+      "const a = { m: () => { return 1; } };": [
+        {
+          col: 12,
+          message: "Expected method shorthand",
+          hint: "Use the method syntax `name() { ... }`",
+        }
+      ],
+      "const a = { m: () => { return function () { return this; }; } };": [
+        {
+          col: 12,
+          message: "Expected method shorthand",
+          hint: "Use the method syntax `name() { ... }`",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,162 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, MemberExpr, Module,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferArrayFlatMap;
+
+impl LintRule for PreferArrayFlatMap {
+  fn new() -> Box<Self> {
+    Box::new(PreferArrayFlatMap)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-array-flat-map"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferArrayFlatMapVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using `.flatMap()` instead of `.map().flat()`.
+
+`Array#flatMap` maps each element and flattens the result by one level in a single pass, without creating an intermediate array.
+
+### Invalid:
+```typescript
+const result = arr.map((x) => [x, x * 2]).flat();
+const result = arr.map((x) => [x, x * 2]).flat(1);
+```
+
+### Valid:
+```typescript
+const result = arr.flatMap((x) => [x, x * 2]);
+const result = arr.map((x) => [[x]]).flat(2);
+const result = arr.flat();
+```"#
+  }
+}
+
+struct PreferArrayFlatMapVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferArrayFlatMapVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// If `call_expr` is a `<obj>.<name>(...)` call, returns `<obj>`.
+fn method_receiver<'a>(
+  call_expr: &'a CallExpr,
+  name: &str,
+) -> Option<&'a Expr> {
+  if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**callee
+    {
+      if matches!(&**prop, Expr::Ident(ident) if ident.sym == *name) {
+        return Some(&**obj);
+      }
+    }
+  }
+  None
+}
+
+/// Checks if the arguments of `.flat()` flatten exactly one level.
+fn is_depth_one(args: &[ExprOrSpread]) -> bool {
+  match args {
+    [] => true,
+    [ExprOrSpread { spread: None, expr }] => {
+      matches!(&**expr, Expr::Lit(Lit::Num(num)) if num.value == 1.0)
+    }
+    _ => false,
+  }
+}
+
+impl<'c> VisitAll for PreferArrayFlatMapVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if !is_depth_one(&call_expr.args) {
+      return;
+    }
+
+    if let Some(Expr::Call(inner_call)) = method_receiver(call_expr, "flat") {
+      if method_receiver(inner_call, "map").is_some() {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          "prefer-array-flat-map",
+          "Prefer `.flatMap()` over `.map().flat()`",
+          "Replace `.map(f).flat()` with `.flatMap(f)`",
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_array_flat_map_valid() {
+    assert_lint_ok! {
+      PreferArrayFlatMap,
+      "a.flatMap(f);",
+      "a.flat();",
+      "a.map(f).flat(2);",
+      "a.map(f).flat(depth);",
+      "a.filter(f).flat();",
+      "a.map(f).flat;",
+      "map(f).flat();",
+      "a.map(f).b.flat();",
+    };
+  }
+
+  #[test]
+  fn prefer_array_flat_map_invalid() {
+    assert_lint_err! {
+      PreferArrayFlatMap,
+      "a.map(f).flat();": [
+        {
+          col: 0,
+          message: "Prefer `.flatMap()` over `.map().flat()`",
+          hint: "Replace `.map(f).flat()` with `.flatMap(f)`",
+        }
+      ],
+      "const b = a.map((x) => [x]).flat(1);": [
+        {
+          col: 10,
+          message: "Prefer `.flatMap()` over `.map().flat()`",
+          hint: "Replace `.map(f).flat()` with `.flatMap(f)`",
+        }
+      ],
+      "a.b.map(f).flat().map(g).flat();": [
+        {
+          col: 0,
+          message: "Prefer `.flatMap()` over `.map().flat()`",
+          hint: "Replace `.map(f).flat()` with `.flatMap(f)`",
+        },
+        {
+          col: 0,
+          message: "Prefer `.flatMap()` over `.map().flat()`",
+          hint: "Replace `.map(f).flat()` with `.flatMap(f)`",
+        }
+      ],
+    };
+  }
+}
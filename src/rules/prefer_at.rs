@@ -0,0 +1,238 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::DropSpan;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, BinExpr, BinaryOp, Expr, ExprOrSuper, Lit, MemberExpr, Module,
+  Number, PatOrExpr, UnaryExpr, UnaryOp, UpdateExpr,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct PreferAt;
+
+impl LintRule for PreferAt {
+  fn new() -> Box<Self> {
+    Box::new(PreferAt)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-at"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferAtVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using `.at()` to access elements relative to the end of an array or string.
+
+Indexing with `x[x.length - n]` repeats the object expression and is easy to get wrong. `x.at(-n)` expresses the same access directly. Only accesses where `n` is a positive integer literal are reported, since `x.at(-n)` reads from the start of `x` when `n` is zero or negative. Only side-effect-free objects (identifiers and simple member chains) are reported, and assignment targets are ignored since `.at()` cannot be assigned to.
+
+### Invalid:
+```typescript
+const last = arr[arr.length - 1];
+const secondToLast = foo.bar[foo.bar.length - 2];
+```
+
+### Valid:
+```typescript
+const last = arr.at(-1);
+const previous = arr[i - 1];
+const nth = arr[arr.length - n];
+arr[arr.length - 1] = 0;
+```"#
+  }
+}
+
+struct PreferAtVisitor<'c> {
+  context: &'c mut Context,
+  /// Spans of member expressions used as assignment targets, which can't be
+  /// rewritten to `.at()`.
+  assignment_targets: HashSet<Span>,
+}
+
+impl<'c> PreferAtVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      assignment_targets: HashSet::new(),
+    }
+  }
+
+  fn mark_assignment_target(&mut self, expr: &Expr) {
+    if let Expr::Member(member_expr) = expr {
+      self.assignment_targets.insert(member_expr.span);
+    }
+  }
+}
+
+/// Checks if `expr` can be evaluated twice without observable side effects,
+/// i.e. it is an identifier, `this` or a non-computed member chain of those.
+fn is_side_effect_free(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      computed: false,
+      ..
+    }) => is_side_effect_free(obj),
+    _ => false,
+  }
+}
+
+/// Checks if `expr` is `<obj>.length`.
+fn is_length_of(expr: &Expr, obj: &Expr) -> bool {
+  if let Expr::Member(MemberExpr {
+    obj: ExprOrSuper::Expr(length_obj),
+    prop,
+    computed: false,
+    ..
+  }) = expr
+  {
+    if let Expr::Ident(prop) = &**prop {
+      return prop.sym == *"length"
+        && (**length_obj).clone().drop_span() == obj.clone().drop_span();
+    }
+  }
+  false
+}
+
+/// Checks if `expr` is a positive integer literal, for which `x[x.length - n]`
+/// and `x.at(-n)` access the same element.
+fn is_positive_integer(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(Number { value, .. })) => {
+      *value > 0.0 && value.fract() == 0.0
+    }
+    _ => false,
+  }
+}
+
+impl<'c> Visit for PreferAtVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, parent: &dyn Node) {
+    if let PatOrExpr::Expr(expr) = &assign_expr.left {
+      self.mark_assignment_target(expr);
+    }
+    if let PatOrExpr::Pat(pat) = &assign_expr.left {
+      if let swc_ecmascript::ast::Pat::Expr(expr) = &**pat {
+        self.mark_assignment_target(expr);
+      }
+    }
+    swc_ecmascript::visit::visit_assign_expr(self, assign_expr, parent);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, parent: &dyn Node) {
+    self.mark_assignment_target(&update_expr.arg);
+    swc_ecmascript::visit::visit_update_expr(self, update_expr, parent);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.mark_assignment_target(&unary_expr.arg);
+    }
+    swc_ecmascript::visit::visit_unary_expr(self, unary_expr, parent);
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, parent: &dyn Node) {
+    if member_expr.computed
+      && !self.assignment_targets.contains(&member_expr.span)
+    {
+      if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+        if let Expr::Bin(BinExpr {
+          op: BinaryOp::Sub,
+          left,
+          right,
+          ..
+        }) = &*member_expr.prop
+        {
+          if is_side_effect_free(obj)
+            && is_length_of(left, obj)
+            && is_positive_integer(right)
+          {
+            self.context.add_diagnostic_with_hint(
+              member_expr.span,
+              "prefer-at",
+              "Prefer `.at(-n)` over `[x.length - n]`",
+              "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+            );
+          }
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_member_expr(self, member_expr, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_at_valid() {
+    assert_lint_ok! {
+      PreferAt,
+      "arr[i - 1];",
+      "arr.at(-1);",
+      "arr[arr.length];",
+      "arr[other.length - 1];",
+      "arr[arr.length + 1];",
+      "arr.length - 1;",
+      "foo()[foo().length - 1];",
+      "arr[arr.length - 1] = 0;",
+      "arr[arr.length - 1]++;",
+      "delete arr[arr.length - 1];",
+      "a.b[a.c.length - 1];",
+      "arr[arr.length - i];",
+      "arr[arr.length - 0];",
+      "arr[arr.length - 1.5];",
+      "this.items[this.items.length - n];",
+    };
+  }
+
+  #[test]
+  fn prefer_at_invalid() {
+    assert_lint_err! {
+      PreferAt,
+      "arr[arr.length - 1];": [
+        {
+          col: 0,
+          message: "Prefer `.at(-n)` over `[x.length - n]`",
+          hint: "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+        }
+      ],
+      "const x = foo.bar[foo.bar.length - 2];": [
+        {
+          col: 10,
+          message: "Prefer `.at(-n)` over `[x.length - n]`",
+          hint: "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+        }
+      ],
+      "this.items[this.items.length - 3];": [
+        {
+          col: 0,
+          message: "Prefer `.at(-n)` over `[x.length - n]`",
+          hint: "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+        }
+      ],
+      "arr[arr.length - 1][arr[arr.length - 1].length - 1] = 0;": [
+        {
+          col: 0,
+          message: "Prefer `.at(-n)` over `[x.length - n]`",
+          hint: "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+        },
+        {
+          col: 20,
+          message: "Prefer `.at(-n)` over `[x.length - n]`",
+          hint: "Replace `x[x.length - n]` with `x.at(-n)` to access elements from the end",
+        }
+      ],
+    };
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use serde::Deserialize;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::mem;
@@ -18,11 +19,43 @@ use swc_ecmascript::utils::find_ids;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::{Node, Visit, VisitWith};
 
-pub struct PreferConst;
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DestructuringMode {
+  /// Reports any variable of a destructuring which could be `const`.
+  Any,
+  /// Reports the variables of a destructuring only if all of them could be
+  /// `const`.
+  All,
+}
+
+impl Default for DestructuringMode {
+  fn default() -> Self {
+    DestructuringMode::Any
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferConstOptions {
+  /// How variables declared or initialized together by a destructuring are
+  /// reported, as in `let { a, b } = obj; a = 0;`.
+  pub destructuring: DestructuringMode,
+}
+
+pub struct PreferConst {
+  options: PreferConstOptions,
+}
+
+impl PreferConst {
+  pub fn with_options(options: PreferConstOptions) -> Box<Self> {
+    Box::new(PreferConst { options })
+  }
+}
 
 impl LintRule for PreferConst {
   fn new() -> Box<Self> {
-    Box::new(PreferConst)
+    Self::with_options(PreferConstOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -41,10 +74,51 @@ impl LintRule for PreferConst {
     let mut collector = VariableCollector::new();
     collector.visit_module(module, module);
 
-    let mut visitor =
-      PreferConstVisitor::new(context, mem::take(&mut collector.scopes));
+    let mut visitor = PreferConstVisitor::new(
+      context,
+      &self.options,
+      mem::take(&mut collector.scopes),
+    );
     visitor.visit_module(module, module);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `const` for variables which are never reassigned.
+
+Declaring such variables with `const` makes it clear that their value never
+changes, and prevents reassigning them by mistake.
+
+### Options:
+- `destructuring`: `"any"` (default) reports any variable of a destructuring
+  which is never reassigned, `"all"` only reports them if none of the variables
+  of the destructuring are reassigned.
+
+### Invalid:
+```typescript
+let a = 0;
+foo(a);
+
+let b;
+b = 0;
+
+for (let i in [1, 2, 3]) {
+  foo(i);
+}
+```
+
+### Valid:
+```typescript
+const a = 0;
+foo(a);
+
+let b = 0;
+b = 1;
+
+for (let i = 0; i < 3; i++) {
+  foo(i);
+}
+```"#
+  }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -55,6 +129,9 @@ struct Variable {
   /// If this variable is declared in "init" section of a for statement, it stores `Some(span)` where
   /// `span` is the span of the for statement. Otherwise, it stores `None`.
   in_for_init: Option<Span>,
+  /// If this variable is declared or initialized by a destructuring pattern, it stores `Some(span)`
+  /// where `span` is the span of the pattern. Otherwise, it stores `None`.
+  destructuring: Option<Span>,
   is_param: bool,
 }
 
@@ -117,6 +194,22 @@ fn update_variable_status(scope: Scope, ident: &Ident, force_reassigned: bool) {
   }
 }
 
+/// Looks for the variable of the given ident by traversing from the current scope to the parent,
+/// and records the destructuring pattern initializing it, unless it already belongs to one.
+fn set_destructuring(scope: Scope, ident: &Ident, pat_span: Span) {
+  let mut cur_scope = Some(scope);
+  while let Some(cur) = cur_scope {
+    let mut lock = cur.borrow_mut();
+    if let Some(var) = lock.variables.get_mut(&ident.sym) {
+      if var.destructuring.is_none() {
+        var.destructuring = Some(pat_span);
+      }
+      return;
+    }
+    cur_scope = lock.parent.as_ref().map(Rc::clone);
+  }
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 enum ScopeRange {
   Global,
@@ -152,11 +245,29 @@ impl VariableCollector {
         initialized: has_init,
         reassigned: false,
         in_for_init,
+        destructuring: None,
         is_param,
       },
     );
   }
 
+  /// Declares the variables of the pattern of a `let` declaration.
+  fn declare_let(
+    &mut self,
+    pat: &Pat,
+    has_init: bool,
+    in_for_init: Option<Span>,
+  ) {
+    self.extract_decl_idents(pat, has_init, in_for_init);
+    if !matches!(pat, Pat::Ident(_)) {
+      let scope = self.scopes.get(&self.cur_scope).unwrap();
+      let idents: Vec<Ident> = find_ids(pat);
+      for ident in idents {
+        set_destructuring(Rc::clone(scope), &ident, pat.span());
+      }
+    }
+  }
+
   fn extract_decl_idents(
     &mut self,
     pat: &Pat,
@@ -278,7 +389,7 @@ impl Visit for VariableCollector {
           var_decl.visit_children_with(a);
           if var_decl.kind == VarDeclKind::Let {
             for decl in &var_decl.decls {
-              a.extract_decl_idents(
+              a.declare_let(
                 &decl.name,
                 decl.init.is_some(),
                 Some(for_stmt.span),
@@ -312,7 +423,7 @@ impl Visit for VariableCollector {
       if let VarDeclOrPat::VarDecl(var_decl) = &for_of_stmt.left {
         if var_decl.kind == VarDeclKind::Let {
           for decl in &var_decl.decls {
-            a.extract_decl_idents(&decl.name, true, None);
+            a.declare_let(&decl.name, true, None);
           }
         }
       }
@@ -332,7 +443,7 @@ impl Visit for VariableCollector {
       if let VarDeclOrPat::VarDecl(var_decl) = &for_in_stmt.left {
         if var_decl.kind == VarDeclKind::Let {
           for decl in &var_decl.decls {
-            a.extract_decl_idents(&decl.name, true, None);
+            a.declare_let(&decl.name, true, None);
           }
         }
       }
@@ -468,25 +579,28 @@ impl Visit for VariableCollector {
     var_decl.visit_children_with(self);
     if var_decl.kind == VarDeclKind::Let {
       for decl in &var_decl.decls {
-        self.extract_decl_idents(&decl.name, decl.init.is_some(), None);
+        self.declare_let(&decl.name, decl.init.is_some(), None);
       }
     }
   }
 }
 
-struct PreferConstVisitor<'c> {
+struct PreferConstVisitor<'c, 'o> {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   context: &'c mut Context,
+  options: &'o PreferConstOptions,
 }
 
-impl<'c> PreferConstVisitor<'c> {
+impl<'c, 'o> PreferConstVisitor<'c, 'o> {
   fn new(
     context: &'c mut Context,
+    options: &'o PreferConstOptions,
     scopes: BTreeMap<ScopeRange, Scope>,
   ) -> Self {
     Self {
       context,
+      options,
       scopes,
       cur_scope: ScopeRange::Global,
     }
@@ -574,7 +688,18 @@ impl<'c> PreferConstVisitor<'c> {
       .iter()
       .any(|i| self.declared_outer_scope_or_param_var(i));
 
+    let destructuring = if let Pat::Ident(_) = pat {
+      None
+    } else {
+      Some(pat.span())
+    };
+
     for ident in idents {
+      if let Some(pat_span) = destructuring {
+        let scope = self.scopes.get(&self.cur_scope).unwrap();
+        set_destructuring(Rc::clone(scope), ident, pat_span);
+      }
+
       // If tha pat contains either of the following:
       //
       // - MemberExpresion
@@ -611,13 +736,22 @@ impl<'c> PreferConstVisitor<'c> {
   }
 
   fn exit_module(&mut self) {
-    let mut for_init_vars = BTreeMap::new();
+    let mut grouped_vars = BTreeMap::new();
     let scopes = self.scopes.clone();
     for scope in scopes.values() {
       for (sym, status) in scope.borrow().variables.iter() {
-        if let Some(for_span) = status.in_for_init {
-          for_init_vars
-            .entry(for_span)
+        let group = match (status.in_for_init, status.destructuring) {
+          (Some(for_span), _) => Some(for_span),
+          (None, Some(pat_span))
+            if self.options.destructuring == DestructuringMode::All =>
+          {
+            Some(pat_span)
+          }
+          _ => None,
+        };
+        if let Some(group) = group {
+          grouped_vars
+            .entry(group)
             .or_insert_with(Vec::new)
             .push((sym.clone(), *status));
         } else if status.should_report() {
@@ -626,9 +760,9 @@ impl<'c> PreferConstVisitor<'c> {
       }
     }
 
-    // With regard to init sections of for statements, we should report diagnostics only if *all*
-    // variables there need to be reported.
-    for (sym, var) in for_init_vars
+    // With regard to init sections of for statements, and destructurings in the "all" mode, we
+    // should report diagnostics only if *all* variables there need to be reported.
+    for (sym, var) in grouped_vars
       .iter()
       .filter_map(|(_, vars)| {
         if vars.iter().all(|(_, status)| status.should_report()) {
@@ -644,7 +778,7 @@ impl<'c> PreferConstVisitor<'c> {
   }
 }
 
-impl<'c> Visit for PreferConstVisitor<'c> {
+impl<'c, 'o> Visit for PreferConstVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_module(&mut self, module: &Module, _: &dyn Node) {
@@ -1488,6 +1622,7 @@ let global2 = 42;
 mod prefer_const_tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   // Some tests are derived from
   // https://github.com/eslint/eslint/blob/v7.10.0/tests/lib/rules/prefer-const.js
@@ -1725,7 +1860,7 @@ var foo = function() {
       r#"/*eslint use-x:error*/ { let x = 1 }"#,
       29,
     );
-    assert_lint_err_n::<PreferConst>(r#"let { foo, bar } = baz;"#, vec![11, 6]);
+    assert_lint_err_n::<PreferConst>(r#"let { foo, bar } = baz;"#, vec![6, 11]);
     assert_lint_err::<PreferConst>(r#"const x = [1,2]; let [,y] = x;"#, 23);
     assert_lint_err_n::<PreferConst>(
       r#"const x = [1,2,3]; let [y,,z] = x;"#,
@@ -1811,4 +1946,53 @@ e = 2;
       4,
     );
   }
+
+  #[test]
+  fn prefer_const_destructuring_all() {
+    assert_lint_ok_with_options! {
+      PreferConst,
+      json!({ "destructuring": "all" }),
+      r#"let {a, b} = obj; b = 0;"#,
+      r#"let [x = -1, y] = [1, 2]; y = 0;"#,
+      r#"let a, b; ({a = 0, b} = obj); b = 0; foo(a, b);"#,
+      r#"let x = 1; x = 2;"#,
+      r#"for (let i = 0; i < 10; i++) {}"#,
+    };
+    assert_lint_err_with_options! {
+      PreferConst,
+      json!({ "destructuring": "all" }),
+      r#"let {a, b} = obj;"#: [
+        {
+          col: 5,
+          message: "'a' is never reassigned. Use 'const' instead",
+        },
+        {
+          col: 8,
+          message: "'b' is never reassigned. Use 'const' instead",
+        }
+      ],
+      r#"let {a, b} = obj, c = 1; b = 0;"#: [
+        {
+          col: 18,
+          message: "'c' is never reassigned. Use 'const' instead",
+        }
+      ],
+      r#"let x = 1; use(x);"#: [
+        {
+          col: 4,
+          message: "'x' is never reassigned. Use 'const' instead",
+        }
+      ],
+    };
+    assert_lint_err_with_options! {
+      PreferConst,
+      json!({ "destructuring": "any" }),
+      r#"let {a, b} = obj; b = 0;"#: [
+        {
+          col: 5,
+          message: "'a' is never reassigned. Use 'const' instead",
+        }
+      ],
+    };
+  }
 }
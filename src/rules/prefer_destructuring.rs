@@ -0,0 +1,279 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::static_property_name;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, Expr, ExprOrSuper, Lit, MemberExpr, Module, Pat,
+  PatOrExpr, VarDeclarator,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct DestructuringKinds {
+  /// Report accesses by numeric index, as in `const x = arr[0]`.
+  pub array: bool,
+  /// Report reads of a same-named property, as in `const x = obj.x`.
+  pub object: bool,
+}
+
+impl Default for DestructuringKinds {
+  fn default() -> Self {
+    Self {
+      array: true,
+      object: true,
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PreferDestructuringOptions {
+  pub variable_declarator: DestructuringKinds,
+  pub assignment_expression: DestructuringKinds,
+}
+
+pub struct PreferDestructuring {
+  options: PreferDestructuringOptions,
+}
+
+impl PreferDestructuring {
+  pub fn with_options(options: PreferDestructuringOptions) -> Box<Self> {
+    Box::new(PreferDestructuring { options })
+  }
+}
+
+impl LintRule for PreferDestructuring {
+  fn new() -> Box<Self> {
+    Self::with_options(PreferDestructuringOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-destructuring"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferDestructuringVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires destructuring when reading a property into a variable of the same name, or an array element by index.
+
+The `VariableDeclarator` and `AssignmentExpression` options each take `{ "array": boolean, "object": boolean }` (all `true` by default) to choose which cases are reported in declarations and in assignments.
+
+### Invalid:
+```typescript
+const x = obj.x;
+const first = arr[0];
+let y;
+y = obj.y;
+```
+
+### Valid:
+```typescript
+const { x } = obj;
+const [first] = arr;
+const y = obj.x;
+```"#
+  }
+}
+
+struct PreferDestructuringVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferDestructuringOptions,
+}
+
+impl<'c, 'o> PreferDestructuringVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferDestructuringOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check(
+    &mut self,
+    span: Span,
+    name: &str,
+    expr: &Expr,
+    kinds: DestructuringKinds,
+  ) {
+    let member_expr = match expr {
+      Expr::Member(member_expr) => member_expr,
+      _ => return,
+    };
+    if let ExprOrSuper::Super(_) = member_expr.obj {
+      return;
+    }
+
+    if kinds.array && is_index_access(member_expr) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        "prefer-destructuring",
+        "Use array destructuring",
+        "Destructure the element instead, as in `const [x] = arr`",
+      );
+    } else if kinds.object && static_property_name(member_expr) == Some(name) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        "prefer-destructuring",
+        "Use object destructuring",
+        "Destructure the property instead, as in `const { x } = obj`",
+      );
+    }
+  }
+}
+
+fn is_index_access(member_expr: &MemberExpr) -> bool {
+  member_expr.computed && matches!(&*member_expr.prop, Expr::Lit(Lit::Num(_)))
+}
+
+impl<'c, 'o> VisitAll for PreferDestructuringVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let (Pat::Ident(ident), Some(init)) =
+      (&var_declarator.name, &var_declarator.init)
+    {
+      self.check(
+        var_declarator.span,
+        &ident.sym,
+        init,
+        self.options.variable_declarator,
+      );
+    }
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    if assign_expr.op != AssignOp::Assign {
+      return;
+    }
+
+    let name = match &assign_expr.left {
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Ident(ident) => &ident.sym,
+        Pat::Expr(expr) => match &**expr {
+          Expr::Ident(ident) => &ident.sym,
+          _ => return,
+        },
+        _ => return,
+      },
+      PatOrExpr::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => &ident.sym,
+        _ => return,
+      },
+    };
+    self.check(
+      assign_expr.span,
+      name,
+      &assign_expr.right,
+      self.options.assignment_expression,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn prefer_destructuring_valid() {
+    assert_lint_ok! {
+      PreferDestructuring,
+      "const y = obj.x;",
+      "const { x } = obj;",
+      "const [x] = arr;",
+      "const x = obj[x];",
+      "const x = super.x;",
+      "let x; x += obj.x;",
+      "obj.x = other.x;",
+      "let x;",
+    };
+  }
+
+  #[test]
+  fn prefer_destructuring_invalid() {
+    assert_lint_err! {
+      PreferDestructuring,
+      "const x = obj.x;": [
+        {
+          col: 6,
+          message: "Use object destructuring",
+          hint: "Destructure the property instead, as in `const { x } = obj`",
+        }
+      ],
+      "const x = obj['x'];": [
+        {
+          col: 6,
+          message: "Use object destructuring",
+          hint: "Destructure the property instead, as in `const { x } = obj`",
+        }
+      ],
+      "const first = arr[0];": [
+        {
+          col: 6,
+          message: "Use array destructuring",
+          hint: "Destructure the element instead, as in `const [x] = arr`",
+        }
+      ],
+      "let x; x = a.b.x;": [
+        {
+          col: 7,
+          message: "Use object destructuring",
+          hint: "Destructure the property instead, as in `const { x } = obj`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_destructuring_options() {
+    assert_lint_ok_with_options! {
+      PreferDestructuring,
+      json!({ "VariableDeclarator": { "array": false } }),
+      "const x = arr[0];",
+    };
+    assert_lint_err_with_options! {
+      PreferDestructuring,
+      json!({ "VariableDeclarator": { "array": false } }),
+      "const x = obj.x;": [
+        {
+          col: 6,
+          message: "Use object destructuring",
+          hint: "Destructure the property instead, as in `const { x } = obj`",
+        }
+      ],
+    };
+    assert_lint_ok_with_options! {
+      PreferDestructuring,
+      json!({ "AssignmentExpression": { "array": false, "object": false } }),
+      "let x; x = obj.x;",
+    };
+    assert_lint_err_with_options! {
+      PreferDestructuring,
+      json!({ "AssignmentExpression": { "array": false, "object": false } }),
+      "const x = obj.x;": [
+        {
+          col: 6,
+          message: "Use object destructuring",
+          hint: "Destructure the property instead, as in `const { x } = obj`",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,178 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  Module, TsCallSignatureDecl, TsInterfaceDecl, TsType, TsTypeElement,
+  TsTypeLit,
+};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferFunctionType;
+
+impl LintRule for PreferFunctionType {
+  fn new() -> Box<Self> {
+    Box::new(PreferFunctionType)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-function-type"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferFunctionTypeVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces using function types instead of interfaces or type literals with only a call signature.
+
+An interface or a type literal whose only member is a call signature describes a function, which a function type expresses more concisely. Interfaces extending other types are allowed, as they can't be expressed as a function type.
+
+### Invalid:
+```typescript
+interface Fn {
+  (): void;
+}
+type Callback = { (value: string): number };
+```
+
+### Valid:
+```typescript
+type Fn = () => void;
+type Callback = (value: string) => number;
+interface Named {
+  (): void;
+  name: string;
+}
+interface Extended extends Base {
+  (): void;
+}
+```"#
+  }
+}
+
+struct PreferFunctionTypeVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferFunctionTypeVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// If `members` consists of exactly one call signature, returns it.
+fn sole_call_signature(
+  members: &[TsTypeElement],
+) -> Option<&TsCallSignatureDecl> {
+  match members {
+    [TsTypeElement::TsCallSignatureDecl(call_signature)] => {
+      Some(call_signature)
+    }
+    _ => None,
+  }
+}
+
+/// A call signature returning `this` can't be expressed as a function type.
+fn returns_this(call_signature: &TsCallSignatureDecl) -> bool {
+  call_signature
+    .type_ann
+    .as_ref()
+    .map(|type_ann| matches!(&*type_ann.type_ann, TsType::TsThisType(_)))
+    .unwrap_or(false)
+}
+
+impl<'c> VisitAll for PreferFunctionTypeVisitor<'c> {
+  fn visit_ts_interface_decl(
+    &mut self,
+    interface_decl: &TsInterfaceDecl,
+    _parent: &dyn Node,
+  ) {
+    if !interface_decl.extends.is_empty() {
+      return;
+    }
+
+    if let Some(call_signature) = sole_call_signature(&interface_decl.body.body)
+    {
+      if !returns_this(call_signature) {
+        self.context.add_diagnostic_with_hint(
+          interface_decl.span,
+          "prefer-function-type",
+          "Interface only has a call signature, you should use a function type instead",
+          "Replace the interface with `type T = (...) => R`",
+        );
+      }
+    }
+  }
+
+  fn visit_ts_type_lit(&mut self, type_lit: &TsTypeLit, _parent: &dyn Node) {
+    if let Some(call_signature) = sole_call_signature(&type_lit.members) {
+      if !returns_this(call_signature) {
+        self.context.add_diagnostic_with_hint(
+          type_lit.span,
+          "prefer-function-type",
+          "Type literal only has a call signature, you should use a function type instead",
+          "Replace the type literal with `(...) => R`",
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_function_type_valid() {
+    assert_lint_ok! {
+      PreferFunctionType,
+      "type Fn = () => void;",
+      "interface Foo { bar: string; }",
+      "interface Foo { (): void; bar: string; }",
+      "interface Foo extends Bar { (): void; }",
+      "interface Foo { (): this; }",
+      "interface Foo { new (): Foo; }",
+      "type Foo = { (): void; bar: number };",
+      "interface Foo { (): void; (x: number): void; }",
+      "interface Foo {}",
+    };
+  }
+
+  #[test]
+  fn prefer_function_type_invalid() {
+    assert_lint_err! {
+      PreferFunctionType,
+      "interface Fn { (): void }": [
+        {
+          col: 0,
+          message: "Interface only has a call signature, you should use a function type instead",
+          hint: "Replace the interface with `type T = (...) => R`",
+        }
+      ],
+      "export interface Fn<T> { (value: T): string; }": [
+        {
+          col: 7,
+          message: "Interface only has a call signature, you should use a function type instead",
+          hint: "Replace the interface with `type T = (...) => R`",
+        }
+      ],
+      "type Fn = { (value: string): number };": [
+        {
+          col: 10,
+          message: "Type literal only has a call signature, you should use a function type instead",
+          hint: "Replace the type literal with `(...) => R`",
+        }
+      ],
+      "function foo(cb: { (): void }) {}": [
+        {
+          col: 17,
+          message: "Type literal only has a call signature, you should use a function type instead",
+          hint: "Replace the type literal with `(...) => R`",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,282 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, MemberExpr, Module,
+  Regex, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferIncludes;
+
+impl LintRule for PreferIncludes {
+  fn new() -> Box<Self> {
+    Box::new(PreferIncludes)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-includes"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferIncludesVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using `includes` instead of comparing the result of `indexOf` against `-1` or `0`.
+
+`includes` states the intent of checking for existence directly. The rule also reports `RegExp#test` calls on regex literals without any special characters or flags, since these are plain substring checks.
+
+### Invalid:
+```typescript
+a.indexOf(x) !== -1;
+a.indexOf(x) === -1;
+a.indexOf(x) >= 0;
+a.indexOf(x) > -1;
+a.indexOf(x) < 0;
+/foo/.test(str);
+```
+
+### Valid:
+```typescript
+a.includes(x);
+!a.includes(x);
+str.includes("foo");
+a.indexOf(x) > 5;
+/fo+/.test(str);
+```"#
+  }
+}
+
+struct PreferIncludesVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferIncludesVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// If `call_expr` is a `<obj>.<name>(...)` call, returns `<obj>`.
+fn method_receiver<'a>(
+  call_expr: &'a CallExpr,
+  name: &str,
+) -> Option<&'a Expr> {
+  if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**callee
+    {
+      if matches!(&**prop, Expr::Ident(ident) if ident.sym == *name) {
+        return Some(&**obj);
+      }
+    }
+  }
+  None
+}
+
+fn is_index_of_call(expr: &Expr) -> bool {
+  if let Expr::Call(call_expr) = expr {
+    method_receiver(call_expr, "indexOf").is_some()
+      && !call_expr.args.is_empty()
+      && call_expr.args.iter().all(|a| a.spread.is_none())
+  } else {
+    false
+  }
+}
+
+/// Returns the numeric value of `expr` if it is a number literal, optionally
+/// negated.
+fn num_value(expr: &Expr) -> Option<f64> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) => match &**arg {
+      Expr::Lit(Lit::Num(num)) => Some(-num.value),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Swaps the operands of a relational operator, so that `0 <= x` can be
+/// checked as `x >= 0`.
+fn flip(op: BinaryOp) -> BinaryOp {
+  match op {
+    BinaryOp::Lt => BinaryOp::Gt,
+    BinaryOp::LtEq => BinaryOp::GtEq,
+    BinaryOp::Gt => BinaryOp::Lt,
+    BinaryOp::GtEq => BinaryOp::LtEq,
+    op => op,
+  }
+}
+
+/// Checks if `<indexOf call> <op> <value>` is an existence check.
+fn is_existence_check(op: BinaryOp, value: f64) -> bool {
+  use BinaryOp::*;
+  match op {
+    EqEq | EqEqEq | NotEq | NotEqEq | Gt => value == -1.0,
+    GtEq | Lt => value == 0.0,
+    _ => false,
+  }
+}
+
+/// Checks if a regex literal only matches a fixed string.
+fn is_plain_regex(regex: &Regex) -> bool {
+  regex.flags.is_empty()
+    && !regex.exp.is_empty()
+    && !regex.exp.chars().any(|c| "\\^$.*+?()[]{}|/".contains(c))
+}
+
+impl<'c> VisitAll for PreferIncludesVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    let (op, value) = if is_index_of_call(&bin_expr.left) {
+      (bin_expr.op, num_value(&bin_expr.right))
+    } else if is_index_of_call(&bin_expr.right) {
+      (flip(bin_expr.op), num_value(&bin_expr.left))
+    } else {
+      return;
+    };
+
+    if let Some(value) = value {
+      if is_existence_check(op, value) {
+        self.context.add_diagnostic_with_hint(
+          bin_expr.span,
+          "prefer-includes",
+          "Use `includes()` method instead",
+          "Replace the `indexOf` comparison with `includes`",
+        );
+      }
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let Some(Expr::Lit(Lit::Regex(regex))) =
+      method_receiver(call_expr, "test")
+    {
+      if call_expr.args.len() == 1
+        && call_expr.args[0].spread.is_none()
+        && is_plain_regex(regex)
+      {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          "prefer-includes",
+          "Use `String#includes()` method with a string instead",
+          format!(
+            "Replace `/{}/.test(str)` with `str.includes(\"{}\")`",
+            regex.exp, regex.exp
+          ),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_includes_valid() {
+    assert_lint_ok! {
+      PreferIncludes,
+      "a.includes(x);",
+      "a.indexOf(x) > 5;",
+      "a.indexOf(x) === 0;",
+      "a.indexOf(x) >= -1;",
+      "a.indexOf(x) !== b;",
+      "indexOf(x) !== -1;",
+      "a.indexOf() !== -1;",
+      "a.indexOf(...x) !== -1;",
+      "a.lastIndexOf(x) !== -1;",
+      "/fo+/.test(str);",
+      "/foo/i.test(str);",
+      r#"/a\.b/.test(str);"#,
+      "/foo/.test(a, b);",
+      "regex.test(str);",
+    };
+  }
+
+  #[test]
+  fn prefer_includes_invalid() {
+    assert_lint_err! {
+      PreferIncludes,
+      "a.indexOf(x) !== -1;": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "a.indexOf(x) === -1;": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "a.indexOf(x) != -1;": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "a.indexOf(x) >= 0;": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "a.indexOf(x) > -1;": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "if (a.b.indexOf(x, 1) < 0) {}": [
+        {
+          col: 4,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "-1 !== a.indexOf(x);": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "0 <= a.indexOf(x);": [
+        {
+          col: 0,
+          message: "Use `includes()` method instead",
+          hint: "Replace the `indexOf` comparison with `includes`",
+        }
+      ],
+      "/foo/.test(str);": [
+        {
+          col: 0,
+          message: "Use `String#includes()` method with a string instead",
+          hint: "Replace `/foo/.test(str)` with `str.includes(\"foo\")`",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,297 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, AssignPat, AssignPatProp, BinExpr, BinaryOp, Expr, Lit,
+  Module, ReturnStmt, UnaryExpr, UnaryOp, VarDeclarator,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferNullishCoalescingOptions {
+  /// Don't report `||` whose fallback is a string, number, bigint or boolean
+  /// literal, as treating all falsy values alike is often intended there.
+  pub ignore_primitives: bool,
+}
+
+pub struct PreferNullishCoalescing {
+  options: PreferNullishCoalescingOptions,
+}
+
+impl PreferNullishCoalescing {
+  pub fn with_options(options: PreferNullishCoalescingOptions) -> Box<Self> {
+    Box::new(PreferNullishCoalescing { options })
+  }
+}
+
+impl LintRule for PreferNullishCoalescing {
+  fn new() -> Box<Self> {
+    Self::with_options(PreferNullishCoalescingOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-nullish-coalescing"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor =
+      PreferNullishCoalescingVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces using the nullish coalescing operator (`??`) instead of `||` to provide default values.
+
+`a || b` falls back to `b` for every falsy `a`, including `0`, `""` and `false`. When only `null` and `undefined` should be replaced, `a ?? b` is the correct operator.
+
+Since `||` is sometimes intended, this rule only checks expressions used as a value: the right hand side of `=`, variable initializers, default values and `return` arguments. Operands which are obviously booleans are ignored.
+
+The `ignorePrimitives` option (default `false`) skips `||` expressions whose fallback is a string, number, bigint or boolean literal.
+
+### Invalid:
+```typescript
+const timeout = options.timeout || 1000;
+function foo(name = input || "default") {}
+function bar() {
+  return cache || compute();
+}
+```
+
+### Valid:
+```typescript
+const timeout = options.timeout ?? 1000;
+if (a || b) {}
+const ok = x === 1 || y === 2;
+```"#
+  }
+}
+
+struct PreferNullishCoalescingVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferNullishCoalescingOptions,
+}
+
+impl<'c, 'o> PreferNullishCoalescingVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferNullishCoalescingOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_value(&mut self, expr: &Expr) {
+    let bin_expr = match expr {
+      Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::LogicalOr => bin_expr,
+      Expr::Paren(paren_expr) => return self.check_value(&paren_expr.expr),
+      _ => return,
+    };
+
+    if is_boolean(&bin_expr.left) || is_boolean(&bin_expr.right) {
+      return;
+    }
+    if self.options.ignore_primitives && is_primitive_literal(&bin_expr.right) {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      bin_expr.span,
+      "prefer-nullish-coalescing",
+      "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+      "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+    );
+  }
+}
+
+/// Checks if `expr` obviously evaluates to a boolean.
+fn is_boolean(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang, ..
+    }) => true,
+    Expr::Bin(BinExpr {
+      op, left, right, ..
+    }) => match op {
+      BinaryOp::EqEq
+      | BinaryOp::NotEq
+      | BinaryOp::EqEqEq
+      | BinaryOp::NotEqEq
+      | BinaryOp::Lt
+      | BinaryOp::LtEq
+      | BinaryOp::Gt
+      | BinaryOp::GtEq
+      | BinaryOp::In
+      | BinaryOp::InstanceOf => true,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+        is_boolean(left) && is_boolean(right)
+      }
+      _ => false,
+    },
+    Expr::Paren(paren_expr) => is_boolean(&paren_expr.expr),
+    _ => false,
+  }
+}
+
+fn is_primitive_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_))
+    | Expr::Lit(Lit::Num(_))
+    | Expr::Lit(Lit::BigInt(_))
+    | Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    _ => false,
+  }
+}
+
+impl<'c, 'o> VisitAll for PreferNullishCoalescingVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    if assign_expr.op == AssignOp::Assign {
+      self.check_value(&assign_expr.right);
+    }
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let Some(init) = &var_declarator.init {
+      self.check_value(init);
+    }
+  }
+
+  fn visit_assign_pat(&mut self, assign_pat: &AssignPat, _parent: &dyn Node) {
+    self.check_value(&assign_pat.right);
+  }
+
+  fn visit_assign_pat_prop(
+    &mut self,
+    assign_pat_prop: &AssignPatProp,
+    _parent: &dyn Node,
+  ) {
+    if let Some(value) = &assign_pat_prop.value {
+      self.check_value(value);
+    }
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if let Some(arg) = &return_stmt.arg {
+      self.check_value(arg);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn prefer_nullish_coalescing_valid() {
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      "const x = a ?? b;",
+      "if (a || b) {}",
+      "while (a || b) {}",
+      "foo(a || b);",
+      "const x = a === 1 || b === 2;",
+      "const x = !a || !b;",
+      "const x = a || false;",
+      "x ||= y;",
+      "x += a || b;",
+      "function foo() { return a && b; }",
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_invalid() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      "const x = a || b;": [
+        {
+          col: 10,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "const x = a || 0;": [
+        {
+          col: 10,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "x = (options.timeout || 1000);": [
+        {
+          col: 5,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "function foo(name = input || 'default') {}": [
+        {
+          col: 20,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "const { a = b || c } = obj;": [
+        {
+          col: 12,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "function foo() { return cache || compute(); }": [
+        {
+          col: 24,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_ignore_primitives() {
+    assert_lint_ok_with_options! {
+      PreferNullishCoalescing,
+      json!({ "ignorePrimitives": true }),
+      "const x = a || 0;",
+      "const x = a || '';",
+    };
+    assert_lint_err_with_options! {
+      PreferNullishCoalescing,
+      json!({ "ignorePrimitives": true }),
+      "const x = a || b;": [
+        {
+          col: 10,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+      "const x = a || [];": [
+        {
+          col: 10,
+          message: "Prefer using nullish coalescing operator (`??`) instead of a logical or (`||`)",
+          hint: "`??` only falls back for `null` and `undefined`, while `||` also does for `0`, `\"\"` and `false`",
+        }
+      ],
+    };
+  }
+}
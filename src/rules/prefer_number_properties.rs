@@ -0,0 +1,224 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSuper, Ident, MemberExpr, Module, Prop,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferNumberProperties;
+
+impl LintRule for PreferNumberProperties {
+  fn new() -> Box<Self> {
+    Box::new(PreferNumberProperties)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-number-properties"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferNumberPropertiesVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using the `Number` static methods instead of their global counterparts.
+
+ECMAScript 2015 moved `parseInt`, `parseFloat`, `isNaN` and `isFinite` onto the `Number` namespace. `Number.parseInt` and `Number.parseFloat` are the exact same functions as the globals. `Number.isNaN` and `Number.isFinite` however do not coerce their argument to a number, so `isNaN("foo")` is `true` while `Number.isNaN("foo")` is `false`.
+
+### Invalid:
+```typescript
+parseInt("10", 10);
+parseFloat("1.5");
+isNaN(x);
+isFinite(x);
+```
+
+### Valid:
+```typescript
+Number.parseInt("10", 10);
+Number.parseFloat("1.5");
+Number.isNaN(x);
+Number.isFinite(x);
+```"#
+  }
+}
+
+struct PreferNumberPropertiesVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferNumberPropertiesVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Returns the hint for `ident` if it refers to one of the global functions
+  /// available on `Number`.
+  fn check_ident(&self, ident: &Ident) -> Option<&'static str> {
+    let hint = match &*ident.sym {
+      "parseInt" | "parseFloat" => {
+        "The global function and the `Number` method are identical"
+      }
+      "isNaN" | "isFinite" => {
+        "Unlike the global function, the `Number` method does not coerce its argument to a number"
+      }
+      _ => return None,
+    };
+
+    if !self.context.is_global_ident(ident) {
+      return None;
+    }
+
+    Some(hint)
+  }
+
+  fn report(&mut self, span: Span, ident: &Ident) {
+    if let Some(hint) = self.check_ident(ident) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        "prefer-number-properties",
+        format!(
+          "Use `Number.{}` instead of the global `{}`",
+          ident.sym, ident.sym
+        ),
+        hint,
+      );
+    }
+  }
+}
+
+impl<'c> Visit for PreferNumberPropertiesVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Ident(ident) = &**callee {
+        self.report(call_expr.span, ident);
+        call_expr.args.visit_with(call_expr, self);
+        return;
+      }
+    }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.report(ident.span, ident);
+      return;
+    }
+    swc_ecmascript::visit::visit_expr(self, expr, parent);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.report(ident.span, ident);
+      return;
+    }
+    swc_ecmascript::visit::visit_prop(self, prop, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_number_properties_valid() {
+    assert_lint_ok! {
+      PreferNumberProperties,
+      r#"Number.parseInt("1");"#,
+      r#"Number.parseFloat("1.5");"#,
+      "Number.isNaN(x);",
+      "Number.isFinite(x);",
+      "foo.parseInt(x);",
+      "foo.isNaN;",
+      "const o = { parseInt: 1 };",
+      r#"
+function parseInt(s: string) {}
+parseInt("1");
+      "#,
+      r#"
+import { isNaN } from "./utils.ts";
+isNaN(x);
+      "#,
+      r#"
+function foo(isFinite: (n: number) => boolean) {
+  return isFinite(1);
+}
+      "#,
+      r#"
+function foo() {
+  const parseFloat = (s: string) => 1;
+  parseFloat("1.5");
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn prefer_number_properties_invalid() {
+    assert_lint_err! {
+      PreferNumberProperties,
+      r#"parseInt("1");"#: [
+        {
+          col: 0,
+          message: "Use `Number.parseInt` instead of the global `parseInt`",
+          hint: "The global function and the `Number` method are identical",
+        }
+      ],
+      r#"const n = parseFloat("1.5");"#: [
+        {
+          col: 10,
+          message: "Use `Number.parseFloat` instead of the global `parseFloat`",
+          hint: "The global function and the `Number` method are identical",
+        }
+      ],
+      "if (isNaN(x)) {}": [
+        {
+          col: 4,
+          message: "Use `Number.isNaN` instead of the global `isNaN`",
+          hint: "Unlike the global function, the `Number` method does not coerce its argument to a number",
+        }
+      ],
+      "[1, 2].every(isFinite);": [
+        {
+          col: 13,
+          message: "Use `Number.isFinite` instead of the global `isFinite`",
+          hint: "Unlike the global function, the `Number` method does not coerce its argument to a number",
+        }
+      ],
+      "const o = { parseInt };": [
+        {
+          col: 12,
+          message: "Use `Number.parseInt` instead of the global `parseInt`",
+          hint: "The global function and the `Number` method are identical",
+        }
+      ],
+      r#"parseInt(parseFloat("1.5"));"#: [
+        {
+          col: 0,
+          message: "Use `Number.parseInt` instead of the global `parseInt`",
+          hint: "The global function and the `Number` method are identical",
+        },
+        {
+          col: 9,
+          message: "Use `Number.parseFloat` instead of the global `parseFloat`",
+          hint: "The global function and the `Number` method are identical",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,213 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::DropSpan;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, MemberExpr, Module,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct PreferOptionalChain;
+
+impl LintRule for PreferOptionalChain {
+  fn new() -> Box<Self> {
+    Box::new(PreferOptionalChain)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-optional-chain"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferOptionalChainVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces using optional chaining instead of chained `&&` checks.
+
+Checking every step of a property access with `&&` is verbose and easy to get wrong. Optional chaining (`?.`) expresses the same intent concisely.
+
+### Invalid:
+```typescript
+a && a.b && a.b.c;
+foo && foo.bar();
+foo.bar && foo.bar();
+```
+
+### Valid:
+```typescript
+a?.b?.c;
+foo?.bar();
+foo.bar?.();
+a && b && c;
+```"#
+  }
+}
+
+struct PreferOptionalChainVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferOptionalChainVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// Collects the operands of a (left-associative) `&&` chain.
+fn collect_and_operands<'a>(
+  bin_expr: &'a BinExpr,
+  operands: &mut Vec<&'a Expr>,
+) {
+  match &*bin_expr.left {
+    Expr::Bin(left) if left.op == BinaryOp::LogicalAnd => {
+      collect_and_operands(left, operands)
+    }
+    left => operands.push(left),
+  }
+  operands.push(&bin_expr.right);
+}
+
+/// Checks if `expr` can be re-evaluated as an optional chain without changing
+/// behavior, i.e. it consists only of identifiers, `this` and property
+/// accesses with a static key.
+fn is_chainable_base(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed,
+      ..
+    }) => {
+      (!computed
+        || matches!(
+          &**prop,
+          Expr::Ident(_) | Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Num(_))
+        ))
+        && is_chainable_base(obj)
+    }
+    _ => false,
+  }
+}
+
+/// Checks if `expr` is a member or call chain which starts with `base`,
+/// e.g. `a.b.c` or `a.b()` for the base `a`.
+fn extends(expr: &Expr, base: &Expr) -> bool {
+  let inner = match expr {
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      ..
+    }) => &**obj,
+    Expr::Call(CallExpr {
+      callee: ExprOrSuper::Expr(callee),
+      ..
+    }) => &**callee,
+    _ => return false,
+  };
+  inner.clone().drop_span() == base.clone().drop_span() || extends(inner, base)
+}
+
+impl<'c> Visit for PreferOptionalChainVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, parent: &dyn Node) {
+    if bin_expr.op != BinaryOp::LogicalAnd {
+      swc_ecmascript::visit::visit_bin_expr(self, bin_expr, parent);
+      return;
+    }
+
+    let mut operands = vec![];
+    collect_and_operands(bin_expr, &mut operands);
+
+    let found = operands
+      .windows(2)
+      .any(|pair| is_chainable_base(pair[0]) && extends(pair[1], pair[0]));
+    if found {
+      self.context.add_diagnostic_with_hint(
+        bin_expr.span,
+        "prefer-optional-chain",
+        "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+        "Replace `a && a.b` with `a?.b`",
+      );
+    }
+
+    // Nested `&&` expressions of this chain are already checked.
+    for operand in operands {
+      self.visit_expr(operand, bin_expr);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_optional_chain_valid() {
+    assert_lint_ok! {
+      PreferOptionalChain,
+      "a && b && c;",
+      "a?.b?.c;",
+      "a || a.b;",
+      "a && a;",
+      "a.b && a.bc;",
+      "a && b.a;",
+      "foo() && foo().bar;",
+      "a[i++] && a[i++].b;",
+      "a.b && c && a.b.c;",
+    };
+  }
+
+  #[test]
+  fn prefer_optional_chain_invalid() {
+    assert_lint_err! {
+      PreferOptionalChain,
+      "a && a.b && a.b.c;": [
+        {
+          col: 0,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+      "foo && foo.bar();": [
+        {
+          col: 0,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+      "foo.bar && foo.bar();": [
+        {
+          col: 0,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+      "const x = this.a && this.a.b.c;": [
+        {
+          col: 10,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+      "if (x && a[0] && a[0].b) {}": [
+        {
+          col: 4,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+      "f(() => a && a.b);": [
+        {
+          col: 8,
+          message: "Prefer using an optional chain expression instead, as it's more concise and easier to read",
+          hint: "Replace `a && a.b` with `a?.b`",
+        }
+      ],
+    };
+  }
+}
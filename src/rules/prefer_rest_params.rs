@@ -0,0 +1,186 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::{visit_function_depth, FunctionDepth};
+use swc_ecmascript::ast::{
+  Expr, ExprOrSuper, Ident, MemberExpr, Module, PropName,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferRestParams;
+
+impl LintRule for PreferRestParams {
+  fn new() -> Box<Self> {
+    Box::new(PreferRestParams)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-rest-params"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferRestParamsVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `arguments` object in favor of rest parameters.
+
+Rest parameters are a real array and make it clear from the signature that a
+function takes a variable number of arguments. Property accesses like
+`arguments.length` are allowed.
+
+### Invalid:
+```typescript
+function sum() {
+  return Array.from(arguments).reduce((a, b) => a + b, 0);
+}
+function first() {
+  return arguments[0];
+}
+```
+
+### Valid:
+```typescript
+function sum(...args: number[]) {
+  return args.reduce((a, b) => a + b, 0);
+}
+function count() {
+  return arguments.length;
+}
+```"#
+  }
+}
+
+struct PreferRestParamsVisitor<'c> {
+  context: &'c mut Context,
+  function_depth: FunctionDepth,
+}
+
+impl<'c> PreferRestParamsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      function_depth: FunctionDepth::default(),
+    }
+  }
+}
+
+impl<'c> Visit for PreferRestParamsVisitor<'c> {
+  noop_visit_type!();
+
+  visit_function_depth!(function_depth);
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    let is_arguments_property_access = !member_expr.computed
+      && matches!(
+        &member_expr.obj,
+        ExprOrSuper::Expr(expr)
+          if matches!(&**expr, Expr::Ident(ident) if ident.sym == *"arguments")
+      );
+    if !is_arguments_property_access {
+      member_expr.obj.visit_with(member_expr, self);
+    }
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, prop_name: &PropName, _parent: &dyn Node) {
+    if let PropName::Computed(computed_prop_name) = prop_name {
+      computed_prop_name.visit_with(prop_name, self);
+    }
+  }
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    // Arrow functions don't have their own `arguments`.
+    if self.function_depth.non_arrow_depth() > 0
+      && ident.sym == *"arguments"
+      && self.context.scope.var(&ident.to_id()).is_none()
+    {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        "prefer-rest-params",
+        "Use the rest parameters instead of `arguments`",
+        "Declare the parameters as `...args` and use `args` instead",
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_rest_params_valid() {
+    assert_lint_ok! {
+      PreferRestParams,
+      "function f(...args) { return args[0]; }",
+      "function f() { return arguments.length; }",
+      "function f() { return arguments.callee; }",
+      "function f(arguments) { return arguments[0]; }",
+      "function f() { const arguments = []; return arguments[0]; }",
+      "const o = { arguments: 1 }; o.arguments;",
+      "const o = { arguments() {} };",
+      "const f = () => arguments;",
+    };
+  }
+
+  #[test]
+  fn prefer_rest_params_invalid() {
+    assert_lint_err! {
+      PreferRestParams,
+      "function f() { return arguments[0]; }": [
+        {
+          col: 22,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+      "function f() { return Array.from(arguments); }": [
+        {
+          col: 33,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+      "function f() { const g = () => arguments[0]; }": [
+        {
+          col: 31,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+      "class A { m() { return [...arguments]; } }": [
+        {
+          col: 27,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+      "class A { constructor() { this.a = arguments[0]; } }": [
+        {
+          col: 35,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+      "const o = { set a(v) { f(arguments); } };": [
+        {
+          col: 25,
+          message: "Use the rest parameters instead of `arguments`",
+          hint: "Declare the parameters as `...args` and use `args` instead",
+        }
+      ],
+    };
+  }
+}
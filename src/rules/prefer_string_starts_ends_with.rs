@@ -0,0 +1,318 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::DropSpan;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit,
+  MemberExpr, Module, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferStringStartsEndsWith;
+
+impl LintRule for PreferStringStartsEndsWith {
+  fn new() -> Box<Self> {
+    Box::new(PreferStringStartsEndsWith)
+  }
+
+  fn code(&self) -> &'static str {
+    "prefer-string-starts-ends-with"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = PreferStringStartsEndsWithVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using `startsWith` and `endsWith` instead of more convoluted ways of checking for a prefix or a suffix.
+
+The rule is conservative and only reports comparisons where a string literal makes it clear that the receiver is a string.
+
+### Invalid:
+```typescript
+str.indexOf("foo") === 0;
+str.indexOf("foo") !== 0;
+str.charAt(0) === "f";
+str.slice(0, 3) === "foo";
+str.charAt(str.length - 1) === "o";
+str.slice(-3) === "foo";
+```
+
+### Valid:
+```typescript
+str.startsWith("foo");
+!str.startsWith("foo");
+str.endsWith("foo");
+str.indexOf(foo) === 0;
+str.indexOf("foo") === 1;
+```"#
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+  StartsWith,
+  EndsWith,
+}
+
+impl Kind {
+  fn message(&self) -> &'static str {
+    match self {
+      Kind::StartsWith => "Use `startsWith` to check for a string prefix",
+      Kind::EndsWith => "Use `endsWith` to check for a string suffix",
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match self {
+      Kind::StartsWith => {
+        "Replace the comparison with `str.startsWith(prefix)`"
+      }
+      Kind::EndsWith => "Replace the comparison with `str.endsWith(suffix)`",
+    }
+  }
+}
+
+struct PreferStringStartsEndsWithVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferStringStartsEndsWithVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// If `expr` is a call of a non-computed method, returns the receiver,
+/// the method name and the arguments.
+fn as_method_call(expr: &Expr) -> Option<(&Expr, &str, &[ExprOrSpread])> {
+  if let Expr::Call(CallExpr {
+    callee: ExprOrSuper::Expr(callee),
+    args,
+    ..
+  }) = expr
+  {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**callee
+    {
+      if let Expr::Ident(prop) = &**prop {
+        return Some((&**obj, &*prop.sym, args.as_slice()));
+      }
+    }
+  }
+  None
+}
+
+fn is_string_lit(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Str(_)))
+}
+
+/// Returns the length of `expr` in UTF-16 code units, like `String#length`,
+/// if it's a string literal.
+fn string_lit_len(expr: &Expr) -> Option<usize> {
+  match expr {
+    Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.encode_utf16().count()),
+    _ => None,
+  }
+}
+
+fn is_num(expr: &Expr, value: f64) -> bool {
+  matches!(expr, Expr::Lit(Lit::Num(num)) if num.value == value)
+}
+
+/// Checks if `expr` is `-value`.
+fn is_negative_num(expr: &Expr, value: f64) -> bool {
+  matches!(
+    expr,
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) if is_num(arg, value)
+  )
+}
+
+/// Checks if `expr` is `<obj>.length - 1`.
+fn is_last_index_of(expr: &Expr, obj: &Expr) -> bool {
+  if let Expr::Bin(BinExpr {
+    op: BinaryOp::Sub,
+    left,
+    right,
+    ..
+  }) = expr
+  {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(length_obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**left
+    {
+      return matches!(&**prop, Expr::Ident(ident) if ident.sym == *"length")
+        && is_num(right, 1.0)
+        && (**length_obj).clone().drop_span() == obj.clone().drop_span();
+    }
+  }
+  false
+}
+
+/// Checks a `call <op> other` comparison and returns which method should be
+/// used instead, if any.
+fn check_comparison(call: &Expr, other: &Expr) -> Option<Kind> {
+  let (obj, method, args) = as_method_call(call)?;
+  let arg = |i: usize| args.get(i).map(|a| &*a.expr);
+  // The comparisons are only equivalent if the compared string has as many
+  // characters as the call returns.
+  let len = string_lit_len(other);
+  match (method, args.len()) {
+    // str.indexOf("foo") === 0
+    ("indexOf", 1) if is_string_lit(arg(0)?) && is_num(other, 0.0) => {
+      Some(Kind::StartsWith)
+    }
+    // str.charAt(0) === "f"
+    ("charAt", 1) if len == Some(1) && is_num(arg(0)?, 0.0) => {
+      Some(Kind::StartsWith)
+    }
+    // str.charAt(str.length - 1) === "o"
+    ("charAt", 1) if len == Some(1) && is_last_index_of(arg(0)?, obj) => {
+      Some(Kind::EndsWith)
+    }
+    // str.slice(0, 3) === "foo"
+    ("slice", 2) if is_num(arg(0)?, 0.0) && is_num(arg(1)?, len? as f64) => {
+      Some(Kind::StartsWith)
+    }
+    // str.slice(-3) === "foo"
+    ("slice", 1) if len? > 0 && is_negative_num(arg(0)?, len? as f64) => {
+      Some(Kind::EndsWith)
+    }
+    _ => None,
+  }
+}
+
+impl<'c> VisitAll for PreferStringStartsEndsWithVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if !matches!(
+      bin_expr.op,
+      BinaryOp::EqEq | BinaryOp::EqEqEq | BinaryOp::NotEq | BinaryOp::NotEqEq
+    ) {
+      return;
+    }
+
+    let kind = check_comparison(&bin_expr.left, &bin_expr.right)
+      .or_else(|| check_comparison(&bin_expr.right, &bin_expr.left));
+
+    if let Some(kind) = kind {
+      self.context.add_diagnostic_with_hint(
+        bin_expr.span,
+        "prefer-string-starts-ends-with",
+        kind.message(),
+        kind.hint(),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_string_starts_ends_with_valid() {
+    assert_lint_ok! {
+      PreferStringStartsEndsWith,
+      r#"str.startsWith("foo");"#,
+      r#"str.endsWith("foo");"#,
+      r#"str.indexOf(foo) === 0;"#,
+      r#"str.indexOf("foo") === 1;"#,
+      r#"str.indexOf("foo") > 0;"#,
+      r#"str.charAt(1) === "f";"#,
+      r#"str.charAt(0) === c;"#,
+      r#"str.charAt(other.length - 1) === "f";"#,
+      r#"str.slice(1, 3) === "foo";"#,
+      r#"str.slice(3) === "foo";"#,
+      r#"str.slice(0, 3) === foo;"#,
+      r#"indexOf("foo") === 0;"#,
+      r#"str.slice(0, 3) === "fo";"#,
+      r#"str.slice(0, n) === "foo";"#,
+      r#"str.slice(-2) === "foo";"#,
+      r#"str.slice(-0) === "";"#,
+      r#"str.charAt(0) === "fo";"#,
+      r#"str.charAt(0) === "";"#,
+      r#"str.charAt(str.length - 1) === "oo";"#,
+      r#"str.charAt(0) === "👍";"#,
+    };
+  }
+
+  #[test]
+  fn prefer_string_starts_ends_with_invalid() {
+    assert_lint_err! {
+      PreferStringStartsEndsWith,
+      r#"str.indexOf("foo") === 0;"#: [
+        {
+          col: 0,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"if (str.indexOf("foo") !== 0) {}"#: [
+        {
+          col: 4,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"0 == str.indexOf("foo");"#: [
+        {
+          col: 0,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"str.charAt(0) === "f";"#: [
+        {
+          col: 0,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"str.slice(0, 3) !== "foo";"#: [
+        {
+          col: 0,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"str.charAt(str.length - 1) === "o";"#: [
+        {
+          col: 0,
+          message: "Use `endsWith` to check for a string suffix",
+          hint: "Replace the comparison with `str.endsWith(suffix)`",
+        }
+      ],
+      r#"str.slice(0, 2) === "👍";"#: [
+        {
+          col: 0,
+          message: "Use `startsWith` to check for a string prefix",
+          hint: "Replace the comparison with `str.startsWith(prefix)`",
+        }
+      ],
+      r#"a.b.slice(-3) === "foo";"#: [
+        {
+          col: 0,
+          message: "Use `endsWith` to check for a string suffix",
+          hint: "Replace the comparison with `str.endsWith(suffix)`",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,330 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSuper, Ident, Lit, MemberExpr, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RadixMode {
+  /// Require the radix parameter in all `parseInt` calls.
+  Always,
+  /// Disallow a radix of `10`, which is the default.
+  AsNeeded,
+}
+
+impl Default for RadixMode {
+  fn default() -> Self {
+    RadixMode::Always
+  }
+}
+
+pub struct Radix {
+  mode: RadixMode,
+}
+
+impl Radix {
+  pub fn with_options(mode: RadixMode) -> Box<Self> {
+    Box::new(Radix { mode })
+  }
+}
+
+impl LintRule for Radix {
+  fn new() -> Box<Self> {
+    Self::with_options(RadixMode::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "radix"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = RadixVisitor::new(context, self.mode);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the radix parameter of `parseInt`.
+
+Without a radix, `parseInt` guesses the base of the number from the string, as
+in `parseInt("0x10")` which returns `16`. Passing the radix makes the intent
+explicit.
+
+### Options:
+- `"always"` (default): requires the radix parameter.
+- `"as-needed"`: disallows the redundant radix `10`.
+
+### Invalid:
+```typescript
+parseInt("08");
+Number.parseInt("08");
+parseInt("08", 1);
+```
+
+### Valid:
+```typescript
+parseInt("08", 10);
+Number.parseInt("ff", 16);
+```"#
+  }
+}
+
+struct RadixVisitor<'c> {
+  context: &'c mut Context,
+  mode: RadixMode,
+}
+
+impl<'c> RadixVisitor<'c> {
+  fn new(context: &'c mut Context, mode: RadixMode) -> Self {
+    Self { context, mode }
+  }
+
+  /// Checks if `ident` refers to the global named `name`, rather than a
+  /// local binding shadowing it.
+  fn is_global(&self, ident: &Ident, name: &str) -> bool {
+    ident.sym == *name && self.context.is_global_ident(ident)
+  }
+
+  /// Checks if `callee` is the global `parseInt` or `Number.parseInt`.
+  fn is_parse_int(&self, callee: &Expr) -> bool {
+    match callee {
+      Expr::Ident(ident) => self.is_global(ident, "parseInt"),
+      Expr::Member(MemberExpr {
+        obj: ExprOrSuper::Expr(obj),
+        prop,
+        computed: false,
+        ..
+      }) => {
+        matches!(&**obj, Expr::Ident(obj) if self.is_global(obj, "Number"))
+          && matches!(&**prop, Expr::Ident(prop) if prop.sym == *"parseInt")
+      }
+      Expr::Paren(paren_expr) => self.is_parse_int(&paren_expr.expr),
+      _ => false,
+    }
+  }
+}
+
+/// Checks if `radix` is known to be an invalid radix, as in `1` or
+/// `undefined`.
+fn is_invalid_radix(radix: &Expr) -> bool {
+  match radix {
+    Expr::Lit(Lit::Num(num)) => {
+      num.value.fract() != 0.0 || num.value < 2.0 || num.value > 36.0
+    }
+    Expr::Lit(_) => true,
+    Expr::Ident(ident) => ident.sym == *"undefined",
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Void, ..
+    }) => true,
+    _ => false,
+  }
+}
+
+impl<'c> Visit for RadixVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
+
+    let is_parse_int = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => self.is_parse_int(callee),
+      ExprOrSuper::Super(_) => false,
+    };
+    // The arguments can't be checked when they are spread.
+    if !is_parse_int || call_expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return;
+    }
+
+    match call_expr.args.get(1) {
+      None => {
+        if self.mode == RadixMode::Always {
+          self.context.add_diagnostic_with_hint(
+            call_expr.span,
+            "radix",
+            "Missing radix parameter",
+            "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+          );
+        }
+      }
+      Some(radix) if is_invalid_radix(&radix.expr) => {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          "radix",
+          "Invalid radix parameter",
+          "The radix must be an integer between 2 and 36",
+        );
+      }
+      Some(radix) => {
+        let is_default_radix =
+          matches!(&*radix.expr, Expr::Lit(Lit::Num(num)) if num.value == 10.0);
+        if self.mode == RadixMode::AsNeeded && is_default_radix {
+          self.context.add_diagnostic_with_hint(
+            call_expr.span,
+            "radix",
+            "Redundant radix parameter",
+            "Remove the radix parameter, `10` is the default",
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use serde_json::json;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.13.0/tests/lib/rules/radix.js
+  // MIT Licensed.
+
+  #[test]
+  fn radix_valid() {
+    assert_lint_ok! {
+      Radix,
+      r#"parseInt("08", 10);"#,
+      r#"parseInt("10", 2);"#,
+      r#"parseInt("10", 36);"#,
+      r#"parseInt("10", foo);"#,
+      r#"parseInt(...args);"#,
+      r#"Number.parseInt("08", 10);"#,
+      r#"Number.parseInt("10", foo);"#,
+      r#"parseFloat("08");"#,
+      r#"Number.parseFloat("08");"#,
+      r#"foo.parseInt("08");"#,
+      r#"Number[parseInt]("08");"#,
+      r#"function f(parseInt) { parseInt("08"); }"#,
+      r#"const parseInt = (s) => s; parseInt("08");"#,
+      r#"function f(Number) { Number.parseInt("08"); }"#,
+    };
+  }
+
+  #[test]
+  fn radix_invalid() {
+    assert_lint_err! {
+      Radix,
+      r#"parseInt("08");"#: [
+        {
+          col: 0,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        }
+      ],
+      r#"parseInt();"#: [
+        {
+          col: 0,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        }
+      ],
+      r#"Number.parseInt("08");"#: [
+        {
+          col: 0,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        }
+      ],
+      r#"const n = (parseInt)(str);"#: [
+        {
+          col: 10,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        }
+      ],
+      r#"parseInt("10", 1);"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+      r#"parseInt("10", 37);"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+      r#"parseInt("10", 10.5);"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+      r#"parseInt("10", "10");"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+      r#"Number.parseInt("10", undefined);"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+      r#"foo(parseInt(bar(parseInt("1"))));"#: [
+        {
+          col: 4,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        },
+        {
+          col: 17,
+          message: "Missing radix parameter",
+          hint: "Add the radix parameter explicitly, as in `parseInt(x, 10)`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn radix_as_needed() {
+    assert_lint_ok_with_options! {
+      Radix,
+      json!("as-needed"),
+      r#"parseInt("08");"#,
+      r#"parseInt("10", 2);"#,
+      r#"Number.parseInt("08");"#,
+    };
+    assert_lint_err_with_options! {
+      Radix,
+      json!("as-needed"),
+      r#"parseInt("08", 10);"#: [
+        {
+          col: 0,
+          message: "Redundant radix parameter",
+          hint: "Remove the radix parameter, `10` is the default",
+        }
+      ],
+      r#"Number.parseInt("08", 10);"#: [
+        {
+          col: 0,
+          message: "Redundant radix parameter",
+          hint: "Remove the radix parameter, `10` is the default",
+        }
+      ],
+      r#"parseInt("08", 0);"#: [
+        {
+          col: 0,
+          message: "Invalid radix parameter",
+          hint: "The radix must be an integer between 2 and 36",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,213 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, MemberExpr, Module,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequireArraySortCompareOptions {
+  /// Don't report arrays which are known to only contain strings, as sorting
+  /// them without a comparator is well-defined.
+  pub ignore_string_arrays: bool,
+}
+
+pub struct RequireArraySortCompare {
+  options: RequireArraySortCompareOptions,
+}
+
+impl RequireArraySortCompare {
+  pub fn with_options(options: RequireArraySortCompareOptions) -> Box<Self> {
+    Box::new(RequireArraySortCompare { options })
+  }
+}
+
+impl LintRule for RequireArraySortCompare {
+  fn new() -> Box<Self> {
+    Self::with_options(RequireArraySortCompareOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    "require-array-sort-compare"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor =
+      RequireArraySortCompareVisitor::new(context, &self.options);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires a compare function to be passed to `Array#sort` and `Array#toSorted`.
+
+Without a compare function, elements are converted to strings and sorted by their UTF-16 code units, so `[3, 20, 100].sort()` results in `[100, 20, 3]`.
+
+The `ignoreStringArrays` option (default `false`) skips arrays which only contain string literals.
+
+### Invalid:
+```typescript
+arr.sort();
+[3, 20, 100].toSorted();
+```
+
+### Valid:
+```typescript
+arr.sort((a, b) => a - b);
+[3, 20, 100].toSorted((a, b) => a - b);
+```"#
+  }
+}
+
+struct RequireArraySortCompareVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o RequireArraySortCompareOptions,
+}
+
+impl<'c, 'o> RequireArraySortCompareVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o RequireArraySortCompareOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+}
+
+/// If `call_expr` is a `.sort()` or `.toSorted()` call, returns the receiver.
+fn sort_receiver(call_expr: &CallExpr) -> Option<&Expr> {
+  if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+    if let Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) = &**callee
+    {
+      if let Expr::Ident(ident) = &**prop {
+        if ident.sym == *"sort" || ident.sym == *"toSorted" {
+          return Some(&**obj);
+        }
+      }
+    }
+  }
+  None
+}
+
+/// Checks if `expr` is an array literal containing only strings.
+fn is_string_array(expr: &Expr) -> bool {
+  if let Expr::Array(array_lit) = expr {
+    array_lit.elems.iter().all(|elem| match elem {
+      Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+        Expr::Lit(Lit::Str(_)) => true,
+        Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+        _ => false,
+      },
+      _ => false,
+    })
+  } else {
+    false
+  }
+}
+
+impl<'c, 'o> VisitAll for RequireArraySortCompareVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if !call_expr.args.is_empty() {
+      return;
+    }
+
+    if let Some(obj) = sort_receiver(call_expr) {
+      if self.options.ignore_string_arrays && is_string_array(obj) {
+        return;
+      }
+
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        "require-array-sort-compare",
+        "Require a compare argument when sorting",
+        "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn require_array_sort_compare_valid() {
+    assert_lint_ok! {
+      RequireArraySortCompare,
+      "arr.sort((a, b) => a - b);",
+      "arr.toSorted((a, b) => a - b);",
+      "arr.sort(compare);",
+      "sort();",
+      "arr.sort;",
+      "arr.filter();",
+    };
+  }
+
+  #[test]
+  fn require_array_sort_compare_invalid() {
+    assert_lint_err! {
+      RequireArraySortCompare,
+      "arr.sort();": [
+        {
+          col: 0,
+          message: "Require a compare argument when sorting",
+          hint: "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+        }
+      ],
+      "const sorted = [3, 1, 2].toSorted();": [
+        {
+          col: 15,
+          message: "Require a compare argument when sorting",
+          hint: "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+        }
+      ],
+      r#"["b", "a"].sort();"#: [
+        {
+          col: 0,
+          message: "Require a compare argument when sorting",
+          hint: "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn require_array_sort_compare_ignore_string_arrays() {
+    assert_lint_ok_with_options! {
+      RequireArraySortCompare,
+      json!({ "ignoreStringArrays": true }),
+      r#"["b", "a"].sort();"#,
+      "[`b`, `a`].toSorted();",
+    };
+    assert_lint_err_with_options! {
+      RequireArraySortCompare,
+      json!({ "ignoreStringArrays": true }),
+      r#"["b", 1].sort();"#: [
+        {
+          col: 0,
+          message: "Require a compare argument when sorting",
+          hint: "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+        }
+      ],
+      "arr.sort();": [
+        {
+          col: 0,
+          message: "Require a compare argument when sorting",
+          hint: "Pass a compare function, e.g. `(a, b) => a - b` for numbers",
+        }
+      ],
+    };
+  }
+}
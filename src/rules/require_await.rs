@@ -0,0 +1,234 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Span;
+use swc_ecmascript::ast::ArrowExpr;
+use swc_ecmascript::ast::AwaitExpr;
+use swc_ecmascript::ast::ForOfStmt;
+use swc_ecmascript::ast::Function;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct RequireAwait;
+
+impl LintRule for RequireAwait {
+  fn new() -> Box<Self> {
+    Box::new(RequireAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    "require-await"
+  }
+
+  fn lint_module(
+    &self,
+    context: &mut Context,
+    module: &swc_ecmascript::ast::Module,
+  ) {
+    let mut visitor = RequireAwaitVisitor::new(context);
+    visitor.visit_module(module, module);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows async functions that have no await expression.
+
+An `async` function without `await` doesn't need to be `async`, which often
+means that an `await` was forgotten or left behind by a refactoring.
+
+### Invalid:
+```typescript
+async function f1() {
+  doSomething();
+}
+
+const f2 = async () => {
+  doSomething();
+};
+```
+
+### Valid:
+```typescript
+async function f1() {
+  await doSomething();
+}
+
+async function f2() {
+  for await (const num of asyncIterable) {
+    console.log(num);
+  }
+}
+
+function f3() {
+  doSomething();
+}
+```"#
+  }
+}
+
+struct RequireAwaitVisitor<'c> {
+  context: &'c mut Context,
+  /// Whether an `await` was seen in each of the enclosing functions.
+  await_stack: Vec<bool>,
+}
+
+impl<'c> RequireAwaitVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      await_stack: vec![],
+    }
+  }
+
+  fn enter_function(&mut self) {
+    self.await_stack.push(false);
+  }
+
+  fn exit_function(&mut self, is_async: bool, span: Span) {
+    let has_await = self.await_stack.pop().unwrap();
+    if is_async && !has_await {
+      self
+        .context
+        .add_diagnostic_with_hint(span, "require-await", "Async function has no `await` expression", "Remove `async` keyword from the function or use `await` expression inside.");
+    }
+  }
+
+  fn mark_await(&mut self) {
+    if let Some(has_await) = self.await_stack.last_mut() {
+      *has_await = true;
+    }
+  }
+}
+
+impl<'c> Visit for RequireAwaitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, parent: &dyn Node) {
+    self.enter_function();
+    swc_ecmascript::visit::visit_function(self, function, parent);
+    self.exit_function(function.is_async, function.span);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
+    self.enter_function();
+    swc_ecmascript::visit::visit_arrow_expr(self, arrow_expr, parent);
+    self.exit_function(arrow_expr.is_async, arrow_expr.span);
+  }
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr, parent: &dyn Node) {
+    self.mark_await();
+    swc_ecmascript::visit::visit_await_expr(self, await_expr, parent);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, parent: &dyn Node) {
+    if for_of_stmt.await_token.is_some() {
+      self.mark_await();
+    }
+    swc_ecmascript::visit::visit_for_of_stmt(self, for_of_stmt, parent);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn require_await_valid() {
+    assert_lint_ok! {
+      RequireAwait,
+      "function foo() { doSomething(); }",
+      "async function foo() { await doSomething(); }",
+      "async function foo() { return await foo(); }",
+      "const foo = async () => { await doSomething(); };",
+      "const foo = async () => await doSomething();",
+      "const foo = function() { doSomething(); };",
+      "async function foo() { for await (const x of xs) { bar(x); } }",
+      "async function foo() { const bar = () => 1; await bar(); }",
+      "class Foo { async bar() { await baz(); } }",
+      "const obj = { async foo() { await bar(); } };",
+      "async function* foo() { await bar(); yield 1; }",
+      r#"
+async function foo() {
+  function bar() {}
+  await bar();
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn require_await_invalid() {
+    assert_lint_err! {
+      RequireAwait,
+      "async function foo() { doSomething(); }": [
+        {
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "async function foo() {}": [
+        {
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "const foo = async () => { doSomething(); };": [
+        {
+          col: 12,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "const foo = async () => doSomething();": [
+        {
+          col: 12,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "async function* foo() { yield 1; }": [
+        {
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "async function foo() { async function bar() { await baz(); } }": [
+        {
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "async function foo() { const bar = async () => { await baz(); }; }": [
+        {
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      "async function foo() { await bar(async () => { baz(); }); }": [
+        {
+          col: 33,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+      r#"
+async function foo() {
+  doSomething();
+}
+      "#: [
+        {
+          line: 2,
+          col: 0,
+          message: "Async function has no `await` expression",
+          hint: "Remove `async` keyword from the function or use `await` expression inside.",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,224 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_atoms::js_word;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, ImportDecl, Lit, Module,
+  ObjectLit, Prop, PropName, PropOrSpread,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct RequireJsonImportAssertion;
+
+impl LintRule for RequireJsonImportAssertion {
+  fn new() -> Box<Self> {
+    Box::new(RequireJsonImportAssertion)
+  }
+
+  fn code(&self) -> &'static str {
+    "require-json-import-assertion"
+  }
+
+  fn lint_module(&self, context: &mut Context, module: &Module) {
+    let mut visitor = RequireJsonImportAssertionVisitor::new(context);
+    module.visit_all_with(module, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires an import assertion when importing JSON modules.
+
+JSON modules can only be imported with the `type: "json"` assertion, which
+guarantees that the imported file is not executed as JavaScript.
+
+### Invalid:
+```typescript
+import data from "./data.json";
+const config = await import("./config.json");
+```
+
+### Valid:
+```typescript
+import data from "./data.json" assert { type: "json" };
+const config = await import("./config.json", { assert: { type: "json" } });
+```"#
+  }
+}
+
+struct RequireJsonImportAssertionVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> RequireJsonImportAssertionVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// Returns the value of the property `key` of `object_lit`, if it has a static
+/// name.
+fn get_prop<'a>(object_lit: &'a ObjectLit, key: &str) -> Option<&'a Expr> {
+  object_lit.props.iter().find_map(|prop| match prop {
+    PropOrSpread::Prop(prop) => match &**prop {
+      Prop::KeyValue(key_value) => {
+        let name = match &key_value.key {
+          PropName::Ident(ident) => &ident.sym,
+          PropName::Str(str_) => &str_.value,
+          _ => return None,
+        };
+        if name == key {
+          Some(&*key_value.value)
+        } else {
+          None
+        }
+      }
+      _ => None,
+    },
+    PropOrSpread::Spread(_) => None,
+  })
+}
+
+/// Checks if `asserts` contains `type: "json"`.
+fn asserts_json(asserts: &ObjectLit) -> bool {
+  matches!(
+    get_prop(asserts, "type"),
+    Some(Expr::Lit(Lit::Str(str_))) if str_.value == *"json"
+  )
+}
+
+impl<'c> VisitAll for RequireJsonImportAssertionVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_import_decl(
+    &mut self,
+    import_decl: &ImportDecl,
+    _parent: &dyn Node,
+  ) {
+    // swc doesn't parse the `assert` clause of import declarations yet, so
+    // none of the parsed ones has an assertion.
+    if import_decl.type_only || !import_decl.src.value.ends_with(".json") {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      import_decl.span,
+      "require-json-import-assertion",
+      "JSON modules must be imported with an import assertion",
+      r#"Add `assert { type: "json" }` after the module specifier"#,
+    );
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let is_import = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Ident(ident) => ident.sym == js_word!("import"),
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_import {
+      return;
+    }
+
+    match call_expr.args.first() {
+      Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+        Expr::Lit(Lit::Str(specifier))
+          if specifier.value.ends_with(".json") => {}
+        _ => return,
+      },
+      _ => return,
+    }
+    let has_assertion = match call_expr.args.get(1) {
+      Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+        Expr::Object(options) => match get_prop(options, "assert") {
+          Some(Expr::Object(asserts)) => asserts_json(asserts),
+          _ => false,
+        },
+        _ => false,
+      },
+      _ => false,
+    };
+    if has_assertion {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      call_expr.span,
+      "require-json-import-assertion",
+      "JSON modules must be imported with an import assertion",
+      r#"Pass `{ assert: { type: "json" } }` as the second argument"#,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_json_import_assertion_valid() {
+    assert_lint_ok! {
+      RequireJsonImportAssertion,
+      r#"import type { Data } from "./data.json";"#,
+      r#"import mod from "./mod.ts";"#,
+      r#"await import("./data.json", { assert: { type: "json" } });"#,
+      r#"await import("./data.json", { "assert": { "type": "json" } });"#,
+      r#"await import("./mod.ts");"#,
+      "await import(name);",
+    };
+  }
+
+  #[test]
+  #[ignore = "swc cannot parse this at the moment"]
+  fn require_json_import_assertion_valid_static() {
+    assert_lint_ok! {
+      RequireJsonImportAssertion,
+      r#"import data from "./data.json" assert { type: "json" };"#,
+      r#"import data from "./data.json" assert { "type": "json" };"#,
+    };
+  }
+
+  #[test]
+  fn require_json_import_assertion_invalid() {
+    assert_lint_err! {
+      RequireJsonImportAssertion,
+      r#"import data from "./data.json";"#: [
+        {
+          col: 0,
+          message: "JSON modules must be imported with an import assertion",
+          hint: r#"Add `assert { type: "json" }` after the module specifier"#,
+        }
+      ],
+      r#"import "./data.json";"#: [
+        {
+          col: 0,
+          message: "JSON modules must be imported with an import assertion",
+          hint: r#"Add `assert { type: "json" }` after the module specifier"#,
+        }
+      ],
+      r#"const data = await import("./data.json");"#: [
+        {
+          col: 19,
+          message: "JSON modules must be imported with an import assertion",
+          hint: r#"Pass `{ assert: { type: "json" } }` as the second argument"#,
+        }
+      ],
+      r#"import("./data.json", { assert: { type: "css" } });"#: [
+        {
+          col: 0,
+          message: "JSON modules must be imported with an import assertion",
+          hint: r#"Pass `{ assert: { type: "json" } }` as the second argument"#,
+        }
+      ],
+      r#"import("./data.json", { with: { type: "json" } });"#: [
+        {
+          col: 0,
+          message: "JSON modules must be imported with an import assertion",
+          hint: r#"Pass `{ assert: { type: "json" } }` as the second argument"#,
+        }
+      ],
+    };
+  }
+}
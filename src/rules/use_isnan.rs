@@ -1,15 +1,45 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, MemberExpr, SwitchStmt,
+};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct UseIsNaN;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UseIsNaNOptions {
+  /// Report `switch (NaN)` and `case NaN:`.
+  pub enforce_for_switch_case: bool,
+  /// Report `indexOf(NaN)` and `lastIndexOf(NaN)`.
+  pub enforce_for_index_of: bool,
+}
+
+impl Default for UseIsNaNOptions {
+  fn default() -> Self {
+    Self {
+      enforce_for_switch_case: true,
+      enforce_for_index_of: false,
+    }
+  }
+}
+
+pub struct UseIsNaN {
+  options: UseIsNaNOptions,
+}
+
+impl UseIsNaN {
+  pub fn with_options(options: UseIsNaNOptions) -> Box<Self> {
+    Box::new(UseIsNaN { options })
+  }
+}
 
 impl LintRule for UseIsNaN {
   fn new() -> Box<Self> {
-    Box::new(UseIsNaN)
+    Self::with_options(UseIsNaNOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -25,83 +55,80 @@ impl LintRule for UseIsNaN {
     context: &mut Context,
     module: &swc_ecmascript::ast::Module,
   ) {
-    let mut visitor = UseIsNaNVisitor::new(context);
+    let mut visitor = UseIsNaNVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
   }
 }
 
-struct UseIsNaNVisitor<'c> {
+struct UseIsNaNVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o UseIsNaNOptions,
 }
 
-impl<'c> UseIsNaNVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> UseIsNaNVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o UseIsNaNOptions) -> Self {
+    Self { context, options }
   }
-}
 
-fn is_nan_identifier(ident: &swc_ecmascript::ast::Ident) -> bool {
-  ident.sym == *"NaN"
+  /// Checks if `expr` is the global `NaN` or `Number.NaN`.
+  fn is_nan(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => {
+        ident.sym == *"NaN" && self.context.is_global_ident(ident)
+      }
+      Expr::Member(MemberExpr {
+        obj: ExprOrSuper::Expr(obj),
+        prop,
+        computed: false,
+        ..
+      }) => {
+        matches!(&**obj, Expr::Ident(obj) if obj.sym == *"Number")
+          && matches!(&**prop, Expr::Ident(prop) if prop.sym == *"NaN")
+      }
+      _ => false,
+    }
+  }
 }
 
-impl<'c> Visit for UseIsNaNVisitor<'c> {
+impl<'c, 'o> Visit for UseIsNaNVisitor<'c, 'o> {
   noop_visit_type!();
 
-  fn visit_bin_expr(
-    &mut self,
-    bin_expr: &swc_ecmascript::ast::BinExpr,
-    _parent: &dyn Node,
-  ) {
-    if bin_expr.op == swc_ecmascript::ast::BinaryOp::EqEq
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::NotEq
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::EqEqEq
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::NotEqEq
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::Lt
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::LtEq
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::Gt
-      || bin_expr.op == swc_ecmascript::ast::BinaryOp::GtEq
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, parent: &dyn Node) {
+    if matches!(
+      bin_expr.op,
+      BinaryOp::EqEq
+        | BinaryOp::NotEq
+        | BinaryOp::EqEqEq
+        | BinaryOp::NotEqEq
+        | BinaryOp::Lt
+        | BinaryOp::LtEq
+        | BinaryOp::Gt
+        | BinaryOp::GtEq
+    ) && (self.is_nan(&bin_expr.left) || self.is_nan(&bin_expr.right))
     {
-      if let swc_ecmascript::ast::Expr::Ident(ident) = &*bin_expr.left {
-        if is_nan_identifier(&ident) {
-          self.context.add_diagnostic(
-            bin_expr.span,
-            "use-isnan",
-            "Use the isNaN function to compare with NaN",
-          );
-        }
-      }
-      if let swc_ecmascript::ast::Expr::Ident(ident) = &*bin_expr.right {
-        if is_nan_identifier(&ident) {
-          self.context.add_diagnostic(
-            bin_expr.span,
-            "use-isnan",
-            "Use the isNaN function to compare with NaN",
-          );
-        }
-      }
+      self.context.add_diagnostic_with_hint(
+        bin_expr.span,
+        "use-isnan",
+        "Use the isNaN function to compare with NaN",
+        "Use `Number.isNaN(x)` instead",
+      );
     }
+    swc_ecmascript::visit::visit_bin_expr(self, bin_expr, parent);
   }
 
-  fn visit_switch_stmt(
-    &mut self,
-    switch_stmt: &swc_ecmascript::ast::SwitchStmt,
-    _parent: &dyn Node,
-  ) {
-    if let swc_ecmascript::ast::Expr::Ident(ident) = &*switch_stmt.discriminant
-    {
-      if is_nan_identifier(&ident) {
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, parent: &dyn Node) {
+    if self.options.enforce_for_switch_case {
+      if self.is_nan(&switch_stmt.discriminant) {
         self.context.add_diagnostic(
           switch_stmt.span,
           "use-isnan",
           "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch",
         );
       }
-    }
 
-    for case in &switch_stmt.cases {
-      if let Some(expr) = &case.test {
-        if let swc_ecmascript::ast::Expr::Ident(ident) = &**expr {
-          if is_nan_identifier(ident) {
+      for case in &switch_stmt.cases {
+        if let Some(expr) = &case.test {
+          if self.is_nan(expr) {
             self.context.add_diagnostic(
               case.span,
               "use-isnan",
@@ -111,6 +138,34 @@ impl<'c> Visit for UseIsNaNVisitor<'c> {
         }
       }
     }
+    swc_ecmascript::visit::visit_switch_stmt(self, switch_stmt, parent);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, parent: &dyn Node) {
+    if self.options.enforce_for_index_of && call_expr.args.len() == 1 {
+      if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+        if let Expr::Member(MemberExpr {
+          prop,
+          computed: false,
+          ..
+        }) = &**callee
+        {
+          if let Expr::Ident(prop) = &**prop {
+            if (prop.sym == *"indexOf" || prop.sym == *"lastIndexOf")
+              && self.is_nan(&call_expr.args[0].expr)
+            {
+              self.context.add_diagnostic_with_hint(
+                call_expr.span,
+                "use-isnan",
+                format!("'{}(NaN)' always returns -1", prop.sym),
+                "Use `findIndex((x) => Number.isNaN(x))` instead",
+              );
+            }
+          }
+        }
+      }
+    }
+    swc_ecmascript::visit::visit_call_expr(self, call_expr, parent);
   }
 }
 
@@ -118,10 +173,26 @@ impl<'c> Visit for UseIsNaNVisitor<'c> {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
+
+  #[test]
+  fn use_isnan_valid() {
+    assert_lint_ok! {
+      UseIsNaN,
+      "Number.isNaN(x);",
+      "isNaN(x);",
+      "x === y;",
+      "x === foo.NaN;",
+      "arr.indexOf(NaN);",
+      "function foo(NaN: number) { return x === NaN; }",
+    };
+  }
 
   #[test]
   fn use_isnan_invalid() {
     assert_lint_err::<UseIsNaN>("42 === NaN", 0);
+    assert_lint_err::<UseIsNaN>("x !== Number.NaN", 0);
+    assert_lint_err::<UseIsNaN>("if (NaN < x) {}", 4);
     assert_lint_err_on_line_n::<UseIsNaN>(
       r#"
 switch (NaN) {
@@ -133,5 +204,66 @@ switch (NaN) {
       "#,
       vec![(2, 0), (3, 2)],
     );
+    assert_lint_err_on_line::<UseIsNaN>(
+      r#"
+switch (x) {
+  case Number.NaN:
+    break;
+}
+      "#,
+      3,
+      2,
+    );
+  }
+
+  #[test]
+  fn use_isnan_hint() {
+    assert_lint_err! {
+      UseIsNaN,
+      "x === NaN;": [
+        {
+          col: 0,
+          message: "Use the isNaN function to compare with NaN",
+          hint: "Use `Number.isNaN(x)` instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn use_isnan_enforce_for_switch_case_disabled() {
+    assert_lint_ok_with_options! {
+      UseIsNaN,
+      json!({ "enforceForSwitchCase": false }),
+      "switch (NaN) { case NaN: break; }",
+    };
+  }
+
+  #[test]
+  fn use_isnan_enforce_for_index_of() {
+    assert_lint_ok_with_options! {
+      UseIsNaN,
+      json!({ "enforceForIndexOf": true }),
+      "arr.indexOf(x);",
+      "arr.indexOf(NaN, 1);",
+    };
+    assert_lint_err_with_options! {
+      UseIsNaN,
+      json!({ "enforceForIndexOf": true }),
+      "arr.indexOf(NaN);": [
+        {
+          col: 0,
+          message: "'indexOf(NaN)' always returns -1",
+          hint: "Use `findIndex((x) => Number.isNaN(x))` instead",
+        }
+      ],
+      "const i = arr.lastIndexOf(Number.NaN);": [
+        {
+          col: 10,
+          message: "'lastIndexOf(NaN)' always returns -1",
+          hint: "Use `findIndex((x) => Number.isNaN(x))` instead",
+        }
+      ],
+    };
   }
 }
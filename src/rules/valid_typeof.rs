@@ -1,18 +1,35 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use serde::Deserialize;
 use swc_common::Spanned;
 use swc_ecmascript::ast::BinaryOp::{EqEq, EqEqEq, NotEq, NotEqEq};
-use swc_ecmascript::ast::Expr::{Lit, Unary};
+use swc_ecmascript::ast::Expr::{Lit, Tpl, Unary};
 use swc_ecmascript::ast::Lit::Str;
 use swc_ecmascript::ast::UnaryOp::TypeOf;
 use swc_ecmascript::ast::{BinExpr, Module};
 use swc_ecmascript::visit::{noop_visit_type, Node, Visit};
 
-pub struct ValidTypeof;
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ValidTypeofOptions {
+  /// Also report comparisons with anything other than a string literal or
+  /// another `typeof` expression.
+  pub require_string_literals: bool,
+}
+
+pub struct ValidTypeof {
+  options: ValidTypeofOptions,
+}
+
+impl ValidTypeof {
+  pub fn with_options(options: ValidTypeofOptions) -> Box<Self> {
+    Box::new(ValidTypeof { options })
+  }
+}
 
 impl LintRule for ValidTypeof {
   fn new() -> Box<Self> {
-    Box::new(ValidTypeof)
+    Self::with_options(ValidTypeofOptions::default())
   }
 
   fn tags(&self) -> &[&'static str] {
@@ -24,7 +41,7 @@ impl LintRule for ValidTypeof {
   }
 
   fn lint_module(&self, context: &mut Context, module: &Module) {
-    let mut visitor = ValidTypeofVisitor::new(context);
+    let mut visitor = ValidTypeofVisitor::new(context, &self.options);
     visitor.visit_module(module, module);
   }
 
@@ -41,7 +58,9 @@ When used with a value the `typeof` operator returns one of the following string
 - `"symbol"`
 - `"bigint"`
 
-This rule disallows comparison with anything other than one of these string literals when using the `typeof` operator, as this likely represents a typing mistake in the string. The rule also disallows comparing the result of a `typeof` operation with any non-string literal value, such as `undefined`, which can represent an inadvertent use of a keyword instead of a string. This includes comparing against string variables even if they contain one of the above values as this cannot be guaranteed. An exception to this is comparing the results of two `typeof` operations as these are both guaranteed to return on of the above strings.
+This rule disallows comparison with any string literal other than one of these, as this likely represents a typing mistake in the string. Comparing the results of two `typeof` operations is always allowed as these are both guaranteed to return one of the above strings.
+
+With the `requireStringLiterals` option (default `false`), the rule also disallows comparing the result of a `typeof` operation with any non-string literal value, such as `undefined`, which can represent an inadvertent use of a keyword instead of a string. This includes comparing against string variables even if they contain one of the above values as this cannot be guaranteed.
 
 ### Invalid:
 ```typescript
@@ -56,6 +75,8 @@ typeof bar != "nunber"
 ```typescript
 typeof bar !== "fucntion"
 ```
+
+### Invalid with `requireStringLiterals`:
 ```typescript
 typeof foo === undefined
 ```
@@ -85,17 +106,18 @@ typeof bar === typeof qux
   }
 }
 
-struct ValidTypeofVisitor<'c> {
+struct ValidTypeofVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o ValidTypeofOptions,
 }
 
-impl<'c> ValidTypeofVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> ValidTypeofVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o ValidTypeofOptions) -> Self {
+    Self { context, options }
   }
 }
 
-impl<'c> Visit for ValidTypeofVisitor<'c> {
+impl<'c, 'o> Visit for ValidTypeofVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
@@ -118,13 +140,23 @@ impl<'c> Visit for ValidTypeofVisitor<'c> {
               );
             }
           }
-          _ => {
+          Tpl(tpl) if tpl.exprs.is_empty() => {
+            if !is_valid_typeof_string(&tpl.quasis[0].raw.value) {
+              self.context.add_diagnostic(
+                tpl.span,
+                "valid-typeof",
+                "Invalid typeof comparison value",
+              );
+            }
+          }
+          _ if self.options.require_string_literals => {
             self.context.add_diagnostic(
               operand.span(),
               "valid-typeof",
               "Invalid typeof comparison value",
             );
           }
+          _ => {}
         }
       }
       _ => {}
@@ -132,7 +164,7 @@ impl<'c> Visit for ValidTypeofVisitor<'c> {
   }
 }
 
-fn is_valid_typeof_string(str: &str) -> bool {
+pub(crate) fn is_valid_typeof_string(str: &str) -> bool {
   matches!(
     str,
     "undefined"
@@ -160,6 +192,7 @@ impl EqExpr for BinExpr {
 mod tests {
   use super::*;
   use crate::test_util::*;
+  use serde_json::json;
 
   #[test]
   fn valid_typeof_valid() {
@@ -170,6 +203,10 @@ typeof foo === "string"
 typeof bar == "undefined"
       "#,
       r#"typeof bar === typeof qux"#,
+      r#"typeof foo === `string`"#,
+      r#"typeof foo === undefined"#,
+      r#"typeof bar == Object"#,
+      r#"typeof baz === anotherVariable"#,
     };
   }
 
@@ -179,8 +216,51 @@ typeof bar == "undefined"
     assert_lint_err::<ValidTypeof>(r#"typeof foo == "undefimed""#, 14);
     assert_lint_err::<ValidTypeof>(r#"typeof bar != "nunber""#, 14);
     assert_lint_err::<ValidTypeof>(r#"typeof bar !== "fucntion""#, 15);
-    assert_lint_err::<ValidTypeof>(r#"typeof foo === undefined"#, 15);
-    assert_lint_err::<ValidTypeof>(r#"typeof bar == Object"#, 14);
-    assert_lint_err::<ValidTypeof>(r#"typeof baz === anotherVariable"#, 15);
+    assert_lint_err::<ValidTypeof>(r#"typeof foo === `strnig`"#, 15);
+  }
+
+  #[test]
+  fn valid_typeof_require_string_literals() {
+    assert_lint_ok_with_options! {
+      ValidTypeof,
+      json!({ "requireStringLiterals": true }),
+      r#"typeof foo === "string""#,
+      r#"typeof foo === `string`"#,
+      r#"typeof bar === typeof qux"#,
+    };
+    assert_lint_err_with_options! {
+      ValidTypeof,
+      json!({ "requireStringLiterals": true }),
+      r#"typeof foo === undefined"#: [
+        {
+          col: 15,
+          message: "Invalid typeof comparison value",
+        }
+      ],
+      r#"typeof bar == Object"#: [
+        {
+          col: 14,
+          message: "Invalid typeof comparison value",
+        }
+      ],
+      r#"typeof baz === anotherVariable"#: [
+        {
+          col: 15,
+          message: "Invalid typeof comparison value",
+        }
+      ],
+      r#"typeof foo == 5"#: [
+        {
+          col: 14,
+          message: "Invalid typeof comparison value",
+        }
+      ],
+      r#"typeof foo === "strnig""#: [
+        {
+          col: 15,
+          message: "Invalid typeof comparison value",
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,87 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use std::sync::Arc;
+use swc_common::SourceFile;
+
+/// The source code of a linted file. It shares the text and the index of
+/// lines with the `SourceFile` the parser created, instead of copying them.
+#[derive(Debug)]
+pub(crate) struct SourceText {
+  file: Arc<SourceFile>,
+}
+
+impl SourceText {
+  pub fn new(file: Arc<SourceFile>) -> Self {
+    Self { file }
+  }
+
+  pub fn text(&self) -> &str {
+    &self.file.src
+  }
+
+  /// Returns the text of the 1-based `line`, without its line break. Lines
+  /// are separated by `\n`, like in `LintDiagnostic::range`.
+  pub fn line_text(&self, line: usize) -> Option<&str> {
+    let index = line.checked_sub(1)?;
+    let start = self.line_start(index)?;
+    let end = self
+      .line_start(index + 1)
+      .map_or(self.text().len(), |next_start| next_start - 1);
+    let line_text = &self.text()[start..end];
+    Some(line_text.strip_suffix('\r').unwrap_or(line_text))
+  }
+
+  /// Returns the byte offset of the start of the 0-based `index`th line.
+  fn line_start(&self, index: usize) -> Option<usize> {
+    let line_start = *self.file.lines.get(index)?;
+    Some((line_start - self.file.start_pos).0 as usize)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_common::{FileName, SourceMap};
+
+  fn source_text(text: &str) -> SourceText {
+    let source_map = SourceMap::default();
+    // Add another file first, so the lines don't start at offset 0.
+    source_map.new_source_file(FileName::Anon, "a;\n".to_string());
+    SourceText::new(
+      source_map.new_source_file(FileName::Anon, text.to_string()),
+    )
+  }
+
+  #[test]
+  fn line_text() {
+    let source = source_text("const é = 1;\r\n\n// 👍\nfoo(\"ü\");");
+    assert_eq!(source.line_text(0), None);
+    assert_eq!(source.line_text(1), Some("const é = 1;"));
+    assert_eq!(source.line_text(2), Some(""));
+    assert_eq!(source.line_text(3), Some("// 👍"));
+    assert_eq!(source.line_text(4), Some("foo(\"ü\");"));
+    assert_eq!(source.line_text(5), None);
+  }
+
+  #[test]
+  fn line_text_trailing_newline() {
+    let source = source_text("a;\nb;\n");
+    assert_eq!(source.line_text(1), Some("a;"));
+    assert_eq!(source.line_text(2), Some("b;"));
+    assert_eq!(source.line_text(3), Some(""));
+    assert_eq!(source.line_text(4), None);
+
+    let source = source_text("");
+    assert_eq!(source.line_text(1), Some(""));
+    assert_eq!(source.line_text(2), None);
+  }
+
+  #[test]
+  fn text() {
+    let text = "a;\nb;";
+    let source = source_text(text);
+    assert_eq!(source.text(), text);
+    assert_eq!(source.line_start(0), Some(0));
+    assert_eq!(source.line_start(1), Some(3));
+    assert_eq!(source.line_start(2), None);
+  }
+}
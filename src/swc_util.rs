@@ -2,7 +2,7 @@
 use crate::scopes::Scope;
 use std::error::Error;
 use std::fmt;
-use std::rc::Rc;
+use std::sync::Arc;
 use std::sync::RwLock;
 use swc_common::comments::SingleThreadedComments;
 use swc_common::errors::Diagnostic;
@@ -18,7 +18,7 @@ use swc_common::DUMMY_SP;
 use swc_common::{Mark, GLOBALS};
 use swc_ecmascript::ast::{
   ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr, PatOrExpr,
-  PrivateName, Prop, PropName, PropOrSpread, Str, Tpl,
+  PrivateName, Prop, PropName, PropOrSpread, Str, Tpl, TsType,
 };
 use swc_ecmascript::parser::lexer::Lexer;
 use swc_ecmascript::parser::EsConfig;
@@ -58,6 +58,15 @@ pub fn get_default_ts_config() -> Syntax {
   Syntax::Typescript(ts_config)
 }
 
+/// The default TypeScript config with JSX enabled, for `.tsx` files.
+pub fn get_default_tsx_config() -> Syntax {
+  let mut ts_config = TsConfig::default();
+  ts_config.tsx = true;
+  ts_config.dynamic_import = true;
+  ts_config.decorators = true;
+  Syntax::Typescript(ts_config)
+}
+
 #[derive(Clone, Debug)]
 pub struct SwcDiagnosticBuffer {
   pub diagnostics: Vec<String>,
@@ -106,11 +115,11 @@ impl SwcDiagnosticBuffer {
 }
 
 #[derive(Clone)]
-pub(crate) struct SwcErrorBuffer(Rc<RwLock<Vec<Diagnostic>>>);
+pub(crate) struct SwcErrorBuffer(Arc<RwLock<Vec<Diagnostic>>>);
 
 impl SwcErrorBuffer {
   pub(crate) fn default() -> Self {
-    Self(Rc::new(RwLock::new(vec![])))
+    Self(Arc::new(RwLock::new(vec![])))
   }
 }
 
@@ -126,7 +135,7 @@ impl Emitter for SwcErrorBuffer {
 /// to `parse_module`.
 pub(crate) struct AstParser {
   pub(crate) buffered_error: SwcErrorBuffer,
-  pub(crate) source_map: Rc<SourceMap>,
+  pub(crate) source_map: Arc<SourceMap>,
   pub(crate) handler: Handler,
   pub(crate) globals: Globals,
   /// The marker passed to the resolver (from swc).
@@ -154,7 +163,7 @@ impl AstParser {
 
     AstParser {
       buffered_error,
-      source_map: Rc::new(SourceMap::default()),
+      source_map: Arc::new(SourceMap::default()),
       handler,
       globals,
       top_level_mark,
@@ -244,6 +253,13 @@ impl DropSpan for Expr {
   }
 }
 
+impl DropSpan for TsType {
+  fn drop_span(self) -> Self {
+    let mut dropper = SpanDropper;
+    dropper.fold_ts_type(self)
+  }
+}
+
 /// Extracts regex string from an expression, using ScopeManager.
 /// If the passed expression is not regular expression, this will return `None`.
 pub(crate) fn extract_regex(
@@ -386,3 +402,199 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Returns the name of the property accessed by `member_expr` if it's static,
+/// as in `obj.prop` or `obj["prop"]`.
+pub(crate) fn static_property_name(member_expr: &MemberExpr) -> Option<&str> {
+  match (&*member_expr.prop, member_expr.computed) {
+    (Expr::Ident(ident), false) => Some(&ident.sym),
+    (Expr::Lit(Lit::Str(str_)), true) => Some(&str_.value),
+    _ => None,
+  }
+}
+
+/// Whether the expression is an async function or arrow function expression,
+/// ignoring any parentheses around it.
+pub(crate) fn is_async_function(expr: &Expr) -> bool {
+  match expr {
+    Expr::Fn(fn_expr) => fn_expr.function.is_async,
+    Expr::Arrow(arrow_expr) => arrow_expr.is_async,
+    Expr::Paren(paren_expr) => is_async_function(&paren_expr.expr),
+    _ => false,
+  }
+}
+
+/// Counts the functions enclosing the node being visited, for visitors which
+/// need to know whether they are inside a function. The `Visit` methods
+/// updating it are implemented with `visit_function_depth!`.
+#[derive(Debug, Default)]
+pub struct FunctionDepth {
+  depth: usize,
+  arrow_depth: usize,
+}
+
+impl FunctionDepth {
+  /// Returns the number of enclosing functions, arrow functions included.
+  pub fn depth(&self) -> usize {
+    self.depth
+  }
+
+  /// Returns the number of enclosing functions which aren't arrow functions,
+  /// which are the ones having their own `this` and `arguments`.
+  pub fn non_arrow_depth(&self) -> usize {
+    self.depth - self.arrow_depth
+  }
+
+  pub fn enter(&mut self, is_arrow: bool) {
+    self.depth += 1;
+    if is_arrow {
+      self.arrow_depth += 1;
+    }
+  }
+
+  pub fn exit(&mut self, is_arrow: bool) {
+    self.depth -= 1;
+    if is_arrow {
+      self.arrow_depth -= 1;
+    }
+  }
+}
+
+/// Implements the `Visit` methods of functions, arrow functions, class
+/// constructors and object literal accessors so that they keep the
+/// `FunctionDepth` in the field `$field` of the visitor up to date.
+///
+/// The visitor can't implement these methods itself.
+macro_rules! visit_function_depth {
+  ($field:ident) => {
+    fn visit_function(
+      &mut self,
+      function: &swc_ecmascript::ast::Function,
+      _parent: &dyn swc_ecmascript::visit::Node,
+    ) {
+      self.$field.enter(false);
+      swc_ecmascript::visit::VisitWith::visit_children_with(function, self);
+      self.$field.exit(false);
+    }
+
+    fn visit_arrow_expr(
+      &mut self,
+      arrow_expr: &swc_ecmascript::ast::ArrowExpr,
+      _parent: &dyn swc_ecmascript::visit::Node,
+    ) {
+      self.$field.enter(true);
+      swc_ecmascript::visit::VisitWith::visit_children_with(arrow_expr, self);
+      self.$field.exit(true);
+    }
+
+    fn visit_constructor(
+      &mut self,
+      constructor: &swc_ecmascript::ast::Constructor,
+      _parent: &dyn swc_ecmascript::visit::Node,
+    ) {
+      self.$field.enter(false);
+      swc_ecmascript::visit::VisitWith::visit_children_with(constructor, self);
+      self.$field.exit(false);
+    }
+
+    fn visit_getter_prop(
+      &mut self,
+      getter_prop: &swc_ecmascript::ast::GetterProp,
+      _parent: &dyn swc_ecmascript::visit::Node,
+    ) {
+      self.$field.enter(false);
+      swc_ecmascript::visit::VisitWith::visit_children_with(getter_prop, self);
+      self.$field.exit(false);
+    }
+
+    fn visit_setter_prop(
+      &mut self,
+      setter_prop: &swc_ecmascript::ast::SetterProp,
+      _parent: &dyn swc_ecmascript::visit::Node,
+    ) {
+      self.$field.enter(false);
+      swc_ecmascript::visit::VisitWith::visit_children_with(setter_prop, self);
+      self.$field.exit(false);
+    }
+  };
+}
+
+pub(crate) use visit_function_depth;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+  use swc_ecmascript::ast::Ident;
+  use swc_ecmascript::visit::{noop_visit_type, Node, Visit};
+
+  /// Records the depths at every identifier named `here`.
+  #[derive(Default)]
+  struct DepthRecorder {
+    function_depth: FunctionDepth,
+    depths: Vec<(usize, usize)>,
+  }
+
+  impl Visit for DepthRecorder {
+    noop_visit_type!();
+    visit_function_depth!(function_depth);
+
+    fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+      if ident.sym == *"here" {
+        self.depths.push((
+          self.function_depth.depth(),
+          self.function_depth.non_arrow_depth(),
+        ));
+      }
+    }
+  }
+
+  fn depths(source_code: &str) -> Vec<(usize, usize)> {
+    let module = parse(source_code);
+    let mut recorder = DepthRecorder::default();
+    recorder.visit_module(&module, &module);
+    recorder.depths
+  }
+
+  #[test]
+  fn function_depth() {
+    assert_eq!(depths("here;"), vec![(0, 0)]);
+    assert_eq!(
+      depths("function f() { here; function g() { here; } here; } here;"),
+      vec![(1, 1), (2, 2), (1, 1), (0, 0)]
+    );
+    assert_eq!(
+      depths("const f = () => { here; return () => here; };"),
+      vec![(1, 0), (2, 0)]
+    );
+    assert_eq!(
+      depths("function f() { const g = () => here; }"),
+      vec![(2, 1)]
+    );
+  }
+
+  #[test]
+  fn function_depth_methods() {
+    assert_eq!(
+      depths(
+        r#"
+class A {
+  constructor() { here; }
+  m() { here; }
+  get g() { return () => here; }
+  #p() { here; }
+}
+        "#
+      ),
+      vec![(1, 1), (1, 1), (2, 1), (1, 1)]
+    );
+    assert_eq!(
+      depths(
+        "const o = { m() { here; }, get g() { here; }, set s(v) { here; } };"
+      ),
+      vec![(1, 1), (1, 1), (1, 1)]
+    );
+    // Default parameters are evaluated in the scope of the function.
+    assert_eq!(depths("function f(a = here) {}"), vec![(1, 1)]);
+  }
+}
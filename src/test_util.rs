@@ -2,8 +2,10 @@
 
 use crate::diagnostic::LintDiagnostic;
 use crate::linter::LinterBuilder;
+use crate::rules::get_rule_with_options;
 use crate::rules::LintRule;
 use crate::swc_util;
+use serde_json::Value;
 use std::marker::PhantomData;
 use swc_ecmascript::ast::Module;
 
@@ -16,6 +18,18 @@ macro_rules! assert_lint_ok {
   };
 }
 
+#[macro_export]
+macro_rules! assert_lint_ok_with_options {
+  ($rule:ty, $options:expr, $($src:literal),* $(,)?) => {
+    $(
+      $crate::test_util::assert_lint_ok_with_options::<$rule>(
+        $src,
+        $options.clone(),
+      );
+    )*
+  };
+}
+
 #[macro_export]
 macro_rules! assert_lint_err {
   (
@@ -43,6 +57,43 @@ macro_rules! assert_lint_err {
       let t = $crate::test_util::LintErrTester::<$rule> {
         src: $src,
         errors,
+        options: None,
+        rule: std::marker::PhantomData,
+      };
+      t.run();
+    )*
+  };
+}
+
+#[macro_export]
+macro_rules! assert_lint_err_with_options {
+  (
+    $rule:ty,
+    $options:expr,
+    $(
+      $src:literal : [
+        $(
+          {
+            $($field:ident : $value:expr),* $(,)?
+          }
+        ),* $(,)?
+      ]
+    ),+ $(,)?
+  ) => {
+    $(
+      let mut errors = Vec::new();
+      $(
+        let mut builder = $crate::test_util::LintErrBuilder::new();
+        $(
+          builder.$field($value);
+        )*
+        let e = builder.build();
+        errors.push(e);
+      )*
+      let t = $crate::test_util::LintErrTester::<$rule> {
+        src: $src,
+        errors,
+        options: Some($options.clone()),
         rule: std::marker::PhantomData,
       };
       t.run();
@@ -54,6 +105,8 @@ macro_rules! assert_lint_err {
 pub struct LintErrTester<T: LintRule + 'static> {
   pub src: &'static str,
   pub errors: Vec<LintErr>,
+  /// Options to build the rule with, instead of `LintRule::new`.
+  pub options: Option<Value>,
   pub rule: PhantomData<T>,
 }
 
@@ -112,7 +165,7 @@ impl LintErrBuilder {
 
 impl<T: LintRule + 'static> LintErrTester<T> {
   pub fn run(&self) {
-    let rule = T::new();
+    let rule = build_rule::<T>(self.options.clone());
     let rule_code = rule.code();
     let diagnostics = lint(rule, self.src);
     assert_eq!(
@@ -138,11 +191,31 @@ impl<T: LintRule + 'static> LintErrTester<T> {
   }
 }
 
+/// Builds the rule `T` with `options`, which are deserialized as in the
+/// `ruleOptions` of `LintConfig`.
+fn build_rule<T: LintRule + 'static>(
+  options: Option<Value>,
+) -> Box<dyn LintRule> {
+  let rule = T::new();
+  match options {
+    Some(options) => get_rule_with_options(rule.code(), options)
+      .expect("Failed to build the rule with options"),
+    None => rule,
+  }
+}
+
 fn lint(rule: Box<dyn LintRule>, source: &str) -> Vec<LintDiagnostic> {
+  // JSX rules are skipped unless the source is parsed as TSX, while the type
+  // assertions of other tests can't be parsed as TSX.
+  let syntax = if rule.tags().contains(&"jsx") {
+    swc_util::get_default_tsx_config()
+  } else {
+    swc_util::get_default_ts_config()
+  };
   let mut linter = LinterBuilder::default()
     .lint_unused_ignore_directives(false)
     .lint_unknown_rules(false)
-    .syntax(swc_util::get_default_ts_config())
+    .syntax(syntax)
     .rules(vec![rule])
     .build();
 
@@ -226,6 +299,20 @@ pub fn assert_lint_ok<T: LintRule + 'static>(source: &str) {
   }
 }
 
+pub fn assert_lint_ok_with_options<T: LintRule + 'static>(
+  source: &str,
+  options: Value,
+) {
+  let rule = build_rule::<T>(Some(options));
+  let diagnostics = lint(rule, source);
+  if !diagnostics.is_empty() {
+    panic!(
+      "Unexpected diagnostics found:\n{:#?}\n\nsource:\n{}\n",
+      diagnostics, source
+    );
+  }
+}
+
 pub fn assert_lint_err<T: LintRule + 'static>(source: &str, col: usize) {
   assert_lint_err_on_line::<T>(source, 1, col)
 }
@@ -287,3 +374,50 @@ pub fn parse(source_code: &str) -> Module {
     ast_parser.parse_module("file_name.ts", syntax, source_code);
   parse_result.unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::rules::no_empty::NoEmpty;
+  use serde_json::json;
+
+  #[test]
+  fn lint_with_options() {
+    let options = json!({ "allowEmptyCatch": true });
+    assert_lint_ok_with_options! {
+      NoEmpty,
+      options,
+      "try { foo(); } catch (e) {}",
+      "try { foo(); } catch {}",
+    };
+    assert_lint_err_with_options! {
+      NoEmpty,
+      options,
+      "try {} catch (e) {}": [
+        {
+          col: 4,
+          message: "Empty block statement",
+        }
+      ],
+    };
+    // The same source is reported with the default options.
+    assert_lint_err! {
+      NoEmpty,
+      "try { foo(); } catch (e) {}": [
+        {
+          col: 25,
+          message: "Empty block statement",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  #[should_panic(expected = "Failed to build the rule with options")]
+  fn lint_with_invalid_options() {
+    assert_lint_ok_with_options! {
+      NoEmpty,
+      json!({ "allowEmptyCatch": "yes" }),
+      "try { foo(); } catch (e) {}",
+    };
+  }
+}